@@ -0,0 +1,54 @@
+use clap::Args;
+use solver::FlamecastSolutionFile;
+
+#[derive(Args)]
+pub struct CompareArgs {
+    /// Path of the first solution file
+    pub first_solution: String,
+    /// Path of the second solution file
+    pub second_solution: String,
+}
+
+pub fn process_compare_solutions(args: CompareArgs) {
+    let first = FlamecastSolutionFile::load(&args.first_solution).unwrap_or_else(|error| {
+        panic!("{}", error);
+    });
+    let second = FlamecastSolutionFile::load(&args.second_solution).unwrap_or_else(|error| {
+        panic!("{}", error);
+    });
+    if first.instance.to_json() != second.instance.to_json() {
+        println!("Warning: the solutions belong to different instance files.");
+    }
+
+    let first_graph = &first.solution.base_graph;
+    let second_graph = &second.solution.base_graph;
+    let distance = first_graph
+        .calculate_topological_distance(second_graph)
+        .unwrap_or_else(|error| panic!("{}", error));
+    let diffs = first_graph
+        .calculate_source_grouping_diff(second_graph)
+        .unwrap();
+
+    println!(
+        "Objective values: {} / {}",
+        first.objective_value, second.objective_value
+    );
+    println!("Topological distance: {}", distance.distance);
+    for (index, rand_index) in distance.layer_rand_indices.iter().enumerate() {
+        println!("Layer {}: rand index {}", index + 1, rand_index);
+    }
+
+    if diffs.is_empty() {
+        println!("The solutions group the sources identically.");
+        return;
+    }
+    for diff in diffs.iter() {
+        println!("Layer {}:", diff.layer);
+        for group in diff.only_in_first.iter() {
+            println!("  - {:?}", group);
+        }
+        for group in diff.only_in_second.iter() {
+            println!("  + {:?}", group);
+        }
+    }
+}
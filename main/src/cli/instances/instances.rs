@@ -1,4 +1,7 @@
-use super::{process_generate_instances, process_run_tests, RunTestsArgs};
+use super::{
+    process_compare_solutions, process_generate_instances, process_run_tests,
+    process_verify_solution, CompareArgs, RunTestsArgs, VerifyArgs,
+};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -13,6 +16,10 @@ pub enum InstancesSubcommand {
     Generate,
     /// Run tests on Flamecast instances.
     RunTests(RunTestsArgs),
+    /// Compare the topologies of two solutions of the same instance.
+    Compare(CompareArgs),
+    /// Verify the feasibility and the costs of a solution of an instance.
+    Verify(VerifyArgs),
 }
 
 pub fn process_instances_command(args: InstancesArgs) {
@@ -23,5 +30,11 @@ pub fn process_instances_command(args: InstancesArgs) {
         InstancesSubcommand::RunTests(run_tests_args) => {
             process_run_tests(run_tests_args);
         }
+        InstancesSubcommand::Compare(compare_args) => {
+            process_compare_solutions(compare_args);
+        }
+        InstancesSubcommand::Verify(verify_args) => {
+            process_verify_solution(verify_args);
+        }
     }
 }
@@ -1,7 +1,11 @@
+mod compare;
 mod generate_instances;
 mod instances;
 mod run_tests;
+mod verify;
 
+pub use compare::*;
 pub use generate_instances::*;
 pub use instances::*;
 pub use run_tests::*;
+pub use verify::*;
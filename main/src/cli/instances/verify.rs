@@ -0,0 +1,60 @@
+use clap::Args;
+use solver::{FlamecastInstanceFile, FlamecastSolutionFile, SearchDepth};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Path of the instance file
+    pub instance: String,
+    /// Path of the solution file
+    pub solution: String,
+    /// Embed the topology again with this search depth (shallow, middle, deep, very-deep)
+    #[arg(short, long, value_parser = parse_search_depth)]
+    pub search_depth: Option<SearchDepth>,
+}
+
+pub fn process_verify_solution(args: VerifyArgs) {
+    let instance = FlamecastInstanceFile::load(&args.instance, None).unwrap_or_else(|error| {
+        panic!("{}", error);
+    });
+    let solution =
+        FlamecastSolutionFile::load_without_validation(&args.solution).unwrap_or_else(|error| {
+            panic!("{}", error);
+        });
+
+    let report = solution.verify(&instance, args.search_depth);
+    if !report.instance_matches {
+        println!("Mismatch: the solution file stores a different instance.");
+    }
+    for error in report.feasibility_errors.iter() {
+        println!("Infeasible: {}", error);
+    }
+    if let Some(objective_value) = report.recomputed_objective_value {
+        println!("Recomputed objective value: {}", objective_value);
+    }
+    if let Some(objective_value) = report.reembedded_objective_value {
+        println!("Re-embedded objective value: {}", objective_value);
+    }
+    for mismatch in report.mismatches.iter() {
+        println!(
+            "Mismatch: {} is {} but {} was recomputed",
+            mismatch.quantity, mismatch.reported_value, mismatch.recomputed_value
+        );
+    }
+
+    if report.is_verified() {
+        println!("The solution is verified.");
+    } else {
+        println!("The solution could not be verified.");
+        std::process::exit(1);
+    }
+}
+
+fn parse_search_depth(s: &str) -> Result<SearchDepth, String> {
+    return match s {
+        "shallow" => Ok(SearchDepth::Shallow),
+        "middle" => Ok(SearchDepth::Middle),
+        "deep" => Ok(SearchDepth::Deep),
+        "very-deep" => Ok(SearchDepth::VeryDeep),
+        _ => Err(format!("`{s}` isn't a valid search depth")),
+    };
+}
@@ -4,9 +4,11 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use solver::{FlamecastTestInstance, InitialSolutionFunction, VertexEmbeddings};
+use solver::{FlamecastInstanceFile, InitialSolutionFunction};
 
-use super::{get_alpha_options, run_test, FlamecastBaseInstance, INSTANCES_DIR};
+use crate::solver_testing::{get_statistics_file_path, write_solution_statistics};
+
+use super::{get_alpha_options, INSTANCES_DIR};
 
 pub const ALPHA_SOLUTIONS_DIR: &str = "./ba/solutions/alpha";
 
@@ -46,7 +48,7 @@ pub fn process_test_alpha(dir_name: &String) {
         .collect::<Vec<DirEntry>>();
 
     let done_jobs = Mutex::new(0);
-    let total_jobs = get_num_instances(&entries) * ALPHA_VALUES.len();
+    let total_jobs = get_num_instances(&entries);
     println!("Total jobs: {}", total_jobs);
     println!("Starting jobs...");
 
@@ -54,35 +56,37 @@ pub fn process_test_alpha(dir_name: &String) {
         let file_path = entry.path();
 
         if file_path.is_file() && file_path.extension().map_or(false, |ext| ext == "json") {
-            let base_instance = FlamecastBaseInstance::from_file(&file_path.display().to_string());
+            // base instances have no alpha, so the sweep starts with the first alpha
+            let mut instance = FlamecastInstanceFile::load(
+                &file_path.display().to_string(),
+                Some(ALPHA_VALUES[0]),
+            )
+            .unwrap()
+            .to_flamecast_instance(InitialSolutionFunction::Matching)
+            .unwrap();
             let instance_name = file_path.file_stem().unwrap().to_str().unwrap();
 
-            ALPHA_VALUES.par_iter().for_each(|alpha| {
-                let mut sources_drains_embeddings =
-                    VertexEmbeddings::new_with_size(base_instance.layers);
-                sources_drains_embeddings.embeddings[0] = base_instance.sources.clone();
-                sources_drains_embeddings.embeddings[base_instance.layers - 1] =
-                    base_instance.drains.clone();
-                let instance = FlamecastTestInstance::new(
-                    *alpha,
-                    base_instance.layers,
-                    base_instance.capacities.clone(),
-                    sources_drains_embeddings,
-                );
+            // every alpha starts from the best topology of the previous one
+            let alpha_sweep = instance
+                .solve_alpha_sweep(&ALPHA_VALUES.to_vec(), get_alpha_options)
+                .unwrap();
 
-                let alpha_string = alpha.to_string().replace('.', "_");
+            for point in alpha_sweep.points.iter() {
+                let alpha_string = point.alpha.to_string().replace('.', "_");
                 let solution_dir = format!("{}/{}/{}", ALPHA_SOLUTIONS_DIR, alpha_string, dir_name);
 
-                run_test(
-                    instance,
-                    &solution_dir,
-                    &instance_name.to_string(),
-                    InitialSolutionFunction::Matching,
-                    get_alpha_options,
+                std::fs::write(
+                    format!("{}/{}.json", solution_dir, instance_name),
+                    serde_json::to_string_pretty(&point.logger).unwrap(),
+                )
+                .unwrap();
+                write_solution_statistics(
+                    &point.statistics,
+                    &get_statistics_file_path(&solution_dir, &instance_name.to_string()),
                 );
+            }
 
-                register_job_done(&done_jobs, total_jobs);
-            });
+            register_job_done(&done_jobs, total_jobs);
         }
     });
 
@@ -21,11 +21,6 @@ impl FlamecastBaseInstance {
         }
     }
 
-    pub fn from_file(file_path: &String) -> Self {
-        let content: String = std::fs::read_to_string(file_path).expect("Failed to read file");
-        serde_json::from_str(&content).expect("Failed to parse JSON")
-    }
-
     pub fn to_file(&self, file_path: &String) {
         let content = serde_json::to_string_pretty(self).expect("Failed to serialize to JSON");
         std::fs::write(file_path, content).expect("Failed to write file");
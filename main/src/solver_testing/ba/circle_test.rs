@@ -4,8 +4,7 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use serde::{Deserialize, Serialize};
-use solver::{FlamecastTestInstance, InitialSolutionFunction, VertexEmbeddings};
+use solver::{FlamecastInstanceFile, InitialSolutionFunction};
 
 use crate::solver_testing::{get_circle_options, get_num_instances, register_job_done, run_test};
 
@@ -14,14 +13,6 @@ pub const CIRCLE_SOLUTIONS_DIR: &str = "./circle/solutions";
 
 pub const CIRCLE_ALPHA_VALUES: [f64; 10] = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9];
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CircleInstance {
-    pub sources: Vec<Vec<f64>>,
-    pub sinks: Vec<Vec<f64>>,
-    pub layers: usize,
-    pub capacities: Vec<Option<usize>>,
-}
-
 pub fn circle_test() {
     ThreadPoolBuilder::new()
         .num_threads(num_cpus::get())
@@ -65,31 +56,12 @@ pub fn circle_test() {
         if file_path.is_file() && file_path.extension().map_or(false, |ext| ext == "json") {
             println!("Processing file: {:?}", file_path);
             let instance_name = file_path.file_stem().unwrap().to_str().unwrap();
-            let instance: CircleInstance =
-                serde_json::from_reader(std::fs::File::open(&file_path).unwrap()).unwrap();
+            let content = std::fs::read_to_string(&file_path).unwrap();
             CIRCLE_ALPHA_VALUES.par_iter().for_each(|alpha| {
-                let mut sources_drains_embeddings =
-                    VertexEmbeddings::new_with_size(instance.layers);
-                sources_drains_embeddings.embeddings[0] =
-                    instance.sources.iter().map(|x| (x[0], x[1])).collect();
-                sources_drains_embeddings.embeddings[instance.layers - 1] =
-                    instance.sinks.iter().map(|x| (x[0], x[1])).collect();
-                let mut stored_capacities = instance
-                    .capacities
-                    .iter()
-                    .map(|&x| match x {
-                        Some(capacity) => capacity,
-                        None => instance.sources.len(),
-                    })
-                    .collect::<Vec<usize>>();
-                let mut capacities = vec![1];
-                capacities.append(&mut stored_capacities);
-                let instance = FlamecastTestInstance::new(
-                    *alpha,
-                    instance.layers,
-                    capacities,
-                    sources_drains_embeddings,
-                );
+                // circle instances have no alpha, so the alpha of the test is used
+                let instance = FlamecastInstanceFile::from_json(&content, Some(*alpha))
+                    .unwrap()
+                    .to_test_instance();
 
                 let alpha_string = alpha.to_string().replace('.', "_");
                 let solution_dir = format!("{}/{}", CIRCLE_SOLUTIONS_DIR, alpha_string);
@@ -4,11 +4,10 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use solver::{FlamecastTestInstance, InitialSolutionFunction, VertexEmbeddings};
+use solver::{FlamecastInstanceFile, InitialSolutionFunction};
 
 use crate::solver_testing::{
-    get_init_options, get_num_instances, register_job_done, run_test, FlamecastBaseInstance,
-    INSTANCES_DIR,
+    get_init_options, get_num_instances, register_job_done, run_test, INSTANCES_DIR,
 };
 
 pub const INIT_SOLUTIONS_DIR: &str = "./ba/solutions/init";
@@ -65,7 +64,11 @@ pub fn init_test(dir_name: &String) {
         let file_path = entry.path();
 
         if file_path.is_file() && file_path.extension().map_or(false, |ext| ext == "json") {
-            let base_instance = FlamecastBaseInstance::from_file(&file_path.display().to_string());
+            // base instances have no alpha, the alpha of the test replaces the one of the file
+            let mut instance_file =
+                FlamecastInstanceFile::load(&file_path.display().to_string(), Some(INIT_ALPHA))
+                    .unwrap();
+            instance_file.alpha = INIT_ALPHA;
             let instance_name = file_path.file_stem().unwrap().to_str().unwrap();
 
             INIT_FUNCTION_TYPES.par_iter().for_each(|init_function| {
@@ -76,20 +79,8 @@ pub fn init_test(dir_name: &String) {
                     dir_name
                 );
 
-                let mut sources_drains_embeddings =
-                    VertexEmbeddings::new_with_size(base_instance.layers);
-                sources_drains_embeddings.embeddings[0] = base_instance.sources.clone();
-                sources_drains_embeddings.embeddings[base_instance.layers - 1] =
-                    base_instance.drains.clone();
-                let instance = FlamecastTestInstance::new(
-                    INIT_ALPHA,
-                    base_instance.layers,
-                    base_instance.capacities.clone(),
-                    sources_drains_embeddings,
-                );
-
                 run_test(
-                    instance,
+                    instance_file.to_test_instance(),
                     &solution_dir,
                     &instance_name.to_string(),
                     init_function.clone(),
@@ -8,11 +8,11 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use solver::{FlamecastInstance, FlamecastTestInstance, InitialSolutionFunction, VertexEmbeddings};
+use solver::{FlamecastInstanceFile, InitialSolutionFunction};
 
 use crate::solver_testing::{
-    get_iterations_options, get_num_instances, register_job_done, FlamecastBaseInstance,
-    INSTANCES_DIR,
+    get_iterations_options, get_num_instances, get_statistics_file_path, register_job_done,
+    write_solution_statistics, INSTANCES_DIR,
 };
 
 pub const ITERATIONS_SOLUTIONS_DIR: &str = "./ba/solutions/iterations";
@@ -70,7 +70,12 @@ pub fn iterations_test(dir_name: &String) {
         let file_path = entry.path();
 
         if file_path.is_file() && file_path.extension().map_or(false, |ext| ext == "json") {
-            let base_instance = FlamecastBaseInstance::from_file(&file_path.display().to_string());
+            // base instances have no alpha, the alpha of the test replaces the one of the file
+            let base_instance_file = FlamecastInstanceFile::load(
+                &file_path.display().to_string(),
+                Some(ITERATIONS_ALPHAS[0]),
+            )
+            .unwrap();
             let instance_name = file_path.file_stem().unwrap().to_str().unwrap();
 
             ITERATIONS_MULTIPLIERS
@@ -85,26 +90,11 @@ pub fn iterations_test(dir_name: &String) {
                             dir_name
                         );
 
-                        let mut sources_drains_embeddings =
-                            VertexEmbeddings::new_with_size(base_instance.layers);
-                        sources_drains_embeddings.embeddings[0] = base_instance.sources.clone();
-                        sources_drains_embeddings.embeddings[base_instance.layers - 1] =
-                            base_instance.drains.clone();
-
-                        let instance = FlamecastTestInstance::new(
-                            *alpha,
-                            base_instance.layers,
-                            base_instance.capacities.clone(),
-                            sources_drains_embeddings.clone(),
-                        );
-
-                        let mut instance = FlamecastInstance::new(
-                            instance.alpha,
-                            instance.num_layers,
-                            instance.capacities,
-                            instance.sources_drains_embeddings,
-                            InitialSolutionFunction::Matching,
-                        );
+                        let mut instance_file = base_instance_file.clone();
+                        instance_file.alpha = *alpha;
+                        let mut instance = instance_file
+                            .to_flamecast_instance(InitialSolutionFunction::Matching)
+                            .unwrap();
 
                         let options = get_iterations_options(
                             instance.get_objective_function_value(),
@@ -123,6 +113,10 @@ pub fn iterations_test(dir_name: &String) {
                             serde_json::to_string_pretty(&instance.logger).unwrap(),
                         )
                         .unwrap();
+                        write_solution_statistics(
+                            &instance.get_solution_statistics(),
+                            &get_statistics_file_path(&solution_dir, &instance_name.to_string()),
+                        );
 
                         register_job_done(&done_jobs, total_jobs);
                     }
@@ -8,11 +8,11 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use solver::{FlamecastInstance, FlamecastTestInstance, InitialSolutionFunction, VertexEmbeddings};
+use solver::{FlamecastInstanceFile, InitialSolutionFunction};
 
 use crate::solver_testing::{
-    get_num_instances, get_num_vertices_options, register_job_done, FlamecastBaseInstance,
-    INSTANCES_DIR,
+    get_num_instances, get_num_vertices_options, get_statistics_file_path, register_job_done,
+    write_solution_statistics, INSTANCES_DIR,
 };
 
 pub const NUM_VERTICES_SOLUTIONS_DIR: &str = "./ba/solutions/num_vertices";
@@ -65,7 +65,12 @@ pub fn num_vertices_test(dir_name: &String) {
         let file_path = entry.path();
 
         if file_path.is_file() && file_path.extension().map_or(false, |ext| ext == "json") {
-            let base_instance = FlamecastBaseInstance::from_file(&file_path.display().to_string());
+            // base instances have no alpha, the alpha of the test replaces the one of the file
+            let base_instance_file = FlamecastInstanceFile::load(
+                &file_path.display().to_string(),
+                Some(NUM_VERTICES_ALPHAS[0]),
+            )
+            .unwrap();
             let instance_name = file_path.file_stem().unwrap().to_str().unwrap();
 
             NUM_VERTICES.par_iter().for_each(|num_vertices| {
@@ -78,25 +83,11 @@ pub fn num_vertices_test(dir_name: &String) {
                         dir_name
                     );
 
-                    let mut sources_drains_embeddings =
-                        VertexEmbeddings::new_with_size(base_instance.layers);
-                    sources_drains_embeddings.embeddings[0] = base_instance.sources.clone();
-                    sources_drains_embeddings.embeddings[base_instance.layers - 1] =
-                        base_instance.drains.clone();
-                    let instance = FlamecastTestInstance::new(
-                        *alpha,
-                        base_instance.layers,
-                        base_instance.capacities.clone(),
-                        sources_drains_embeddings,
-                    );
-
-                    let mut instance = FlamecastInstance::new(
-                        instance.alpha,
-                        instance.num_layers,
-                        instance.capacities,
-                        instance.sources_drains_embeddings,
-                        InitialSolutionFunction::Matching,
-                    );
+                    let mut instance_file = base_instance_file.clone();
+                    instance_file.alpha = *alpha;
+                    let mut instance = instance_file
+                        .to_flamecast_instance(InitialSolutionFunction::Matching)
+                        .unwrap();
 
                     let options = get_num_vertices_options(
                         instance.get_objective_function_value(),
@@ -115,6 +106,10 @@ pub fn num_vertices_test(dir_name: &String) {
                         serde_json::to_string_pretty(&instance.logger).unwrap(),
                     )
                     .unwrap();
+                    write_solution_statistics(
+                        &instance.get_solution_statistics(),
+                        &get_statistics_file_path(&solution_dir, &instance_name.to_string()),
+                    );
 
                     register_job_done(&done_jobs, total_jobs);
                 }
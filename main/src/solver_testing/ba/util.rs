@@ -1,10 +1,12 @@
 use std::fs;
 
 use solver::{
-    FlamecastInstance, FlamecastTestInstance, InitialSolutionFunction, OptimizationOptions,
-    SimulatedAnnealingLogger,
+    EmbeddingOptions, FlamecastInstance, FlamecastTestInstance, InitialSolutionFunction,
+    OptimizationOptions, SimulatedAnnealingLogger,
 };
 
+use crate::solver_testing::{get_statistics_file_path, write_solution_statistics};
+
 pub const CONVERGED_PERCENTAGE: f64 = 0.02;
 
 pub fn run_test(
@@ -14,6 +16,8 @@ pub fn run_test(
     init_function: InitialSolutionFunction,
     options_generator: fn(f64, usize) -> OptimizationOptions,
 ) {
+    let layer_alphas = instance.layer_alphas;
+    let layer_cost_multipliers = instance.layer_cost_multipliers;
     let mut instance = FlamecastInstance::new(
         instance.alpha,
         instance.num_layers,
@@ -21,6 +25,13 @@ pub fn run_test(
         instance.sources_drains_embeddings,
         init_function,
     );
+    instance
+        .set_layer_costs(
+            layer_alphas,
+            layer_cost_multipliers,
+            &EmbeddingOptions::default(),
+        )
+        .unwrap();
 
     let options = options_generator(
         instance.get_objective_function_value(),
@@ -38,6 +49,10 @@ pub fn run_test(
         serde_json::to_string_pretty(&instance.logger).unwrap(),
     )
     .unwrap();
+    write_solution_statistics(
+        &instance.get_solution_statistics(),
+        &get_statistics_file_path(path_dir, name),
+    );
 }
 
 pub fn get_converged_iteration(logs: &Vec<Vec<SimulatedAnnealingLogger>>) -> Vec<Vec<usize>> {
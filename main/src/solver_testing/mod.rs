@@ -1,6 +1,7 @@
 mod ba;
 mod instance_generation;
 mod optimization_options;
+mod solution_statistics;
 mod test_imported_instances;
 mod test_predefined_instances;
 mod test_user_defined_instances;
@@ -8,6 +9,7 @@ mod test_user_defined_instances;
 pub use ba::*;
 pub use instance_generation::*;
 pub use optimization_options::*;
+pub use solution_statistics::*;
 pub use test_imported_instances::*;
 pub use test_predefined_instances::*;
 pub use test_user_defined_instances::*;
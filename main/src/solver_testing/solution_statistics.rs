@@ -0,0 +1,54 @@
+use std::fs;
+
+use solver::SolutionStatistics;
+
+pub fn write_solution_statistics(statistics: &SolutionStatistics, file_path: &str) {
+    fs::write(file_path, serde_json::to_string_pretty(statistics).unwrap()).unwrap();
+}
+
+pub fn get_statistics_file_path(solution_dir: &String, name: &String) -> String {
+    // the evaluations read every file of a solution directory as logger, so the statistics are
+    // written to a directory next to it
+    let statistics_dir = format!("{}_statistics", solution_dir);
+    fs::create_dir_all(&statistics_dir).unwrap();
+    return format!("{}/{}.json", statistics_dir, name);
+}
+
+pub fn format_solution_statistics(statistics: &SolutionStatistics) -> String {
+    // one line per layer, followed by the distributions over all vertices that are not sources
+    let mut lines = vec![format!(
+        "Objective value: {} (robustness penalty {})",
+        statistics.objective_value, statistics.robustness_penalty
+    )];
+    for layer in statistics.layers.iter() {
+        lines.push(format!(
+            "Layer {}: {} vertices, utilization {:.2}, edge length {:.4}, costs {:.4} (edges {:.4}, opening {:.4}), branching factors {:?}",
+            layer.layer,
+            layer.number_of_vertices,
+            layer.mean_utilization,
+            layer.edge_length,
+            layer.get_costs(),
+            layer.edge_costs,
+            layer.opening_costs,
+            layer.branching_factors
+        ));
+    }
+    lines.push(format!(
+        "Total edge length: {:.4}",
+        statistics.total_edge_length
+    ));
+    lines.push(format!(
+        "Longest source path: source {} with length {:.4}",
+        statistics.longest_source_path, statistics.longest_source_path_length
+    ));
+    lines.push(format!(
+        "Branching factors: {:?}",
+        statistics.branching_factors
+    ));
+    lines.push(format!("Flow histogram: {:?}", statistics.flow_histogram));
+    lines.push(format!(
+        "Utilization histogram: {:?}",
+        statistics.utilization_histogram
+    ));
+    return lines.join("\n");
+}
@@ -1,7 +1,5 @@
-use std::fs;
-
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use solver::{EmbeddingOptions, FlamecastTestInstance, NeighborSearchOption, OptimizationOptions};
+use solver::{EmbeddingOptions, FlamecastInstanceFile, NeighborSearchOption, OptimizationOptions};
 
 use super::{solve_processing_instance, ProcessingInstance, OPTIMIZATION_OPTIONS};
 
@@ -41,24 +39,17 @@ fn get_processing_instances(imported_indices: &Vec<usize>) -> Vec<ProcessingInst
             "{}instance{}/instance.json",
             IMPORTED_BASE_PATH, imported_index
         );
-        let file = fs::read_to_string(file_path);
-        if file.is_err() {
-            println!(
-                "An error occured while reading the file with index {}.",
-                imported_index
-            );
-            continue;
-        }
-        let file = file.unwrap();
-        let instance = serde_json::from_str::<FlamecastTestInstance>(&file);
-        if instance.is_err() {
-            println!(
-                "File with index {} isn't a valid flamecast test instance.",
-                imported_index
-            );
-            continue;
-        }
-        let instance = instance.unwrap();
+        // older instance files are migrated to the current format
+        let instance = match FlamecastInstanceFile::load(&file_path, None) {
+            Ok(instance) => instance,
+            Err(error) => {
+                println!(
+                    "File with index {} isn't a valid flamecast instance: {}",
+                    imported_index, error
+                );
+                continue;
+            }
+        };
 
         optimization_options.iter().enumerate().for_each(
             |(optimization_index, optimization_option)| {
@@ -2,17 +2,19 @@ use std::{fs, io::Write};
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use solver::{
-    generate_flamecast_instance, EmbeddingOptions, FlamecastTestInstance, InitialSolutionFunction,
-    NeighborSearchOption, OptimizationOptions,
+    EmbeddingOptions, FlamecastInstanceFile, InitialSolutionFunction, NeighborSearchOption,
+    OptimizationOptions,
 };
 
 use crate::solver_testing::PREDEFINED_BASE_PATH;
 
-use super::{INSTANCES, OPTIMIZATION_OPTIONS};
+use super::{
+    format_solution_statistics, write_solution_statistics, INSTANCES, OPTIMIZATION_OPTIONS,
+};
 
 #[derive(Debug, Clone)]
 pub struct ProcessingInstance {
-    pub instance: FlamecastTestInstance,
+    pub instance: FlamecastInstanceFile,
     pub optimization_option: OptimizationOptions,
     pub instance_index: usize,
     pub optimization_index: usize,
@@ -20,7 +22,7 @@ pub struct ProcessingInstance {
 
 impl ProcessingInstance {
     pub fn new(
-        instance: FlamecastTestInstance,
+        instance: FlamecastInstanceFile,
         optimization_option: OptimizationOptions,
         instance_index: usize,
         optimization_index: usize,
@@ -58,16 +60,10 @@ pub fn solve_processing_instance(processing_instance: &ProcessingInstance, base_
     let processing_index = processing_instance.optimization_index;
     let current_index = instance_index * OPTIMIZATION_OPTIONS.len() + processing_index;
 
-    let mut instance = generate_flamecast_instance(
-        processing_instance.instance.alpha,
-        processing_instance.instance.num_layers,
-        processing_instance.instance.capacities.clone(),
-        processing_instance
-            .instance
-            .sources_drains_embeddings
-            .clone(),
-        InitialSolutionFunction::Random,
-    );
+    let mut instance = processing_instance
+        .instance
+        .to_flamecast_instance(InitialSolutionFunction::Random)
+        .unwrap();
 
     instance.plot_current_solution(
         format!("{}initial_solution.png", base_path).as_str(),
@@ -79,6 +75,10 @@ pub fn solve_processing_instance(processing_instance: &ProcessingInstance, base_
         serde_json::to_string_pretty(&instance.solution_state.current_solution).unwrap(),
     )
     .unwrap();
+    write_solution_statistics(
+        &instance.get_solution_statistics(),
+        format!("{}initial_solution_statistics.json", base_path).as_str(),
+    );
 
     println!("Start processing instance {}", current_index);
 
@@ -112,8 +112,18 @@ pub fn solve_processing_instance(processing_instance: &ProcessingInstance, base_
         serde_json::to_string_pretty(&instance.solution_state.current_solution).unwrap(),
     )
     .unwrap();
+    let statistics = instance.get_solution_statistics();
+    write_solution_statistics(
+        &statistics,
+        format!("{}final_solution_statistics.json", base_path).as_str(),
+    );
 
-    println!("Finished processing instance {}", current_index);
+    // printed at once so that the reports of parallel instances are not interleaved
+    println!(
+        "Finished processing instance {}\n{}",
+        current_index,
+        format_solution_statistics(&statistics)
+    );
 }
 
 fn get_processing_instances(predefined_instances_indexes: &Vec<usize>) -> Vec<ProcessingInstance> {
@@ -132,12 +142,7 @@ fn get_processing_instances(predefined_instances_indexes: &Vec<usize>) -> Vec<Pr
             "{}instance{}/instance.json",
             PREDEFINED_BASE_PATH, instance_index
         );
-        let test_instance = fs::read_to_string(file_path)
-            .ok()
-            .and_then(|test_instance| {
-                serde_json::from_str::<FlamecastTestInstance>(&test_instance).ok()
-            })
-            .unwrap();
+        let instance_file = FlamecastInstanceFile::load(&file_path, None).unwrap();
 
         let default_options = EmbeddingOptions::default();
         optimization_options.iter().enumerate().for_each(
@@ -154,7 +159,7 @@ fn get_processing_instances(predefined_instances_indexes: &Vec<usize>) -> Vec<Pr
                     default_options.clone(),
                 );
                 processing_instances.push(ProcessingInstance::new(
-                    test_instance.clone(),
+                    instance_file.clone(),
                     option,
                     *instance_index,
                     optimization_index,
@@ -1,8 +1,6 @@
-use std::fs;
-
 use solver::{
-    generate_random_flamecast_test_instance, EmbeddingOptions, NeighborSearchOption,
-    OptimizationOptions,
+    generate_random_flamecast_test_instance, EmbeddingOptions, FlamecastInstanceFile,
+    NeighborSearchOption, OptimizationOptions,
 };
 
 use super::{solve_processing_instance, IterationCoolingOption, ProcessingInstance};
@@ -51,11 +49,10 @@ fn get_processing_instance(
     test_instance.plot_instance(
         format!("{}{}instance_visualized.png", USER_DEFINED_BASE_PATH, index).as_str(),
     );
-    fs::write(
-        format!("{}{}instance.json", USER_DEFINED_BASE_PATH, index),
-        serde_json::to_string_pretty(&test_instance).unwrap(),
-    )
-    .unwrap();
+    let instance_file = FlamecastInstanceFile::from_test_instance(&test_instance);
+    instance_file
+        .save(format!("{}{}instance.json", USER_DEFINED_BASE_PATH, index).as_str())
+        .unwrap();
 
     let default_options = EmbeddingOptions::default();
     let option = OptimizationOptions::new(
@@ -69,5 +66,5 @@ fn get_processing_instance(
         default_options.clone(),
         default_options.clone(),
     );
-    return ProcessingInstance::new(test_instance, option, 0, 0);
+    return ProcessingInstance::new(instance_file, option, 0, 0);
 }
@@ -0,0 +1,64 @@
+use crate::{CostModel, GraphEmbedding};
+
+use super::{get_vertex_name, get_vertex_type};
+
+impl GraphEmbedding {
+    pub fn to_dot(&self, alpha: f64) -> String {
+        return self.to_dot_with_cost_model(&CostModel::from_alpha(alpha));
+    }
+
+    pub fn to_dot_with_cost_model(&self, cost_model: &CostModel) -> String {
+        let alpha = cost_model.alpha;
+        let graph = &self.base_graph;
+        let embeddings = &self.vertices_embeddings.embeddings;
+        let vertex_flows = graph.calculate_vertex_flows();
+        let edge_lengths = self.calculate_edge_lengths_with_cost_model(cost_model);
+        let edge_costs = self.calculate_edge_costs_with_cost_model(cost_model);
+        let total_cost = self.calculate_costs_with_cost_model(cost_model);
+
+        let mut dot = String::new();
+        dot.push_str("digraph flamecast {\n");
+        dot.push_str(&format!("  alpha={};\n", alpha));
+        dot.push_str(&format!("  cost={};\n", total_cost));
+
+        // the "!" pins the vertices to their embedding when drawn with neato
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            for vertex_index in 0..layer.vertices.len() {
+                let embedding = embeddings[layer_index][vertex_index];
+                dot.push_str(&format!(
+                    "  {} [pos=\"{},{}!\", x={}, y={}, layer={}, index={}, type=\"{}\", flow={}];\n",
+                    get_vertex_name(layer_index, vertex_index),
+                    embedding.0,
+                    embedding.1,
+                    embedding.0,
+                    embedding.1,
+                    layer_index,
+                    vertex_index,
+                    get_vertex_type(layer_index, graph.layers.len()),
+                    vertex_flows[layer_index][vertex_index]
+                ));
+            }
+        }
+
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            if layer_index == graph.layers.len() - 1 {
+                break;
+            }
+
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                dot.push_str(&format!(
+                    "  {} -> {} [flow={}, length={}, cost={}];\n",
+                    get_vertex_name(layer_index, vertex_index),
+                    get_vertex_name(layer_index + 1, vertex.parent_index.unwrap()),
+                    vertex_flows[layer_index][vertex_index],
+                    edge_lengths[layer_index][vertex_index],
+                    edge_costs[layer_index][vertex_index]
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+
+        return dot;
+    }
+}
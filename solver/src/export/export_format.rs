@@ -0,0 +1,50 @@
+use std::{fs, io, path::Path};
+
+use crate::{CostModel, GraphEmbedding};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    GraphML,
+    Dot,
+    GeoJson,
+}
+
+impl ExportFormat {
+    pub fn from_file_path(file_path: &str) -> Option<Self> {
+        let extension = Path::new(file_path).extension()?.to_str()?.to_lowercase();
+        return match extension.as_str() {
+            "graphml" => Some(ExportFormat::GraphML),
+            "dot" | "gv" => Some(ExportFormat::Dot),
+            "geojson" => Some(ExportFormat::GeoJson),
+            _ => None,
+        };
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ExportFormat::GraphML => String::from("GraphML"),
+            ExportFormat::Dot => String::from("DOT"),
+            ExportFormat::GeoJson => String::from("GeoJSON"),
+        }
+    }
+}
+
+impl GraphEmbedding {
+    pub fn export(&self, file_path: &str, format: ExportFormat, alpha: f64) -> io::Result<()> {
+        return self.export_with_cost_model(file_path, format, &CostModel::from_alpha(alpha));
+    }
+
+    pub fn export_with_cost_model(
+        &self,
+        file_path: &str,
+        format: ExportFormat,
+        cost_model: &CostModel,
+    ) -> io::Result<()> {
+        let content = match format {
+            ExportFormat::GraphML => self.to_graphml_with_cost_model(cost_model),
+            ExportFormat::Dot => self.to_dot_with_cost_model(cost_model),
+            ExportFormat::GeoJson => self.to_geojson_with_cost_model(cost_model),
+        };
+        return fs::write(file_path, content);
+    }
+}
@@ -0,0 +1,83 @@
+use serde_json::{json, Value};
+
+use crate::{CostModel, GraphEmbedding};
+
+use super::{get_vertex_name, get_vertex_type};
+
+impl GraphEmbedding {
+    pub fn to_geojson(&self, alpha: f64) -> String {
+        return self.to_geojson_with_cost_model(&CostModel::from_alpha(alpha));
+    }
+
+    pub fn to_geojson_with_cost_model(&self, cost_model: &CostModel) -> String {
+        let alpha = cost_model.alpha;
+        // GeoJSON consumers expect (x, y) = (longitude, latitude), projected embeddings are converted back
+        let graph = &self.base_graph;
+        let geographic_embedding = self.to_geographic_coordinates(&cost_model.coordinate_system);
+        let embeddings = &geographic_embedding.vertices_embeddings.embeddings;
+        let vertex_flows = graph.calculate_vertex_flows();
+        let edge_lengths = self.calculate_edge_lengths_with_cost_model(cost_model);
+        let edge_costs = self.calculate_edge_costs_with_cost_model(cost_model);
+        let total_cost = self.calculate_costs_with_cost_model(cost_model);
+
+        let mut features: Vec<Value> = Vec::new();
+
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            for vertex_index in 0..layer.vertices.len() {
+                let embedding = embeddings[layer_index][vertex_index];
+                features.push(json!({
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "Point",
+                        "coordinates": [embedding.0, embedding.1],
+                    },
+                    "properties": {
+                        "id": get_vertex_name(layer_index, vertex_index),
+                        "layer": layer_index,
+                        "index": vertex_index,
+                        "type": get_vertex_type(layer_index, graph.layers.len()),
+                        "flow": vertex_flows[layer_index][vertex_index],
+                    },
+                }));
+            }
+        }
+
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            if layer_index == graph.layers.len() - 1 {
+                break;
+            }
+
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                let parent_index = vertex.parent_index.unwrap();
+                let source = embeddings[layer_index][vertex_index];
+                let target = embeddings[layer_index + 1][parent_index];
+                features.push(json!({
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[source.0, source.1], [target.0, target.1]],
+                    },
+                    "properties": {
+                        "source": get_vertex_name(layer_index, vertex_index),
+                        "target": get_vertex_name(layer_index + 1, parent_index),
+                        "layer": layer_index,
+                        "flow": vertex_flows[layer_index][vertex_index],
+                        "length": edge_lengths[layer_index][vertex_index],
+                        "cost": edge_costs[layer_index][vertex_index],
+                    },
+                }));
+            }
+        }
+
+        let feature_collection = json!({
+            "type": "FeatureCollection",
+            "properties": {
+                "alpha": alpha,
+                "cost": total_cost,
+            },
+            "features": features,
+        });
+
+        return serde_json::to_string_pretty(&feature_collection).unwrap();
+    }
+}
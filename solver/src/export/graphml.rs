@@ -0,0 +1,118 @@
+use crate::{CostModel, GraphEmbedding};
+
+use super::{get_vertex_name, get_vertex_type};
+
+impl GraphEmbedding {
+    pub fn to_graphml(&self, alpha: f64) -> String {
+        return self.to_graphml_with_cost_model(&CostModel::from_alpha(alpha));
+    }
+
+    pub fn to_graphml_with_cost_model(&self, cost_model: &CostModel) -> String {
+        let alpha = cost_model.alpha;
+        let graph = &self.base_graph;
+        let embeddings = &self.vertices_embeddings.embeddings;
+        let vertex_flows = graph.calculate_vertex_flows();
+        let edge_lengths = self.calculate_edge_lengths_with_cost_model(cost_model);
+        let edge_costs = self.calculate_edge_costs_with_cost_model(cost_model);
+        let total_cost = self.calculate_costs_with_cost_model(cost_model);
+
+        let mut graphml = String::new();
+        graphml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        graphml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+
+        // attribute declarations
+        let keys = [
+            ("alpha", "graph", "double"),
+            ("cost", "graph", "double"),
+            ("x", "node", "double"),
+            ("y", "node", "double"),
+            ("layer", "node", "int"),
+            ("index", "node", "int"),
+            ("type", "node", "string"),
+            ("flow", "node", "int"),
+            ("flow", "edge", "int"),
+            ("length", "edge", "double"),
+            ("cost", "edge", "double"),
+        ];
+        for (name, domain, attribute_type) in keys.iter() {
+            graphml.push_str(&format!(
+                "  <key id=\"{}_{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>\n",
+                domain, name, domain, name, attribute_type
+            ));
+        }
+
+        graphml.push_str("  <graph id=\"flamecast\" edgedefault=\"directed\">\n");
+        graphml.push_str(&format!("    <data key=\"graph_alpha\">{}</data>\n", alpha));
+        graphml.push_str(&format!(
+            "    <data key=\"graph_cost\">{}</data>\n",
+            total_cost
+        ));
+
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            for vertex_index in 0..layer.vertices.len() {
+                let embedding = embeddings[layer_index][vertex_index];
+                graphml.push_str(&format!(
+                    "    <node id=\"{}\">\n",
+                    get_vertex_name(layer_index, vertex_index)
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_x\">{}</data>\n",
+                    embedding.0
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_y\">{}</data>\n",
+                    embedding.1
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_layer\">{}</data>\n",
+                    layer_index
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_index\">{}</data>\n",
+                    vertex_index
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_type\">{}</data>\n",
+                    get_vertex_type(layer_index, graph.layers.len())
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"node_flow\">{}</data>\n",
+                    vertex_flows[layer_index][vertex_index]
+                ));
+                graphml.push_str("    </node>\n");
+            }
+        }
+
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            if layer_index == graph.layers.len() - 1 {
+                break;
+            }
+
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                graphml.push_str(&format!(
+                    "    <edge source=\"{}\" target=\"{}\">\n",
+                    get_vertex_name(layer_index, vertex_index),
+                    get_vertex_name(layer_index + 1, vertex.parent_index.unwrap())
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"edge_flow\">{}</data>\n",
+                    vertex_flows[layer_index][vertex_index]
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"edge_length\">{}</data>\n",
+                    edge_lengths[layer_index][vertex_index]
+                ));
+                graphml.push_str(&format!(
+                    "      <data key=\"edge_cost\">{}</data>\n",
+                    edge_costs[layer_index][vertex_index]
+                ));
+                graphml.push_str("    </edge>\n");
+            }
+        }
+
+        graphml.push_str("  </graph>\n");
+        graphml.push_str("</graphml>\n");
+
+        return graphml;
+    }
+}
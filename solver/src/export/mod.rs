@@ -0,0 +1,19 @@
+mod dot;
+mod export_format;
+mod geojson;
+mod graphml;
+
+pub use export_format::*;
+
+pub fn get_vertex_name(layer_index: usize, vertex_index: usize) -> String {
+    return format!("v{}_{}", layer_index, vertex_index);
+}
+
+pub fn get_vertex_type(layer_index: usize, num_layers: usize) -> &'static str {
+    if layer_index == 0 {
+        return "source";
+    } else if layer_index == num_layers - 1 {
+        return "drain";
+    }
+    return "inner";
+}
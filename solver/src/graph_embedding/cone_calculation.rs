@@ -1,6 +1,104 @@
-use clarabel::solver::SupportedConeT::{self, SecondOrderConeT};
+use clarabel::solver::SupportedConeT::{self, NonnegativeConeT, PowerConeT, SecondOrderConeT};
 
-pub fn calculate_cones(number_of_edges: usize) -> Vec<SupportedConeT<f64>> {
+use crate::DistanceMetric;
+
+// every edge has the rows (d, dx, dy) in the euclidean formulation, the other metrics map these rows to their own cones
+pub const EUCLIDEAN_ROWS_PER_EDGE: usize = 3;
+
+pub fn calculate_cones(
+    number_of_edges: usize,
+    metric: &DistanceMetric,
+) -> Vec<SupportedConeT<f64>> {
     // calculate cones for clarabel
-    return vec![SecondOrderConeT(3); number_of_edges];
+    return match metric {
+        DistanceMetric::Euclidean => vec![SecondOrderConeT(3); number_of_edges],
+        // d - |dx| - |dy| >= 0 is split into four linear constraints d +- dx +- dy >= 0
+        DistanceMetric::Manhattan => vec![NonnegativeConeT(4 * number_of_edges)],
+        // |dx| <= r1^(1/p) * d^(1-1/p), |dy| <= r2^(1/p) * d^(1-1/p) and r1 + r2 <= d
+        DistanceMetric::Lp(p) => {
+            let mut cones = vec![PowerConeT(1.0 / p); 2 * number_of_edges];
+            cones.push(NonnegativeConeT(number_of_edges));
+            cones
+        }
+    };
+}
+
+pub fn get_number_of_cone_rows(number_of_edges: usize, metric: &DistanceMetric) -> usize {
+    return match metric {
+        DistanceMetric::Euclidean => EUCLIDEAN_ROWS_PER_EDGE * number_of_edges,
+        DistanceMetric::Manhattan => 4 * number_of_edges,
+        DistanceMetric::Lp(_) => 7 * number_of_edges,
+    };
+}
+
+pub fn get_number_of_auxiliary_variables(number_of_edges: usize, metric: &DistanceMetric) -> usize {
+    // the p-norm needs the variables r1 and r2 for every edge
+    return match metric {
+        DistanceMetric::Lp(_) => 2 * number_of_edges,
+        _ => 0,
+    };
+}
+
+pub fn get_metric_rows(
+    euclidean_row: usize,
+    number_of_edges: usize,
+    metric: &DistanceMetric,
+) -> Vec<(usize, f64)> {
+    // returns the rows and factors the euclidean row (d, dx or dy of an edge) contributes to
+    let edge_index = euclidean_row / EUCLIDEAN_ROWS_PER_EDGE;
+    let component = euclidean_row % EUCLIDEAN_ROWS_PER_EDGE;
+
+    return match metric {
+        DistanceMetric::Euclidean => vec![(euclidean_row, 1.0)],
+        DistanceMetric::Manhattan => {
+            let signs = [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)];
+            signs
+                .iter()
+                .enumerate()
+                .map(|(sign_index, (x_sign, y_sign))| {
+                    let factor = match component {
+                        0 => 1.0,
+                        1 => *x_sign,
+                        _ => *y_sign,
+                    };
+                    (4 * edge_index + sign_index, factor)
+                })
+                .collect()
+        }
+        DistanceMetric::Lp(_) => {
+            // the power cones of an edge are (r1, d, dx) and (r2, d, dy), the linear rows follow after all power cones
+            let base_row = 6 * edge_index;
+            match component {
+                0 => vec![
+                    (base_row + 1, 1.0),
+                    (base_row + 4, 1.0),
+                    (6 * number_of_edges + edge_index, 1.0),
+                ],
+                1 => vec![(base_row + 2, 1.0)],
+                _ => vec![(base_row + 5, 1.0)],
+            }
+        }
+    };
+}
+
+pub fn get_auxiliary_entries(
+    number_of_edges: usize,
+    first_auxiliary_column: usize,
+    metric: &DistanceMetric,
+) -> Vec<(usize, usize, f64)> {
+    // returns (row, column, value) entries of the A-matrix for the auxiliary variables
+    let mut entries = Vec::new();
+    if let DistanceMetric::Lp(_) = metric {
+        for edge_index in 0..number_of_edges {
+            let r1_column = first_auxiliary_column + 2 * edge_index;
+            let r2_column = r1_column + 1;
+            let linear_row = 6 * number_of_edges + edge_index;
+
+            entries.push((6 * edge_index, r1_column, -1.0));
+            entries.push((6 * edge_index + 3, r2_column, -1.0));
+            entries.push((linear_row, r1_column, 1.0));
+            entries.push((linear_row, r2_column, 1.0));
+        }
+    }
+    return entries;
 }
@@ -1,18 +1,19 @@
-use crate::types::{LayeredGraph, VertexEmbeddings};
+use crate::types::{CostModel, DistanceMetric, LayeredGraph, MaxPathLength, VertexEmbeddings};
 use clarabel::solver::{
     DefaultInfo, DefaultSettings, DefaultSettingsBuilder, DefaultSolution, DefaultSolver, IPSolver,
+    SolverStatus,
 };
 
 use super::{
-    calculate_a_matrix, calculate_b_vector, calculate_cones, calculate_p_matrix,
-    calculate_q_vector, EmbeddingOptions,
+    append_path_length_constraints, calculate_a_matrix, calculate_b_vector, calculate_cones,
+    calculate_p_matrix, calculate_q_vector, EmbeddingOptions,
 };
 
 pub fn embed_directed_graph(
     graph: &LayeredGraph,
     sources_drains_embeddings: &VertexEmbeddings,
     edge_flows: &Vec<Vec<usize>>,
-    alpha: f64,
+    cost_model: &CostModel,
     options: &EmbeddingOptions,
 ) -> VertexEmbeddings {
     if graph.layers.len() == 2 {
@@ -26,9 +27,10 @@ pub fn embed_directed_graph(
     let number_of_regarded_vertices =
         number_of_vertices - graph.get_sources_indexes().len() - graph.get_drains_indexes().len();
     let number_of_edges = graph.get_number_of_edges();
+    let metric = &cost_model.metric;
 
     // calculate P-Matrix for clarabel
-    let p = calculate_p_matrix(number_of_regarded_vertices, number_of_edges);
+    let p = calculate_p_matrix(number_of_regarded_vertices, number_of_edges, metric);
 
     // calculate q-vector for clarabel
     let q = calculate_q_vector(
@@ -36,17 +38,30 @@ pub fn embed_directed_graph(
         edge_flows,
         number_of_regarded_vertices,
         number_of_edges,
-        alpha,
+        cost_model,
     );
 
     // calculate A-Matrix for clarabel
-    let a = calculate_a_matrix(graph, number_of_regarded_vertices, number_of_edges);
+    let mut a = calculate_a_matrix(graph, number_of_regarded_vertices, number_of_edges, metric);
 
     // calculate b-vector for clarabel
-    let b = calculate_b_vector(graph, number_of_edges, sources_drains_embeddings);
+    let mut b = calculate_b_vector(graph, number_of_edges, sources_drains_embeddings, metric);
 
     // set cones for clarabel
-    let cones = calculate_cones(number_of_edges);
+    let mut cones = calculate_cones(number_of_edges, metric);
+
+    // bound the summed edge lengths from the sources to their drains
+    let path_lengths_bounded = !cost_model.max_path_length.is_unbounded();
+    if path_lengths_bounded {
+        a = append_path_length_constraints(
+            &a,
+            &mut b,
+            &mut cones,
+            graph,
+            number_of_regarded_vertices,
+            &cost_model.max_path_length,
+        );
+    }
 
     // create settings for clarabel
     let settings: DefaultSettings<f64> = DefaultSettingsBuilder::default()
@@ -59,7 +74,26 @@ pub fn embed_directed_graph(
     let mut solver = DefaultSolver::new(&p, &q, &a, &b, &cones, settings);
     solver.solve();
 
-    // x is of the form [xi, xi+1, ..., xj, yi, yi+1, ..., yj, d1, d2, ..., dm], without the (x, y) of sources or drains
+    // if the topology cannot meet the path length bounds, the unbounded embedding is returned,
+    // callers detect the violation by checking the path lengths of the embedding
+    if path_lengths_bounded
+        && matches!(
+            solver.info.status,
+            SolverStatus::PrimalInfeasible | SolverStatus::AlmostPrimalInfeasible
+        )
+    {
+        return embed_directed_graph(
+            graph,
+            sources_drains_embeddings,
+            edge_flows,
+            &cost_model
+                .clone()
+                .with_max_path_length(MaxPathLength::Unbounded),
+            options,
+        );
+    }
+
+    // x is of the form [xi, xi+1, ..., xj, yi, yi+1, ..., yj, d1, d2, ..., dm, auxiliary variables], without the (x, y) of sources or drains
     let solution = &solver.solution.x;
 
     // save the solution in the VertexEmbeddings format
@@ -89,6 +123,7 @@ pub fn embed_directed_graph(
         &result,
         graph,
         number_of_regarded_vertices,
+        metric,
         options,
         &solver.info,
         &solver.solution,
@@ -101,6 +136,7 @@ fn print_informations(
     vertex_embeddings: &VertexEmbeddings,
     graph: &LayeredGraph,
     number_of_regarded_vertices: usize,
+    metric: &DistanceMetric,
     options: &EmbeddingOptions,
     solver_info: &DefaultInfo<f64>,
     solution: &DefaultSolution<f64>,
@@ -132,9 +168,7 @@ fn print_informations(
                     let target_embedding =
                         vertex_embeddings.embeddings[layer_index + 1][parent_index];
 
-                    let edge_length = ((source_embedding.0 - target_embedding.0).powi(2)
-                        + (source_embedding.1 - target_embedding.1).powi(2))
-                    .sqrt();
+                    let edge_length = metric.get_distance(&source_embedding, &target_embedding);
 
                     let dif = (edge_length - solution.x[solution_vertices_number + index]).abs();
                     if dif > max_diff {
@@ -2,14 +2,22 @@ use std::{collections::HashMap, vec};
 
 use clarabel::algebra::CscMatrix;
 
-use crate::LayeredGraph;
+use crate::{DistanceMetric, LayeredGraph};
+
+use super::{
+    get_auxiliary_entries, get_metric_rows, get_number_of_auxiliary_variables,
+    get_number_of_cone_rows,
+};
 
 pub fn calculate_p_matrix(
     number_of_regarded_vertices: usize,
     number_of_edges: usize,
+    metric: &DistanceMetric,
 ) -> CscMatrix<f64> {
     // calculate P-Matrix for clarabel
-    let solution_dimension = 2 * number_of_regarded_vertices + number_of_edges;
+    let solution_dimension = 2 * number_of_regarded_vertices
+        + number_of_edges
+        + get_number_of_auxiliary_variables(number_of_edges, metric);
 
     return CscMatrix::new(
         solution_dimension,
@@ -24,9 +32,51 @@ pub fn calculate_a_matrix(
     graph: &LayeredGraph,
     number_of_regarded_vertices: usize,
     number_of_edges: usize,
+    metric: &DistanceMetric,
 ) -> CscMatrix<f64> {
     // calculate A-Matrix for clarabel
+    let euclidean_a =
+        calculate_euclidean_a_matrix(graph, number_of_regarded_vertices, number_of_edges);
+
+    if *metric == DistanceMetric::Euclidean {
+        return euclidean_a;
+    }
+
+    // map the rows of the euclidean formulation to the rows of the metric
+    let mut rows = Vec::new();
+    let mut cols = Vec::new();
+    let mut values = Vec::new();
+    for col in 0..euclidean_a.n {
+        for index in euclidean_a.colptr[col]..euclidean_a.colptr[col + 1] {
+            let euclidean_row = euclidean_a.rowval[index];
+            for (row, factor) in get_metric_rows(euclidean_row, number_of_edges, metric) {
+                rows.push(row);
+                cols.push(col);
+                values.push(factor * euclidean_a.nzval[index]);
+            }
+        }
+    }
+
+    for (row, col, value) in get_auxiliary_entries(number_of_edges, euclidean_a.n, metric) {
+        rows.push(row);
+        cols.push(col);
+        values.push(value);
+    }
 
+    return CscMatrix::new_from_triplets(
+        get_number_of_cone_rows(number_of_edges, metric),
+        euclidean_a.n + get_number_of_auxiliary_variables(number_of_edges, metric),
+        rows,
+        cols,
+        values,
+    );
+}
+
+fn calculate_euclidean_a_matrix(
+    graph: &LayeredGraph,
+    number_of_regarded_vertices: usize,
+    number_of_edges: usize,
+) -> CscMatrix<f64> {
     let mut col_ptr = vec![0; 2 * number_of_regarded_vertices + number_of_edges + 1];
     let mut row_val = Vec::new();
     let mut values = Vec::new();
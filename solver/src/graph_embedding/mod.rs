@@ -2,10 +2,12 @@ mod cone_calculation;
 mod embed;
 mod matrix_calculation;
 mod options;
+mod path_length_constraints;
 mod vector_calculation;
 
 pub use cone_calculation::*;
 pub use embed::*;
 pub use matrix_calculation::*;
 pub use options::*;
+pub use path_length_constraints::*;
 pub use vector_calculation::*;
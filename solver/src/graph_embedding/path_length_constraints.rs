@@ -0,0 +1,67 @@
+use clarabel::{algebra::CscMatrix, solver::SupportedConeT};
+
+use crate::{LayeredGraph, MaxPathLength};
+
+pub fn append_path_length_constraints(
+    a: &CscMatrix<f64>,
+    b: &mut Vec<f64>,
+    cones: &mut Vec<SupportedConeT<f64>>,
+    graph: &LayeredGraph,
+    number_of_regarded_vertices: usize,
+    max_path_length: &MaxPathLength,
+) -> CscMatrix<f64> {
+    // every bounded source gets the row d_1 + ... + d_k + s = max path length with s >= 0,
+    // where d_1, ..., d_k are the length variables of the edges on its path to the drain
+    let edge_offsets = get_edge_offsets(graph);
+    let first_edge_column = 2 * number_of_regarded_vertices;
+
+    let mut rows = Vec::new();
+    let mut cols = Vec::new();
+    let mut values = Vec::new();
+    for col in 0..a.n {
+        for index in a.colptr[col]..a.colptr[col + 1] {
+            rows.push(a.rowval[index]);
+            cols.push(col);
+            values.push(a.nzval[index]);
+        }
+    }
+
+    let mut number_of_constraints = 0;
+    for source_index in 0..graph.layers[0].vertices.len() {
+        let source_max_path_length = max_path_length.get_max_path_length(source_index);
+        if source_max_path_length.is_infinite() {
+            continue;
+        }
+
+        let row = a.m + number_of_constraints;
+        let mut vertex_index = source_index;
+        // the drains have no outgoing edge
+        let edge_layers = graph.layers.iter().take(graph.layers.len() - 1);
+        for (layer_index, layer) in edge_layers.enumerate() {
+            rows.push(row);
+            cols.push(first_edge_column + edge_offsets[layer_index] + vertex_index);
+            values.push(1.0);
+            vertex_index = layer.vertices[vertex_index].parent_index.unwrap();
+        }
+
+        b.push(source_max_path_length);
+        number_of_constraints += 1;
+    }
+
+    if number_of_constraints > 0 {
+        cones.push(SupportedConeT::NonnegativeConeT(number_of_constraints));
+    }
+
+    return CscMatrix::new_from_triplets(a.m + number_of_constraints, a.n, rows, cols, values);
+}
+
+fn get_edge_offsets(graph: &LayeredGraph) -> Vec<usize> {
+    // index of the first edge of every layer, the edges are numbered layer by layer
+    let mut edge_offsets = Vec::new();
+    let mut edge_offset = 0;
+    for layer in graph.layers.iter() {
+        edge_offsets.push(edge_offset);
+        edge_offset += layer.vertices.len();
+    }
+    return edge_offsets;
+}
@@ -1,22 +1,30 @@
-use crate::{types::VertexEmbeddings, LayeredGraph};
+use crate::{types::VertexEmbeddings, CostModel, DistanceMetric, LayeredGraph};
+
+use super::{get_metric_rows, get_number_of_auxiliary_variables, get_number_of_cone_rows};
 
 pub fn calculate_q_vector(
     graph: &LayeredGraph,
     edge_flows: &Vec<Vec<usize>>,
     number_of_regarded_vertices: usize,
     number_of_edges: usize,
-    alpha: f64,
+    cost_model: &CostModel,
 ) -> Vec<f64> {
-    // calculate q-vector for clarabel
-    let mut q = vec![0.0 as f64; 2 * number_of_regarded_vertices + number_of_edges];
+    // calculate q-vector for clarabel, the auxiliary variables of the metric have no costs
+    let mut q = vec![
+        0.0 as f64;
+        2 * number_of_regarded_vertices
+            + number_of_edges
+            + get_number_of_auxiliary_variables(number_of_edges, &cost_model.metric)
+    ];
 
     let mut edge_index = 0;
     for layer_index in 0..graph.layers.len() - 1 {
         let layer = &graph.layers[layer_index];
 
         for vertex_index in 0..layer.vertices.len() {
-            let flow = edge_flows[layer_index][vertex_index] as f64;
-            let flow_weight = flow.powf(alpha);
+            // the flows are fixed by the topology, so every edge cost function stays linear in the length
+            let flow_weight =
+                cost_model.get_flow_weight(layer_index, edge_flows[layer_index][vertex_index]);
             q[2 * number_of_regarded_vertices + edge_index] = flow_weight;
             edge_index += 1;
         }
@@ -29,8 +37,31 @@ pub fn calculate_b_vector(
     graph: &LayeredGraph,
     number_of_edges: usize,
     sources_drains_embeddings: &VertexEmbeddings,
+    metric: &DistanceMetric,
 ) -> Vec<f64> {
     // calculate b-vector for clarabel
+    let euclidean_b =
+        calculate_euclidean_b_vector(graph, number_of_edges, sources_drains_embeddings);
+
+    if *metric == DistanceMetric::Euclidean {
+        return euclidean_b;
+    }
+
+    let mut b = vec![0.0; get_number_of_cone_rows(number_of_edges, metric)];
+    for (euclidean_row, value) in euclidean_b.iter().enumerate() {
+        for (row, factor) in get_metric_rows(euclidean_row, number_of_edges, metric) {
+            b[row] += factor * value;
+        }
+    }
+
+    return b;
+}
+
+fn calculate_euclidean_b_vector(
+    graph: &LayeredGraph,
+    number_of_edges: usize,
+    sources_drains_embeddings: &VertexEmbeddings,
+) -> Vec<f64> {
     let mut b = vec![0.0; 3 * number_of_edges];
 
     graph.layers[0]
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use pathfinding::{matrix::Matrix, prelude::kuhn_munkres_min};
 
-use crate::{Layer, LayeredGraph, Vertex, VertexEmbeddings, VertexID};
+use crate::{Layer, LayeredGraph, MaxChildren, Vertex, VertexEmbeddings, VertexID};
 
 use super::calculate_sources_drains_weights;
 
@@ -10,11 +10,14 @@ use super::calculate_sources_drains_weights;
 pub fn generate_low_connectivity_flamecast_graph(
     num_layers: usize,
     capacities: &Vec<usize>,
+    max_children: &MaxChildren,
     sources_size: usize,
     drains_size: usize,
     sources_drains_embeddings: &VertexEmbeddings,
 ) -> LayeredGraph {
-    let drain_capacity = capacities[num_layers - 1];
+    // every source has its own chain, so the sources of a drain are its children
+    let drain_capacity =
+        capacities[num_layers - 1].min(max_children.get_max_children(num_layers - 1));
 
     let weights = calculate_sources_drains_weights(
         sources_drains_embeddings,
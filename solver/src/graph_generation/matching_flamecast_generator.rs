@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use ordered_float::OrderedFloat;
 use pathfinding::{matrix::Matrix, prelude::kuhn_munkres_min};
 
-use crate::{Layer, LayeredGraph, Vertex, VertexEmbeddings};
+use crate::{Layer, LayeredGraph, MaxChildren, Vertex, VertexEmbeddings};
 
 use super::k_means_recursive;
 
@@ -11,11 +11,13 @@ use super::k_means_recursive;
 pub fn generate_matching_flamecast_graph(
     num_layers: usize,
     capacities: &Vec<usize>,
+    max_children: &MaxChildren,
     sources_size: usize,
     drains_size: usize,
     sources_drains_embeddings: &VertexEmbeddings,
 ) -> LayeredGraph {
-    let drain_capacity = capacities[num_layers - 1];
+    // the number of sources a drain can serve also depends on the max children below it
+    let drain_capacity = max_children.get_max_sources_per_vertex(capacities)[num_layers - 1];
 
     let weights = calculate_sources_drains_weights(
         sources_drains_embeddings,
@@ -59,6 +61,7 @@ pub fn generate_matching_flamecast_graph(
             drain_id.layer - 1,
             corresponding_sources,
             capacities,
+            max_children,
             sources_embeddings,
         );
     }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use rand::Rng;
 
-use crate::{Layer, LayeredGraph, Vertex, VertexEmbedding};
+use crate::{Layer, LayeredGraph, MaxChildren, Vertex, VertexEmbedding};
 
 use super::k_means_recursive;
 
@@ -12,6 +12,7 @@ pub type VertexSourcesMapping = HashMap<usize, Vec<usize>>;
 pub fn generate_random_flamecast_graph(
     num_layers: usize,
     capacities: &Vec<usize>,
+    max_children: &MaxChildren,
     sources_size: usize,
     drains_size: usize,
     sources_embeddings: &Vec<VertexEmbedding>,
@@ -19,7 +20,8 @@ pub fn generate_random_flamecast_graph(
     let mut graph = LayeredGraph::new_with_size(num_layers);
     graph.layers[0] = Layer::new_with_size(sources_size);
 
-    let drain_capacity = capacities[num_layers - 1];
+    // the number of sources a drain can serve also depends on the max children below it
+    let drain_capacity = max_children.get_max_sources_per_vertex(capacities)[num_layers - 1];
     let drain_sources_mappings = assign_sources_to_vertices(
         &(0..sources_size).collect(),
         &(0..drains_size).collect(),
@@ -40,6 +42,7 @@ pub fn generate_random_flamecast_graph(
             drain_id.layer - 1,
             corresponding_sources,
             capacities,
+            max_children,
             sources_embeddings,
         );
     }
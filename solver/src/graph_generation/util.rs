@@ -1,6 +1,6 @@
 use clustering::kmeans;
 
-use crate::{LayeredGraph, Vertex, VertexEmbedding, VertexID};
+use crate::{LayeredGraph, MaxChildren, Vertex, VertexEmbedding, VertexID};
 
 const MAX_ITER: usize = 40;
 
@@ -10,6 +10,7 @@ pub fn k_means_recursive(
     layer_index: usize,
     sources: &Vec<usize>,
     capacities: &Vec<usize>,
+    max_children: &MaxChildren,
     sources_embeddings: &Vec<VertexEmbedding>,
 ) {
     if layer_index == 0 {
@@ -21,9 +22,14 @@ pub fn k_means_recursive(
         return;
     }
 
-    let capacity = capacities[layer_index];
+    // a cluster must also fit below a vertex of the layer with the max children of its subtree
+    let capacity = max_children.get_max_sources_per_vertex(capacities)[layer_index];
 
-    let cluster_mappings = get_cluster_mappings(sources, capacity, sources_embeddings);
+    let mut cluster_mappings = get_cluster_mappings(sources, capacity, sources_embeddings);
+    // reclustering oversized clusters can create more children than the parent may have
+    if cluster_mappings.len() > max_children.get_max_children(layer_index + 1) {
+        cluster_mappings = get_balanced_cluster_mappings(sources, capacity, sources_embeddings);
+    }
 
     for cluster in cluster_mappings {
         let mut new_vertex = Vertex::new_empty();
@@ -36,21 +42,26 @@ pub fn k_means_recursive(
             layer_index - 1,
             &cluster,
             capacities,
+            max_children,
             sources_embeddings,
         );
     }
 }
 
+fn get_number_of_clusters(sources: &Vec<usize>, capacity: usize) -> usize {
+    return if sources.len() % capacity == 0 {
+        sources.len() / capacity
+    } else {
+        sources.len() / capacity + 1
+    };
+}
+
 fn get_cluster_mappings(
     sources: &Vec<usize>,
     capacity: usize,
     sources_embeddings: &Vec<VertexEmbedding>,
 ) -> Vec<Vec<usize>> {
-    let k = if sources.len() % capacity == 0 {
-        sources.len() / capacity
-    } else {
-        sources.len() / capacity + 1
-    };
+    let k = get_number_of_clusters(sources, capacity);
 
     let mut samples = vec![vec![0.0; 2]; sources.len()];
 
@@ -80,3 +91,39 @@ fn get_cluster_mappings(
 
     return result;
 }
+
+fn get_balanced_cluster_mappings(
+    sources: &Vec<usize>,
+    capacity: usize,
+    sources_embeddings: &Vec<VertexEmbedding>,
+) -> Vec<Vec<usize>> {
+    // the minimal number of clusters of (nearly) equal size, the sources are sorted along
+    // the axis with the larger spread, so that every cluster is a strip of neighboring sources
+    let k = get_number_of_clusters(sources, capacity);
+    let cluster_size = sources.len().div_ceil(k);
+
+    let spread = |axis: fn(&VertexEmbedding) -> f64| {
+        let values = sources
+            .iter()
+            .map(|source| axis(&sources_embeddings[*source]));
+        values.clone().fold(f64::NEG_INFINITY, f64::max) - values.fold(f64::INFINITY, f64::min)
+    };
+    let axis: fn(&VertexEmbedding) -> f64 =
+        if spread(|embedding| embedding.0) >= spread(|embedding| embedding.1) {
+            |embedding| embedding.0
+        } else {
+            |embedding| embedding.1
+        };
+
+    let mut sorted_sources = sources.clone();
+    sorted_sources.sort_by(|a, b| {
+        axis(&sources_embeddings[*a])
+            .partial_cmp(&axis(&sources_embeddings[*b]))
+            .unwrap()
+    });
+
+    return sorted_sources
+        .chunks(cluster_size)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+}
@@ -0,0 +1,83 @@
+use super::{parse_point_role, ImportedPoints};
+
+pub fn parse_csv_points(content: &str) -> Result<ImportedPoints, String> {
+    // every line contains the coordinates and the role (source or drain) of one point,
+    // the columns are taken from the header if there is one and are x, y, type otherwise
+    let mut points = ImportedPoints::new();
+    let mut columns = (0, 1, 2);
+    let mut is_first_line = true;
+
+    for (line_index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = split_csv_line(line);
+        if is_first_line {
+            is_first_line = false;
+            if let Some(header_columns) = parse_csv_header(&fields) {
+                columns = header_columns;
+                continue;
+            }
+        }
+
+        let (x_column, y_column, role_column) = columns;
+        let get_field = |column: usize| {
+            fields.get(column).ok_or(format!(
+                "Line {} has only {} columns.",
+                line_index + 1,
+                fields.len()
+            ))
+        };
+        let x = get_field(x_column)?
+            .parse::<f64>()
+            .map_err(|_| format!("Invalid x coordinate in line {}.", line_index + 1))?;
+        let y = get_field(y_column)?
+            .parse::<f64>()
+            .map_err(|_| format!("Invalid y coordinate in line {}.", line_index + 1))?;
+        let is_source = parse_point_role(get_field(role_column)?)
+            .ok_or(format!("Unknown point type in line {}.", line_index + 1))?;
+
+        if is_source {
+            points.sources.push((x, y));
+        } else {
+            points.drains.push((x, y));
+        }
+    }
+
+    return Ok(points);
+}
+
+fn split_csv_line(line: &str) -> Vec<&str> {
+    let delimiter = if line.contains(',') {
+        Some(',')
+    } else if line.contains(';') {
+        Some(';')
+    } else if line.contains('\t') {
+        Some('\t')
+    } else {
+        None
+    };
+
+    return match delimiter {
+        Some(delimiter) => line.split(delimiter).map(|field| field.trim()).collect(),
+        None => line.split_whitespace().collect(),
+    };
+}
+
+fn parse_csv_header(fields: &Vec<&str>) -> Option<(usize, usize, usize)> {
+    let find_column = |names: &[&str]| {
+        fields.iter().position(|field| {
+            names
+                .iter()
+                .any(|name| field.trim_matches('"').eq_ignore_ascii_case(name))
+        })
+    };
+
+    let x_column = find_column(&["x", "lon", "lng", "longitude"])?;
+    let y_column = find_column(&["y", "lat", "latitude"])?;
+    let role_column = find_column(&["type", "role", "kind"])?;
+
+    return Some((x_column, y_column, role_column));
+}
@@ -0,0 +1,47 @@
+use serde_json::Value;
+
+use super::{parse_point_role, ImportedPoints};
+
+pub fn parse_geojson_points(content: &str) -> Result<ImportedPoints, String> {
+    // every point feature needs a "type" or "role" property that marks it as source or drain,
+    // all other features are ignored so that exported solutions can be imported again
+    let geojson = serde_json::from_str::<Value>(content)
+        .map_err(|error| format!("Invalid GeoJSON: {}", error))?;
+    let features = geojson["features"]
+        .as_array()
+        .ok_or("GeoJSON is not a FeatureCollection.")?;
+
+    let mut points = ImportedPoints::new();
+    for (feature_index, feature) in features.iter().enumerate() {
+        if feature["geometry"]["type"] != "Point" {
+            continue;
+        }
+
+        let properties = &feature["properties"];
+        let role = properties["type"]
+            .as_str()
+            .or(properties["role"].as_str())
+            .unwrap_or("");
+        if role == "inner" {
+            continue;
+        }
+        let is_source = parse_point_role(role)
+            .ok_or(format!("Unknown point type of feature {}.", feature_index))?;
+
+        let coordinates = &feature["geometry"]["coordinates"];
+        let x = coordinates[0]
+            .as_f64()
+            .ok_or(format!("Invalid coordinates of feature {}.", feature_index))?;
+        let y = coordinates[1]
+            .as_f64()
+            .ok_or(format!("Invalid coordinates of feature {}.", feature_index))?;
+
+        if is_source {
+            points.sources.push((x, y));
+        } else {
+            points.drains.push((x, y));
+        }
+    }
+
+    return Ok(points);
+}
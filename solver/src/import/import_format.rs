@@ -0,0 +1,44 @@
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    GeoJson,
+    Tsplib,
+}
+
+impl ImportFormat {
+    pub fn detect(file_path: &str, content: &str) -> Self {
+        // the file extension is used if it is known, otherwise the content decides
+        let extension = Path::new(file_path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+        match extension.as_deref() {
+            Some("csv") => return ImportFormat::Csv,
+            Some("geojson") => return ImportFormat::GeoJson,
+            Some("tsp") | Some("vrp") => return ImportFormat::Tsplib,
+            _ => {}
+        }
+
+        return Self::detect_from_content(content);
+    }
+
+    pub fn detect_from_content(content: &str) -> Self {
+        if content.trim_start().starts_with('{') {
+            return ImportFormat::GeoJson;
+        }
+        if content.contains("NODE_COORD_SECTION") {
+            return ImportFormat::Tsplib;
+        }
+        return ImportFormat::Csv;
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ImportFormat::Csv => String::from("CSV"),
+            ImportFormat::GeoJson => String::from("GeoJSON"),
+            ImportFormat::Tsplib => String::from("TSPLIB"),
+        }
+    }
+}
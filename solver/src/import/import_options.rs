@@ -0,0 +1,37 @@
+use super::ImportFormat;
+
+pub const DEFAULT_IMPORT_NUM_LAYERS: usize = 5;
+pub const DEFAULT_IMPORT_ALPHA: f64 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    pub num_layers: usize,
+    pub capacities: Option<Vec<usize>>, // None uses capacity 1 for sources and no limit for all other layers
+    pub alpha: f64,
+    pub format: Option<ImportFormat>, // None detects the format automatically
+}
+
+impl ImportOptions {
+    pub fn new(
+        num_layers: usize,
+        capacities: Option<Vec<usize>>,
+        alpha: f64,
+        format: Option<ImportFormat>,
+    ) -> Self {
+        Self {
+            num_layers,
+            capacities,
+            alpha,
+            format,
+        }
+    }
+
+    pub fn default() -> Self {
+        Self {
+            num_layers: DEFAULT_IMPORT_NUM_LAYERS,
+            capacities: None,
+            alpha: DEFAULT_IMPORT_ALPHA,
+            format: None,
+        }
+    }
+}
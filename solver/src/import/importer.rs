@@ -0,0 +1,94 @@
+use std::fs;
+
+use crate::{FlamecastTestInstance, VertexEmbeddings};
+
+use super::{
+    parse_csv_points, parse_geojson_points, parse_tsplib_points, ImportFormat, ImportOptions,
+    ImportedPoints,
+};
+
+pub fn import_flamecast_instance(
+    file_path: &str,
+    options: &ImportOptions,
+) -> Result<FlamecastTestInstance, String> {
+    let content = fs::read_to_string(file_path)
+        .map_err(|error| format!("Failed to read {}: {}", file_path, error))?;
+    let format = match options.format {
+        Some(format) => format,
+        None => ImportFormat::detect(file_path, &content),
+    };
+
+    return parse_flamecast_instance(&content, format, options);
+}
+
+pub fn parse_flamecast_instance(
+    content: &str,
+    format: ImportFormat,
+    options: &ImportOptions,
+) -> Result<FlamecastTestInstance, String> {
+    let points = match format {
+        ImportFormat::Csv => parse_csv_points(content),
+        ImportFormat::GeoJson => parse_geojson_points(content),
+        ImportFormat::Tsplib => parse_tsplib_points(content),
+    }
+    .map_err(|error| format!("{} import failed: {}", format.to_string(), error))?;
+
+    return build_flamecast_instance(points, options);
+}
+
+fn build_flamecast_instance(
+    points: ImportedPoints,
+    options: &ImportOptions,
+) -> Result<FlamecastTestInstance, String> {
+    let num_sources = points.sources.len();
+    let num_drains = points.drains.len();
+    let num_layers = options.num_layers;
+
+    if num_sources == 0 {
+        return Err(String::from("The instance has no sources."));
+    }
+    if num_drains == 0 {
+        return Err(String::from("The instance has no drains."));
+    }
+    if num_layers < 2 {
+        return Err(String::from("An instance needs at least two layers."));
+    }
+    if !(0.0..=1.0).contains(&options.alpha) {
+        return Err(format!("Alpha {} is not in [0, 1].", options.alpha));
+    }
+
+    let capacities = match &options.capacities {
+        Some(capacities) => capacities.clone(),
+        None => {
+            let mut capacities = vec![num_sources; num_layers];
+            capacities[0] = 1;
+            capacities
+        }
+    };
+    if capacities.len() != num_layers {
+        return Err(format!(
+            "Expected {} capacities but got {}.",
+            num_layers,
+            capacities.len()
+        ));
+    }
+    if capacities[0] == 0 {
+        return Err(String::from("Sources need a capacity of at least 1."));
+    }
+    if capacities[num_layers - 1] * num_drains < num_sources {
+        return Err(String::from(
+            "The capacity of the drains is too small for the number of sources.",
+        ));
+    }
+
+    let mut sources_drains_embeddings = VertexEmbeddings::new_with_size(num_layers);
+    sources_drains_embeddings.embeddings[0] = points.sources;
+    sources_drains_embeddings.embeddings[num_layers - 1] = points.drains;
+
+    return Ok(FlamecastTestInstance::new(
+        options.alpha,
+        num_layers,
+        capacities,
+        sources_drains_embeddings,
+    ));
+}
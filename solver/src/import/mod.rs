@@ -0,0 +1,39 @@
+mod csv;
+mod geojson;
+mod import_format;
+mod import_options;
+mod importer;
+mod tsplib;
+
+pub use csv::*;
+pub use geojson::*;
+pub use import_format::*;
+pub use import_options::*;
+pub use importer::*;
+pub use tsplib::*;
+
+use crate::VertexEmbedding;
+
+#[derive(Debug, Clone)]
+pub struct ImportedPoints {
+    pub sources: Vec<VertexEmbedding>,
+    pub drains: Vec<VertexEmbedding>,
+}
+
+impl ImportedPoints {
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            drains: Vec::new(),
+        }
+    }
+}
+
+pub fn parse_point_role(role: &str) -> Option<bool> {
+    // returns true for sources and false for drains
+    return match role.trim().to_lowercase().as_str() {
+        "source" | "sources" | "s" => Some(true),
+        "drain" | "drains" | "sink" | "sinks" | "d" | "t" => Some(false),
+        _ => None,
+    };
+}
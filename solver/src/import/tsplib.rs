@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::VertexEmbedding;
+
+use super::ImportedPoints;
+
+pub fn parse_tsplib_points(content: &str) -> Result<ImportedPoints, String> {
+    // the nodes of the NODE_COORD_SECTION are sources, the nodes listed in the
+    // DEPOT_SECTION or DRAIN_SECTION are drains
+    let mut coordinates: Vec<(usize, VertexEmbedding)> = Vec::new();
+    let mut drain_ids = HashSet::new();
+    let mut current_section = "";
+
+    for (line_index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line == "EOF" {
+            continue;
+        }
+
+        if line.ends_with("_SECTION") {
+            current_section = line;
+            continue;
+        }
+        if line.contains(':') {
+            // specification entries like NAME or DIMENSION
+            current_section = "";
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        match current_section {
+            "NODE_COORD_SECTION" => {
+                if fields.len() < 3 {
+                    return Err(format!(
+                        "Invalid node coordinates in line {}.",
+                        line_index + 1
+                    ));
+                }
+                let id = fields[0]
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid node id in line {}.", line_index + 1))?;
+                let x = fields[1]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid x coordinate in line {}.", line_index + 1))?;
+                let y = fields[2]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid y coordinate in line {}.", line_index + 1))?;
+                coordinates.push((id, (x, y)));
+            }
+            "DEPOT_SECTION" | "DRAIN_SECTION" => {
+                for field in fields {
+                    let id = field
+                        .parse::<i64>()
+                        .map_err(|_| format!("Invalid node id in line {}.", line_index + 1))?;
+                    // the list of ids is terminated by -1
+                    if id >= 0 {
+                        drain_ids.insert(id as usize);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if coordinates.is_empty() {
+        return Err(String::from("No NODE_COORD_SECTION found."));
+    }
+
+    let known_ids = coordinates
+        .iter()
+        .map(|(id, coordinate)| (*id, *coordinate))
+        .collect::<HashMap<usize, VertexEmbedding>>();
+    if let Some(unknown_id) = drain_ids.iter().find(|id| !known_ids.contains_key(id)) {
+        return Err(format!("Drain {} has no coordinates.", unknown_id));
+    }
+
+    let mut points = ImportedPoints::new();
+    for (id, coordinate) in coordinates {
+        if drain_ids.contains(&id) {
+            points.drains.push(coordinate);
+        } else {
+            points.sources.push(coordinate);
+        }
+    }
+
+    return Ok(points);
+}
@@ -1,25 +1,44 @@
+mod export;
 mod graph_embedding;
 mod graph_generation;
+mod import;
 mod neighborhood;
 mod plotting;
+mod schema;
 mod simulated_annealing;
 mod tests;
 mod timer;
 mod types;
 
 pub use chrono::TimeDelta;
+pub use export::ExportFormat;
 use graph_embedding::embed_directed_graph;
 pub use graph_embedding::{EmbeddingOptions, SearchDepth};
 use graph_generation::generate_random_directed_graph;
+use import::{import_flamecast_instance, parse_flamecast_instance};
+pub use import::{ImportFormat, ImportOptions};
 pub use neighborhood::Neighbor;
-pub use plotting::PlottingVertices;
-use plotting::{plot_embedded_graph, plot_vertices_with_colors};
+use plotting::{
+    plot_annealing_run, plot_embedded_graph, plot_embedded_graph_with_options,
+    plot_vertices_with_colors, plot_vertices_with_colors_and_options,
+};
+pub use plotting::{
+    AnimationFormat, AnimationOptions, PlotFormat, PlottingOptions, PlottingVertices,
+};
+pub use schema::{
+    FlamecastInstanceFile, FlamecastSolutionFile, VerificationMismatch, VerificationReport,
+    INSTANCE_SCHEMA_VERSION, REEMBEDDING_TOLERANCE, SOLUTION_SCHEMA_VERSION,
+    VERIFICATION_TOLERANCE,
+};
 pub use simulated_annealing::{
-    CoolingSchedule, InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
-    SimulatedAnnealingLogger, TimeDeltaSave,
+    AlphaSweep, AlphaSweepPoint, CancellationToken, CoolingSchedule, InitialSolutionFunction,
+    IterationProgress, LayerCountCandidate, LayerCountSearch, MultiObjectiveOption,
+    NeighborSearchOption, OptimizationOptions, SimulatedAnnealingLogger, SolveObserver,
+    TimeDeltaSave,
 };
 use tests::{
-    combine_test_graphs, generate_random_flamecast_instance, FLAMECAST_TEST_INSTANCES,
+    combine_test_graphs, create_random_flamecast_instance, generate_random_flamecast_instance,
+    generate_random_flamecast_instance_with_layer_costs, FLAMECAST_TEST_INSTANCES,
     NEIGHBORHOOD_TEST_INSTANCES, TESTGRAPHS,
 };
 pub use tests::{FlamecastTestInstance, TestGraph};
@@ -49,6 +68,36 @@ pub fn generate_random_flamecast_test_instance(
     );
 }
 
+pub fn generate_random_flamecast_test_instance_with_layer_costs(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    clear_structure: bool,
+) -> FlamecastTestInstance {
+    return generate_random_flamecast_instance_with_layer_costs(
+        num_layers,
+        num_sources,
+        num_drains,
+        clear_structure,
+    );
+}
+
+pub fn generate_random_initialized_flamecast_instance(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    capacities: Option<Vec<usize>>,
+    initial_solution_function: InitialSolutionFunction,
+) -> FlamecastInstance {
+    return create_random_flamecast_instance(
+        num_layers,
+        num_sources,
+        num_drains,
+        capacities,
+        initial_solution_function,
+    );
+}
+
 pub fn generate_flamecast_instance(
     alpha: f64,
     num_layers: usize,
@@ -65,6 +114,37 @@ pub fn generate_flamecast_instance(
     );
 }
 
+pub fn generate_geographic_flamecast_instance(
+    alpha: f64,
+    num_layers: usize,
+    capacities: Vec<usize>,
+    sources_drains_coordinates: VertexEmbeddings,
+    initial_solution_function: InitialSolutionFunction,
+) -> FlamecastInstance {
+    return FlamecastInstance::new_geographic(
+        alpha,
+        num_layers,
+        capacities,
+        sources_drains_coordinates,
+        initial_solution_function,
+    );
+}
+
+pub fn import_flamecast_test_instance(
+    file_path: &str,
+    options: &ImportOptions,
+) -> Result<FlamecastTestInstance, String> {
+    return import_flamecast_instance(file_path, options);
+}
+
+pub fn parse_flamecast_test_instance(
+    content: &str,
+    format: ImportFormat,
+    options: &ImportOptions,
+) -> Result<FlamecastTestInstance, String> {
+    return parse_flamecast_instance(content, format, options);
+}
+
 pub fn embed_graph(
     graph: LayeredGraph,
     sources_drains_embeddings: &VertexEmbeddings,
@@ -75,7 +155,7 @@ pub fn embed_graph(
         &graph,
         sources_drains_embeddings,
         &graph.calculate_edge_flows(),
-        alpha,
+        &CostModel::from_alpha(alpha),
         options,
     );
     return GraphEmbedding::new(graph, graph_embedding);
@@ -90,6 +170,23 @@ pub fn plot_graph(
     plot_embedded_graph(file_path, embedded_graph, show_layers, show_indices);
 }
 
+pub fn plot_graph_with_options(
+    file_path: &str,
+    embedded_graph: &GraphEmbedding,
+    options: &PlottingOptions,
+) {
+    plot_embedded_graph_with_options(file_path, embedded_graph, options);
+}
+
+pub fn plot_solving_run(
+    file_path: &str,
+    logger: &SimulatedAnnealingLogger,
+    alpha: f64,
+    options: &AnimationOptions,
+) {
+    plot_annealing_run(file_path, logger, &CostModel::from_alpha(alpha), options);
+}
+
 pub fn plot_vertices(
     file_path: &str,
     plotting_vertices: Vec<PlottingVertices>,
@@ -98,6 +195,14 @@ pub fn plot_vertices(
     plot_vertices_with_colors(file_path, &plotting_vertices, show_indices);
 }
 
+pub fn plot_vertices_with_options(
+    file_path: &str,
+    plotting_vertices: Vec<PlottingVertices>,
+    options: &PlottingOptions,
+) {
+    plot_vertices_with_colors_and_options(file_path, &plotting_vertices, options);
+}
+
 pub fn get_test_graph(index: usize) -> TestGraph {
     return TESTGRAPHS[index].clone();
 }
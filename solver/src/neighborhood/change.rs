@@ -21,6 +21,14 @@ impl LayeredGraph {
                 let new_parent_id = self.split(children_new_parent);
                 return Some(new_parent_id);
             },
+            Neighbor::Reroute(node, target_node) => {
+                let new_parent_id = self.reroute(node, target_node);
+                return Some(new_parent_id);
+            },
+            Neighbor::SubtreeExchange(node1, node2) => {
+                self.exchange_subtrees(node1, node2);
+                return None;
+            },
         }
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{LayeredGraph, VertexID};
+use crate::{LayeredGraph, MaxChildren, VertexID};
 
 impl LayeredGraph {
     // Merging only possible if the two nodes have the same parent
@@ -26,6 +26,7 @@ impl LayeredGraph {
         parent1: &VertexID,
         parent2: &VertexID,
         capacities: &Vec<usize>,
+        max_children: &MaxChildren,
         current_vertex_flows: &Vec<Vec<usize>>,
     ) -> bool {
         if self.get_parent(parent1) != self.get_parent(parent2) {
@@ -43,6 +44,14 @@ impl LayeredGraph {
             return false;
         }
 
+        // parent1 takes over all children of parent2
+        if self.get_vertex(parent1).get_number_of_children()
+            + self.get_vertex(parent2).get_number_of_children()
+            > max_children.get_max_children(parent1.layer)
+        {
+            return false;
+        }
+
         return true;
     }
 }
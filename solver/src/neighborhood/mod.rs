@@ -1,6 +1,8 @@
 mod merge;
 mod recable;
+mod reroute;
 mod split;
+mod subtree_exchange;
 mod swap;
 mod neighbor;
 mod change;
@@ -8,6 +8,8 @@ pub enum Neighbor {
     Swap(VertexID, VertexID),
     Merge(VertexID, VertexID),
     Split(Vec<VertexID>),
+    SubtreeExchange(VertexID, VertexID),
+    Reroute(VertexID, VertexID),
 }
 
 impl Neighbor {
@@ -30,6 +32,28 @@ impl Neighbor {
                 }
                 return s;
             }
+            Neighbor::SubtreeExchange(v1, v2) => {
+                return format!("SubtreeExchange: {} <=> {}", v1.to_string(), v2.to_string());
+            }
+            Neighbor::Reroute(v1, v2) => {
+                return format!("Reroute: {} ~> {}", v1.to_string(), v2.to_string());
+            }
+        }
+    }
+
+    pub fn get_vertices(&self) -> Vec<VertexID> {
+        // returns all vertices the neighbor operation is applied to
+        match self {
+            Neighbor::Recable(v1, v2)
+            | Neighbor::Swap(v1, v2)
+            | Neighbor::Merge(v1, v2)
+            | Neighbor::SubtreeExchange(v1, v2)
+            | Neighbor::Reroute(v1, v2) => {
+                return vec![v1.clone(), v2.clone()];
+            }
+            Neighbor::Split(v) => {
+                return v.clone();
+            }
         }
     }
 
@@ -61,12 +85,21 @@ impl Neighbor {
         }
     }
 
+    pub fn is_reroute(&self) -> bool {
+        match self {
+            Neighbor::Reroute(_, _) => true,
+            _ => false,
+        }
+    }
+
     pub fn get_names() -> Vec<String> {
         vec![
             String::from("Recable"),
             String::from("Swap"),
             String::from("Merge"),
             String::from("Split"),
+            String::from("SubtreeExchange"),
+            String::from("Reroute"),
         ]
     }
 }
@@ -1,4 +1,4 @@
-use crate::{LayeredGraph, VertexID};
+use crate::{LayeredGraph, MaxChildren, VertexID};
 
 impl LayeredGraph {
     pub fn recable(&mut self, node: &VertexID, target_node: &VertexID) {
@@ -16,6 +16,7 @@ impl LayeredGraph {
         num_layers: usize,
         target_node: &VertexID,
         capacities: &Vec<usize>,
+        max_children: &MaxChildren,
         current_vertex_flows: &Vec<Vec<usize>>,
     ) -> bool {
         // node and target_node must be valid in the graph and target_node must be in the following layer of the node
@@ -32,6 +33,12 @@ impl LayeredGraph {
             return false;
         }
 
+        if self.get_vertex(target_node).get_number_of_children()
+            >= max_children.get_max_children(target_node.layer)
+        {
+            return false;
+        }
+
         let node_flow = current_vertex_flows[node.layer][node.index];
 
         if current_vertex_flows[target_node.layer][target_node.index] + node_flow
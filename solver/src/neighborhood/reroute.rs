@@ -0,0 +1,133 @@
+use crate::{LayeredGraph, MaxChildren, Vertex, VertexID};
+
+impl LayeredGraph {
+    pub fn reroute(&mut self, node: &VertexID, target_node: &VertexID) -> VertexID {
+        // Returns the new parent of the node, the intermediate vertices are appended to their layers
+        self.remove_edge(node);
+
+        let mut current_parent = target_node.clone();
+        for layer in (node.layer + 1..target_node.layer).rev() {
+            let mut intermediate_vertex = Vertex::new_empty();
+            intermediate_vertex.set_parent(Some(current_parent.index));
+            current_parent = self.add_vertex_to_layer(layer, intermediate_vertex);
+        }
+
+        self.add_edge(node, &current_parent);
+
+        return current_parent;
+    }
+
+    pub fn undo_reroute(
+        &mut self,
+        node: &VertexID,
+        target_node: &VertexID,
+        old_parent_node: &VertexID,
+    ) {
+        let mut current_vertex = self.get_parent(node).unwrap();
+        self.remove_edge(node);
+
+        // the intermediate vertices are still the last vertices of their layers
+        while current_vertex != *target_node {
+            let parent = self.get_parent(&current_vertex).unwrap();
+            self.remove_edge(&current_vertex);
+            self.pop_vertex(current_vertex.layer);
+            current_vertex = parent;
+        }
+
+        self.add_edge(node, old_parent_node);
+    }
+
+    pub fn check_reroute_possible(
+        &self,
+        node: &VertexID,
+        target_node: &VertexID,
+        capacities: &Vec<usize>,
+        max_children: &MaxChildren,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> bool {
+        let num_layers = self.layers.len();
+
+        if target_node.layer <= node.layer || node.layer >= num_layers - 1 {
+            return false;
+        }
+
+        let old_parent_id = self.get_parent(node).unwrap();
+        if old_parent_id == *target_node {
+            return false;
+        }
+
+        // like recabling, a reroute must not leave a vertex without children
+        if self
+            .get_vertex(&old_parent_id)
+            .children_indices
+            .as_ref()
+            .unwrap()
+            .len()
+            == 1
+            && old_parent_id.layer != num_layers - 1
+        {
+            return false;
+        }
+
+        // the target gets the node or the first intermediate vertex as an additional child
+        if self.get_vertex(target_node).get_number_of_children()
+            >= max_children.get_max_children(target_node.layer)
+        {
+            return false;
+        }
+
+        let node_flow = current_vertex_flows[node.layer][node.index];
+
+        // the intermediate vertices only carry the flow of the node
+        if (node.layer + 1..target_node.layer).any(|layer| node_flow > capacities[layer]) {
+            return false;
+        }
+
+        // find the vertex of the old path in the layer of the target node
+        let mut old_path_vertex = old_parent_id;
+        while old_path_vertex.layer < target_node.layer {
+            old_path_vertex = self.get_parent(&old_path_vertex).unwrap();
+        }
+
+        // the flow increases on the path of the target node until both paths meet
+        let mut current_vertex = target_node.clone();
+        while current_vertex != old_path_vertex {
+            if current_vertex_flows[current_vertex.layer][current_vertex.index] + node_flow
+                > capacities[current_vertex.layer]
+            {
+                return false;
+            }
+
+            match (
+                self.get_parent(&current_vertex),
+                self.get_parent(&old_path_vertex),
+            ) {
+                (Some(parent), Some(old_parent)) => {
+                    current_vertex = parent;
+                    old_path_vertex = old_parent;
+                }
+                _ => break,
+            }
+        }
+
+        return true;
+    }
+
+    pub fn calculate_vertex_drains(&self) -> Vec<Vec<usize>> {
+        // calculate the index of the drain every vertex is connected to, assumes a valid flamecast graph
+        let num_layers = self.layers.len();
+        let mut vertex_drains = vec![vec![]; num_layers];
+
+        vertex_drains[num_layers - 1] = (0..self.layers[num_layers - 1].vertices.len()).collect();
+
+        for layer_index in (0..num_layers - 1).rev() {
+            vertex_drains[layer_index] = self.layers[layer_index]
+                .vertices
+                .iter()
+                .map(|vertex| vertex_drains[layer_index + 1][vertex.parent_index.unwrap()])
+                .collect();
+        }
+
+        return vertex_drains;
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{LayeredGraph, Vertex, VertexID};
+use crate::{LayeredGraph, MaxChildren, Vertex, VertexID};
 
 impl LayeredGraph {
     pub fn split(&mut self, children_new_parent_vertex: &Vec<VertexID>) -> VertexID {
@@ -22,7 +22,11 @@ impl LayeredGraph {
         self.merge(old_parent_node, new_parent_node);
     }
 
-    pub fn check_split_possible(&self, children_new_parent_vertex: &Vec<VertexID>) -> bool {
+    pub fn check_split_possible(
+        &self,
+        children_new_parent_vertex: &Vec<VertexID>,
+        max_children: &MaxChildren,
+    ) -> bool {
         if children_new_parent_vertex.len() == 0 {
             return false;
         }
@@ -33,6 +37,14 @@ impl LayeredGraph {
             return false;
         }
 
+        // the new vertex becomes an additional child of the grand parent
+        let grand_parent_id = self.get_parent(&parent_id).unwrap();
+        if self.get_vertex(&grand_parent_id).get_number_of_children()
+            >= max_children.get_max_children(grand_parent_id.layer)
+        {
+            return false;
+        }
+
         return true;
     }
 }
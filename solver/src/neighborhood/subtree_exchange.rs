@@ -0,0 +1,46 @@
+use crate::{LayeredGraph, MaxChildren, VertexID};
+
+impl LayeredGraph {
+    // The two vertices keep their parents, only the subtrees below them change places
+    pub fn exchange_subtrees(&mut self, node1: &VertexID, node2: &VertexID) {
+        let children1 = self.get_children(node1).unwrap();
+        let children2 = self.get_children(node2).unwrap();
+
+        children1.iter().for_each(|child| {
+            self.recable(child, node2);
+        });
+        children2.iter().for_each(|child| {
+            self.recable(child, node1);
+        });
+    }
+
+    pub fn undo_exchange_subtrees(&mut self, node1: &VertexID, node2: &VertexID) {
+        self.exchange_subtrees(node1, node2);
+    }
+
+    pub fn check_subtree_exchange_possible(
+        &self,
+        node1: &VertexID,
+        node2: &VertexID,
+        capacities: &Vec<usize>,
+        max_children: &MaxChildren,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> bool {
+        // only inner vertices have a subtree below them and a parent above them
+        if node1.layer == 0 || node1.layer >= self.layers.len() - 1 {
+            return false;
+        }
+
+        // every vertex takes all children of the other one
+        let max_children_layer = max_children.get_max_children(node1.layer);
+        if self.get_vertex(node1).get_number_of_children() > max_children_layer
+            || self.get_vertex(node2).get_number_of_children() > max_children_layer
+        {
+            return false;
+        }
+
+        // both vertices are in the same layer, so they may also have the children of the other one
+        // exchanging the subtrees changes the flows on the paths up to the common ancestor exactly like a swap
+        return self.check_swap_possible(node1, node2, capacities, current_vertex_flows);
+    }
+}
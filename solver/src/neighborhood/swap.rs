@@ -27,6 +27,9 @@ impl LayeredGraph {
             return false;
         }
 
+        // both parents give away one child and get one, so the number of children
+        // of every vertex and with it the max children constraints are unchanged
+
         let flow1 = current_vertex_flows[node1.layer][node1.index];
         let flow2 = current_vertex_flows[node2.layer][node2.index];
 
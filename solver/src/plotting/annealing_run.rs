@@ -0,0 +1,166 @@
+use std::fs::create_dir_all;
+
+use plotters::{
+    coord::Shift,
+    prelude::{BitMapBackend, DrawingArea, IntoDrawingArea, Text},
+    style::{IntoFont, WHITE},
+};
+
+use crate::{CostModel, EmbeddingOptions, GraphEmbedding, SimulatedAnnealingLogger, VertexID};
+
+use super::{
+    calculate_embedding_bounds, calculate_vertex_colors, draw_embedded_graph, ROOT_HEIGHT,
+    ROOT_WIDTH,
+};
+
+#[derive(Debug, Clone)]
+pub enum AnimationFormat {
+    Gif(u32),      // delay between two frames in milliseconds
+    FrameSequence, // numbered png files in the given directory
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationOptions {
+    pub format: AnimationFormat,
+    pub embedding_options: EmbeddingOptions,
+    pub show_layers: bool,
+    pub show_indices: bool,
+    pub highlight_changes: bool,
+}
+
+impl AnimationOptions {
+    pub fn new(
+        format: AnimationFormat,
+        embedding_options: EmbeddingOptions,
+        show_layers: bool,
+        show_indices: bool,
+        highlight_changes: bool,
+    ) -> Self {
+        Self {
+            format,
+            embedding_options,
+            show_layers,
+            show_indices,
+            highlight_changes,
+        }
+    }
+
+    pub fn default() -> Self {
+        Self {
+            format: AnimationFormat::Gif(500),
+            embedding_options: EmbeddingOptions::default(),
+            show_layers: true,
+            show_indices: false,
+            highlight_changes: true,
+        }
+    }
+}
+
+struct AnimationFrame {
+    solution: GraphEmbedding,
+    caption: String,
+    highlighted_vertices: Vec<VertexID>,
+}
+
+pub fn plot_annealing_run(
+    file_path: &str,
+    logger: &SimulatedAnnealingLogger,
+    cost_model: &CostModel,
+    options: &AnimationOptions,
+) {
+    let frames = calculate_animation_frames(logger, cost_model, options);
+    if frames.is_empty() {
+        return;
+    }
+
+    // sources and drains are fixed and span the whole plot, so all frames use the same bounds
+    let history = logger.get_solution_history();
+    let bounds = calculate_embedding_bounds(&history.get_sources_drains_embeddings());
+    let vertex_colors =
+        calculate_vertex_colors(&frames[0].solution.base_graph, options.show_layers);
+
+    let draw_frame = |root: &DrawingArea<BitMapBackend, Shift>, frame: &AnimationFrame| {
+        root.fill(&WHITE).unwrap();
+        draw_embedded_graph(
+            root,
+            &frame.solution,
+            bounds,
+            &vertex_colors,
+            options.show_indices,
+            &frame.highlighted_vertices,
+        );
+        root.draw(&Text::new(
+            frame.caption.clone(),
+            (10, 10),
+            ("sans-serif", 20).into_font(),
+        ))
+        .unwrap();
+        root.present().unwrap();
+    };
+
+    match options.format {
+        AnimationFormat::Gif(frame_delay) => {
+            let root = BitMapBackend::gif(file_path, (ROOT_WIDTH, ROOT_HEIGHT), frame_delay)
+                .unwrap()
+                .into_drawing_area();
+            frames.iter().for_each(|frame| draw_frame(&root, frame));
+        }
+        AnimationFormat::FrameSequence => {
+            create_dir_all(file_path).unwrap();
+            frames.iter().enumerate().for_each(|(frame_index, frame)| {
+                let frame_path = format!("{}/frame_{:05}.png", file_path, frame_index);
+                let root = BitMapBackend::new(frame_path.as_str(), (ROOT_WIDTH, ROOT_HEIGHT))
+                    .into_drawing_area();
+                draw_frame(&root, frame);
+            });
+        }
+    }
+}
+
+fn calculate_animation_frames(
+    logger: &SimulatedAnnealingLogger,
+    cost_model: &CostModel,
+    options: &AnimationOptions,
+) -> Vec<AnimationFrame> {
+    let history = logger.get_solution_history();
+    let intermediate_solutions = history.iter_intermediate_solutions().collect::<Vec<_>>();
+
+    let mut frames = Vec::new();
+    for (solution_index, intermediate_solution) in intermediate_solutions.iter().enumerate() {
+        let solution = history.embed_solution(
+            intermediate_solution.solution.clone(),
+            cost_model,
+            &options.embedding_options,
+        );
+        let cost = solution.calculate_costs_with_cost_model(cost_model);
+
+        let mut caption = match &intermediate_solution.accepted_neighbor {
+            Some(neighbor_change) => {
+                format!(
+                    "Iteration: {}, Cost: {:.6}",
+                    neighbor_change.iteration, cost
+                )
+            }
+            None => format!("Initial Solution, Cost: {:.6}", cost),
+        };
+
+        // vertex indices change when a neighbor is applied, so the vertices of the next
+        // neighbor are highlighted on the solution the neighbor is applied to
+        let mut highlighted_vertices = Vec::new();
+        if options.highlight_changes {
+            if let Some(next_solution) = intermediate_solutions.get(solution_index + 1) {
+                let next_neighbor = &next_solution.accepted_neighbor.as_ref().unwrap().neighbor;
+                highlighted_vertices = next_neighbor.get_vertices();
+                caption.push_str(&format!(", Next: {}", next_neighbor.to_string()));
+            }
+        }
+
+        frames.push(AnimationFrame {
+            solution,
+            caption,
+            highlighted_vertices,
+        });
+    }
+
+    return frames;
+}
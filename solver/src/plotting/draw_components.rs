@@ -1,21 +1,21 @@
 use plotters::{
     prelude::{BitMapBackend, Circle, EmptyElement, PathElement, Text},
-    style::{IntoFont, RGBColor, ShapeStyle, BLACK},
+    style::{IntoFont, RGBColor, ShapeStyle, BLACK, RED},
 };
 
 use crate::VertexEmbedding;
 
 use super::{convert_vertex_to_i32, NODE_RADIUS};
 
-pub fn create_node(
+pub fn create_node<'a>(
     embedded_vertex: &VertexEmbedding,
     color: RGBColor,
     index: Option<usize>,
 ) -> plotters::element::ComposedElement<
     (i32, i32),
-    BitMapBackend,
+    BitMapBackend<'a>,
     Circle<(i32, i32), i32>,
-    Text<'_, (i32, i32), String>,
+    Text<'a, (i32, i32), String>,
 > {
     let coordinates = convert_vertex_to_i32(embedded_vertex);
 
@@ -40,3 +40,13 @@ pub fn create_edge(source: &VertexEmbedding, target: &VertexEmbedding) -> PathEl
 
     return PathElement::new(vec![source_coordinates, target_coordinates], &BLACK);
 }
+
+pub fn create_highlight(embedded_vertex: &VertexEmbedding) -> Circle<(i32, i32), i32> {
+    let coordinates = convert_vertex_to_i32(embedded_vertex);
+
+    return Circle::new(
+        coordinates,
+        2 * NODE_RADIUS,
+        ShapeStyle::from(RED).stroke_width(3),
+    );
+}
@@ -1,13 +1,15 @@
 use plotters::{
-    prelude::{BitMapBackend, IntoDrawingArea},
+    coord::Shift,
+    prelude::{BitMapBackend, DrawingArea, IntoDrawingArea},
     style::{RGBColor, BLACK, WHITE},
 };
 use rand::{seq::SliceRandom, thread_rng};
 
-use crate::{GraphEmbedding, LayeredGraph, VertexEmbeddings};
+use crate::{GraphEmbedding, LayeredGraph, VertexEmbeddings, VertexID};
 
 use super::{
-    create_edge, create_node, DISTINCT_COLORS, NUM_DISTINCT_COLORS, ROOT_HEIGHT, ROOT_WIDTH,
+    calculate_layer_colors, create_edge, create_highlight, create_node, DISTINCT_COLORS,
+    NUM_DISTINCT_COLORS, ROOT_HEIGHT, ROOT_WIDTH,
 };
 
 pub fn plot_embedded_graph(
@@ -16,32 +18,7 @@ pub fn plot_embedded_graph(
     show_layers: bool,
     show_indices: bool,
 ) {
-    let graph = &embedded_graph.base_graph;
-    let original_embeddings = &embedded_graph.vertices_embeddings;
-
-    let x_values: Vec<f64> = original_embeddings
-        .embeddings
-        .iter()
-        .flat_map(|layer| layer.iter().map(|vertex| vertex.0))
-        .collect();
-    let y_values: Vec<f64> = original_embeddings
-        .embeddings
-        .iter()
-        .flat_map(|layer| layer.iter().map(|vertex| vertex.1))
-        .collect();
-
-    let min_x = x_values
-        .iter()
-        .cloned()
-        .fold(f64::INFINITY, |a, b| a.min(b));
-    let max_x = x_values.iter().fold(f64::NEG_INFINITY, |a, b| a.max(*b));
-    let min_y = y_values
-        .iter()
-        .cloned()
-        .fold(f64::INFINITY, |a, b| a.min(b));
-    let max_y = y_values.iter().fold(f64::NEG_INFINITY, |a, b| a.max(*b));
-
-    let embeddings = normalize_vertex_embeddings(original_embeddings, min_x, min_y, max_x, max_y);
+    let bounds = calculate_embedding_bounds(&embedded_graph.vertices_embeddings);
 
     let root = BitMapBackend::new(file_path, (ROOT_WIDTH, ROOT_HEIGHT)).into_drawing_area();
 
@@ -51,6 +28,35 @@ pub fn plot_embedded_graph(
     // Calculate vertex colors for nodes
     let vertex_colors = calculate_vertex_colors(&embedded_graph.base_graph, show_layers);
 
+    draw_embedded_graph(
+        &root,
+        embedded_graph,
+        bounds,
+        &vertex_colors,
+        show_indices,
+        &Vec::new(),
+    );
+}
+
+pub fn draw_embedded_graph(
+    root: &DrawingArea<BitMapBackend, Shift>,
+    embedded_graph: &GraphEmbedding,
+    bounds: (f64, f64, f64, f64),
+    vertex_colors: &Vec<RGBColor>,
+    show_indices: bool,
+    highlighted_vertices: &Vec<VertexID>,
+) {
+    let graph = &embedded_graph.base_graph;
+    let (min_x, min_y, max_x, max_y) = bounds;
+
+    let embeddings = normalize_vertex_embeddings(
+        &embedded_graph.vertices_embeddings,
+        min_x,
+        min_y,
+        max_x,
+        max_y,
+    );
+
     // Draw vertices and edges for each layer
     for (layer_index, layer) in graph.layers.iter().enumerate() {
         // Draw edges first because they should be behind the vertices
@@ -80,6 +86,44 @@ pub fn plot_embedded_graph(
             .unwrap();
         }
     }
+
+    // Draw highlights on top of the graph
+    for vertex_id in highlighted_vertices.iter() {
+        if let Some(embedding) = embeddings
+            .embeddings
+            .get(vertex_id.layer)
+            .and_then(|layer| layer.get(vertex_id.index))
+        {
+            root.draw(&create_highlight(embedding)).unwrap();
+        }
+    }
+}
+
+pub fn calculate_embedding_bounds(embeddings: &VertexEmbeddings) -> (f64, f64, f64, f64) {
+    // returns (min_x, min_y, max_x, max_y) of all embedded vertices
+    let x_values: Vec<f64> = embeddings
+        .embeddings
+        .iter()
+        .flat_map(|layer| layer.iter().map(|vertex| vertex.0))
+        .collect();
+    let y_values: Vec<f64> = embeddings
+        .embeddings
+        .iter()
+        .flat_map(|layer| layer.iter().map(|vertex| vertex.1))
+        .collect();
+
+    let min_x = x_values
+        .iter()
+        .cloned()
+        .fold(f64::INFINITY, |a, b| a.min(b));
+    let max_x = x_values.iter().fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+    let min_y = y_values
+        .iter()
+        .cloned()
+        .fold(f64::INFINITY, |a, b| a.min(b));
+    let max_y = y_values.iter().fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+
+    return (min_x, min_y, max_x, max_y);
 }
 
 pub fn normalize_vertex_embeddings(
@@ -124,10 +168,13 @@ pub fn normalize_vertex_embeddings(
     return normalized_embeddings;
 }
 
-fn calculate_vertex_colors(graph: &LayeredGraph, show_layers: bool) -> Vec<RGBColor> {
+pub fn calculate_vertex_colors(graph: &LayeredGraph, show_layers: bool) -> Vec<RGBColor> {
     let mut vertex_colors = Vec::new();
 
-    if show_layers {
+    if show_layers && graph.layers.len() > NUM_DISTINCT_COLORS {
+        // reusing the distinct colors would give multiple layers the same color
+        vertex_colors = calculate_layer_colors(graph.layers.len(), show_layers);
+    } else if show_layers {
         let mut rng = thread_rng();
         let mut color_indices: Vec<usize> = (0..NUM_DISTINCT_COLORS).collect();
         color_indices.shuffle(&mut rng);
@@ -1,14 +1,22 @@
+mod annealing_run;
 mod distinct_colors;
 mod draw_components;
 mod flamecast_test_instance;
 mod graph_embedding;
+mod pareto_front;
+mod plotting_options;
+mod styled_plot;
 mod util;
 mod vertices_embedding;
 
+pub use annealing_run::*;
 pub use distinct_colors::*;
 pub use draw_components::*;
 pub use flamecast_test_instance::*;
 pub use graph_embedding::*;
+pub use pareto_front::*;
+pub use plotting_options::*;
+pub use styled_plot::*;
 pub use util::*;
 pub use vertices_embedding::*;
 
@@ -16,3 +24,5 @@ pub const ROOT_WIDTH: u32 = 1000;
 pub const ROOT_HEIGHT: u32 = 1000;
 
 pub const NODE_RADIUS: i32 = 6;
+pub const MAX_NODE_RADIUS: i32 = 3 * NODE_RADIUS;
+pub const MAX_EDGE_WIDTH: u32 = 8;
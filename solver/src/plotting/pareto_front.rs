@@ -0,0 +1,105 @@
+use plotters::{
+    prelude::{
+        BitMapBackend, ChartBuilder, Circle, ColorMap, IntoDrawingArea, PathElement, ViridisRGB,
+    },
+    series::LineSeries,
+    style::{Color, IntoFont, ShapeStyle, BLACK, RED, WHITE},
+};
+
+use crate::ParetoArchive;
+
+use super::{NODE_RADIUS, ROOT_HEIGHT, ROOT_WIDTH};
+
+pub fn plot_pareto_front(file_path: &str, pareto_archive: &ParetoArchive) {
+    // plots the costs over the number of inner vertices, the points of the archive
+    // are colored by their max path length if it is tracked
+    let front = pareto_archive.get_front();
+    if front.is_empty() {
+        return;
+    }
+
+    let root = BitMapBackend::new(file_path, (ROOT_WIDTH, ROOT_HEIGHT)).into_drawing_area();
+    root.fill(&WHITE).unwrap();
+
+    let min_vertices = front[0].number_inner_vertices as f64;
+    let max_vertices = front[front.len() - 1].number_inner_vertices as f64;
+    let min_costs = front
+        .iter()
+        .map(|point| point.objective_value)
+        .fold(f64::INFINITY, f64::min);
+    let max_costs = front
+        .iter()
+        .map(|point| point.objective_value)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let vertices_padding = ((max_vertices - min_vertices) * 0.05).max(1.0);
+    let costs_padding = ((max_costs - min_costs) * 0.05).max(max_costs.abs() * 0.01);
+
+    let mut chart = ChartBuilder::on(&root)
+        .margin(20)
+        .x_label_area_size(60)
+        .y_label_area_size(80)
+        .build_cartesian_2d(
+            (min_vertices - vertices_padding)..(max_vertices + vertices_padding),
+            (min_costs - costs_padding)..(max_costs + costs_padding),
+        )
+        .unwrap();
+
+    chart
+        .configure_mesh()
+        .label_style(("sans-serif", 15).into_font())
+        .x_desc("Inner Vertices")
+        .y_desc("Objective Value")
+        .draw()
+        .unwrap();
+
+    // the trade off between the costs and the number of inner vertices
+    let cost_vertex_front = pareto_archive.get_cost_vertex_front();
+    chart
+        .draw_series(LineSeries::new(
+            cost_vertex_front
+                .iter()
+                .map(|point| (point.number_inner_vertices as f64, point.objective_value)),
+            ShapeStyle::from(RED).stroke_width(2),
+        ))
+        .unwrap()
+        .label("Costs / Inner Vertices Front")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    let path_lengths = front
+        .iter()
+        .filter_map(|point| point.max_path_length)
+        .collect::<Vec<f64>>();
+    let min_path_length = path_lengths.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_path_length = path_lengths
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    chart
+        .draw_series(front.iter().map(|point| {
+            let style = match point.max_path_length {
+                Some(path_length) if max_path_length > min_path_length => {
+                    let relative_path_length =
+                        (path_length - min_path_length) / (max_path_length - min_path_length);
+                    ViridisRGB.get_color(relative_path_length as f32).filled()
+                }
+                _ => BLACK.filled(),
+            };
+            Circle::new(
+                (point.number_inner_vertices as f64, point.objective_value),
+                NODE_RADIUS,
+                style,
+            )
+        }))
+        .unwrap();
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .label_font(("sans-serif", 15).into_font())
+        .draw()
+        .unwrap();
+
+    root.present().unwrap();
+}
@@ -0,0 +1,42 @@
+use crate::CoordinateSystem;
+
+use super::{ROOT_HEIGHT, ROOT_WIDTH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlotFormat {
+    Png,
+    Svg,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlottingOptions {
+    pub format: PlotFormat,
+    pub width: u32,
+    pub height: u32,
+    pub show_layers: bool,
+    pub show_indices: bool,
+    pub scale_edges_by_flow: bool, // edge width and color depend on the flow of the edge
+    pub scale_nodes_by_flow: bool, // node size depends on the flow through the vertex
+    pub show_legend: bool,
+    pub show_axes: bool,
+    pub show_scale_bar: bool,
+    pub coordinate_system: CoordinateSystem, // geographic plots label the axes with longitude and latitude
+}
+
+impl PlottingOptions {
+    pub fn default() -> Self {
+        Self {
+            format: PlotFormat::Png,
+            width: ROOT_WIDTH,
+            height: ROOT_HEIGHT,
+            show_layers: true,
+            show_indices: false,
+            scale_edges_by_flow: true,
+            scale_nodes_by_flow: true,
+            show_legend: true,
+            show_axes: true,
+            show_scale_bar: false,
+            coordinate_system: CoordinateSystem::Euclidean,
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use std::ops::Range;
+
+use plotters::{
+    coord::{types::RangedCoordf64, Shift},
+    element::ComposedElement,
+    prelude::{
+        BitMapBackend, Cartesian2d, ChartBuilder, ChartContext, Circle, ColorMap, DerivedColorMap,
+        DrawingArea, DrawingBackend, EmptyElement, IntoDrawingArea, PathElement, SVGBackend, Text,
+        ViridisRGB,
+    },
+    style::{Color, IntoFont, RGBColor, ShapeStyle, BLACK, WHITE},
+};
+
+use crate::{GraphEmbedding, VertexEmbedding};
+
+use super::{
+    calculate_embedding_bounds, PlotFormat, PlottingOptions, PlottingVertices, DISTINCT_COLORS,
+    MAX_EDGE_WIDTH, MAX_NODE_RADIUS, NODE_RADIUS, NUM_DISTINCT_COLORS,
+};
+
+const CHART_MARGIN: u32 = 20;
+const X_LABEL_AREA_SIZE: u32 = 40;
+const Y_LABEL_AREA_SIZE: u32 = 60;
+const AXIS_DESC_SIZE: u32 = 20;
+
+type GraphChart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+type ScaledNode<DB> = ComposedElement<
+    VertexEmbedding,
+    DB,
+    Circle<(i32, i32), i32>,
+    Text<'static, (i32, i32), String>,
+>;
+
+pub fn plot_embedded_graph_with_options(
+    file_path: &str,
+    embedded_graph: &GraphEmbedding,
+    options: &PlottingOptions,
+) {
+    match options.format {
+        PlotFormat::Png => {
+            let root =
+                BitMapBackend::new(file_path, (options.width, options.height)).into_drawing_area();
+            draw_embedded_graph_with_options(&root, embedded_graph, options);
+            root.present().unwrap();
+        }
+        PlotFormat::Svg => {
+            let root =
+                SVGBackend::new(file_path, (options.width, options.height)).into_drawing_area();
+            draw_embedded_graph_with_options(&root, embedded_graph, options);
+            root.present().unwrap();
+        }
+    }
+}
+
+pub fn plot_vertices_with_colors_and_options(
+    file_path: &str,
+    plotting_vertices: &Vec<PlottingVertices>,
+    options: &PlottingOptions,
+) {
+    match options.format {
+        PlotFormat::Png => {
+            let root =
+                BitMapBackend::new(file_path, (options.width, options.height)).into_drawing_area();
+            draw_vertices_with_options(&root, plotting_vertices, options);
+            root.present().unwrap();
+        }
+        PlotFormat::Svg => {
+            let root =
+                SVGBackend::new(file_path, (options.width, options.height)).into_drawing_area();
+            draw_vertices_with_options(&root, plotting_vertices, options);
+            root.present().unwrap();
+        }
+    }
+}
+
+fn draw_embedded_graph_with_options<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    embedded_graph: &GraphEmbedding,
+    options: &PlottingOptions,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let graph = &embedded_graph.base_graph;
+    let embeddings = &embedded_graph.vertices_embeddings.embeddings;
+
+    let mut chart = build_chart(
+        root,
+        calculate_embedding_bounds(&embedded_graph.vertices_embeddings),
+        options,
+    );
+
+    let edge_flows = graph.calculate_edge_flows();
+    let vertex_flows = graph.calculate_vertex_flows();
+    let max_flow = vertex_flows
+        .iter()
+        .flat_map(|layer| layer.iter())
+        .fold(1, |a, b| a.max(*b));
+
+    // Draw edges first because they should be behind the vertices
+    for (layer_index, layer) in graph.layers.iter().enumerate() {
+        if layer_index == graph.layers.len() - 1 {
+            break;
+        }
+
+        chart
+            .draw_series(
+                layer
+                    .vertices
+                    .iter()
+                    .enumerate()
+                    .map(|(vertex_index, vertex)| {
+                        let source = embeddings[layer_index][vertex_index];
+                        let target = embeddings[layer_index + 1][vertex.parent_index.unwrap()];
+                        let style = get_edge_style(
+                            edge_flows[layer_index][vertex_index],
+                            max_flow,
+                            options,
+                        );
+                        PathElement::new(vec![source, target], style)
+                    }),
+            )
+            .unwrap();
+    }
+
+    // Draw vertices layer by layer so every layer gets an entry in the legend
+    let layer_colors = calculate_layer_colors(graph.layers.len(), options.show_layers);
+    for (layer_index, layer) in graph.layers.iter().enumerate() {
+        let color = layer_colors[layer_index];
+        let series = chart
+            .draw_series(layer.vertices.iter().enumerate().map(|(vertex_index, _)| {
+                let radius =
+                    get_node_radius(vertex_flows[layer_index][vertex_index], max_flow, options);
+                let index = if options.show_indices {
+                    Some(vertex_index)
+                } else {
+                    None
+                };
+                create_scaled_node(embeddings[layer_index][vertex_index], radius, color, index)
+            }))
+            .unwrap();
+
+        if options.show_legend {
+            series
+                .label(format!("Layer {}", layer_index))
+                .legend(move |(x, y)| Circle::new((x, y), NODE_RADIUS, color.filled()));
+        }
+    }
+
+    finish_chart(&mut chart, options);
+}
+
+fn draw_vertices_with_options<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    plotting_vertices: &Vec<PlottingVertices>,
+    options: &PlottingOptions,
+) {
+    root.fill(&WHITE).unwrap();
+
+    let all_vertices = plotting_vertices
+        .iter()
+        .map(|plotting_vertices| plotting_vertices.vertices.clone())
+        .collect::<Vec<Vec<VertexEmbedding>>>();
+    let mut chart = build_chart(
+        root,
+        calculate_embedding_bounds(&crate::VertexEmbeddings::from(all_vertices)),
+        options,
+    );
+
+    for (group_index, group) in plotting_vertices.iter().enumerate() {
+        let color = group.color;
+        let series = chart
+            .draw_series(group.vertices.iter().map(|vertex_embedding| {
+                let index = if options.show_indices {
+                    Some(group_index)
+                } else {
+                    None
+                };
+                create_scaled_node(*vertex_embedding, NODE_RADIUS, color, index)
+            }))
+            .unwrap();
+
+        if options.show_legend {
+            series
+                .label(format!("Group {}", group_index))
+                .legend(move |(x, y)| Circle::new((x, y), NODE_RADIUS, color.filled()));
+        }
+    }
+
+    finish_chart(&mut chart, options);
+}
+
+fn build_chart<'a, DB: DrawingBackend>(
+    root: &'a DrawingArea<DB, Shift>,
+    bounds: (f64, f64, f64, f64),
+    options: &PlottingOptions,
+) -> GraphChart<'a, DB> {
+    let (x_label_area_size, y_label_area_size) = if !options.show_axes {
+        (0, 0)
+    } else if options.coordinate_system.is_geographic() {
+        // leave room for the axis descriptions
+        (
+            X_LABEL_AREA_SIZE + AXIS_DESC_SIZE,
+            Y_LABEL_AREA_SIZE + AXIS_DESC_SIZE,
+        )
+    } else {
+        (X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE)
+    };
+
+    // both axes must use the same scale, otherwise distances in the plot are distorted
+    let plot_width = options
+        .width
+        .saturating_sub(2 * CHART_MARGIN + y_label_area_size);
+    let plot_height = options
+        .height
+        .saturating_sub(2 * CHART_MARGIN + x_label_area_size);
+    let (x_range, y_range) = calculate_chart_ranges(bounds, plot_width, plot_height);
+
+    let mut chart = ChartBuilder::on(root)
+        .margin(CHART_MARGIN)
+        .x_label_area_size(x_label_area_size)
+        .y_label_area_size(y_label_area_size)
+        .build_cartesian_2d(x_range, y_range)
+        .unwrap();
+
+    if options.show_axes {
+        let mut mesh = chart.configure_mesh();
+        mesh.disable_mesh()
+            .label_style(("sans-serif", 15).into_font());
+
+        // the projection is equirectangular, so x only depends on the longitude and y only on the latitude
+        let projection = options.coordinate_system.get_projection();
+        let x_formatter = |x: &f64| format!("{:.2}°", projection.unwrap().unproject_x(*x));
+        let y_formatter = |y: &f64| format!("{:.2}°", projection.unwrap().unproject_y(*y));
+        if projection.is_some() {
+            mesh.x_desc("Longitude")
+                .y_desc("Latitude")
+                .x_label_formatter(&x_formatter)
+                .y_label_formatter(&y_formatter);
+        }
+
+        mesh.draw().unwrap();
+    }
+
+    return chart;
+}
+
+fn finish_chart<'a, DB: DrawingBackend + 'a>(
+    chart: &mut GraphChart<'a, DB>,
+    options: &PlottingOptions,
+) {
+    if options.show_scale_bar {
+        draw_scale_bar(chart, options);
+    }
+
+    if options.show_legend {
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .label_font(("sans-serif", 15).into_font())
+            .draw()
+            .unwrap();
+    }
+}
+
+fn draw_scale_bar<'a, DB: DrawingBackend + 'a>(
+    chart: &mut GraphChart<'a, DB>,
+    options: &PlottingOptions,
+) {
+    let x_range = chart.x_range();
+    let y_range = chart.y_range();
+    let x_size = x_range.end - x_range.start;
+    let y_size = y_range.end - y_range.start;
+
+    // the length of the bar is the biggest value of the form 1, 2 or 5 times a power of 10 that fits into a fifth of the plot
+    let target_length = x_size / 5.0;
+    let magnitude = 10.0_f64.powf(target_length.log10().floor());
+    let bar_length = [5.0, 2.0, 1.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|length| *length <= target_length)
+        .unwrap_or(magnitude);
+
+    let start = (x_range.start + 0.05 * x_size, y_range.start + 0.05 * y_size);
+    let end = (start.0 + bar_length, start.1);
+    chart
+        .draw_series(vec![PathElement::new(
+            vec![start, end],
+            ShapeStyle::from(BLACK).stroke_width(3),
+        )])
+        .unwrap();
+    chart
+        .draw_series(vec![Text::new(
+            match options.coordinate_system.is_geographic() {
+                true => format!("{} km", bar_length),
+                false => format!("{}", bar_length),
+            },
+            (start.0, start.1 + 0.05 * y_size),
+            ("sans-serif", 15).into_font(),
+        )])
+        .unwrap();
+}
+
+fn calculate_chart_ranges(
+    bounds: (f64, f64, f64, f64),
+    plot_width: u32,
+    plot_height: u32,
+) -> (Range<f64>, Range<f64>) {
+    let (min_x, min_y, max_x, max_y) = bounds;
+
+    // add some padding so that vertices at the border are not cut off
+    let size = (max_x - min_x).max(max_y - min_y).max(f64::EPSILON);
+    let padding = 0.05 * size;
+    let mut x_size = max_x - min_x + 2.0 * padding;
+    let mut y_size = max_y - min_y + 2.0 * padding;
+
+    // stretch the smaller range so both axes have the same units per pixel
+    let pixel_ratio = plot_width.max(1) as f64 / plot_height.max(1) as f64;
+    if x_size / y_size < pixel_ratio {
+        x_size = y_size * pixel_ratio;
+    } else {
+        y_size = x_size / pixel_ratio;
+    }
+
+    let center_x = (min_x + max_x) / 2.0;
+    let center_y = (min_y + max_y) / 2.0;
+    return (
+        (center_x - x_size / 2.0)..(center_x + x_size / 2.0),
+        (center_y - y_size / 2.0)..(center_y + y_size / 2.0),
+    );
+}
+
+pub fn calculate_layer_colors(num_layers: usize, show_layers: bool) -> Vec<RGBColor> {
+    if !show_layers {
+        return vec![BLACK; num_layers];
+    }
+
+    // distinct colors are easier to tell apart, a colormap is only used if there are not enough of them
+    if num_layers <= NUM_DISTINCT_COLORS {
+        return DISTINCT_COLORS[0..num_layers].to_vec();
+    }
+    return (0..num_layers)
+        .map(|layer_index| ViridisRGB.get_color(layer_index as f32 / (num_layers - 1) as f32))
+        .collect();
+}
+
+fn get_edge_style(flow: usize, max_flow: usize, options: &PlottingOptions) -> ShapeStyle {
+    if !options.scale_edges_by_flow {
+        return ShapeStyle::from(BLACK);
+    }
+
+    let relative_flow = flow as f64 / max_flow as f64;
+    let width = 1 + (relative_flow * (MAX_EDGE_WIDTH - 1) as f64).round() as u32;
+    let color_map = DerivedColorMap::new(&[RGBColor(170, 170, 170), RGBColor(139, 0, 0)]);
+    let color = color_map.get_color(relative_flow as f32);
+
+    return ShapeStyle::from(color).stroke_width(width);
+}
+
+fn get_node_radius(flow: usize, max_flow: usize, options: &PlottingOptions) -> i32 {
+    if !options.scale_nodes_by_flow {
+        return NODE_RADIUS;
+    }
+
+    // the area of the node grows linearly with the flow
+    let relative_flow = (flow as f64 / max_flow as f64).sqrt();
+    return NODE_RADIUS + (relative_flow * (MAX_NODE_RADIUS - NODE_RADIUS) as f64).round() as i32;
+}
+
+fn create_scaled_node<DB: DrawingBackend>(
+    embedded_vertex: VertexEmbedding,
+    radius: i32,
+    color: RGBColor,
+    index: Option<usize>,
+) -> ScaledNode<DB> {
+    let text = if let Some(index) = index {
+        format!("{}", index)
+    } else {
+        "".to_string()
+    };
+
+    return EmptyElement::at(embedded_vertex)
+        + Circle::new((0, 0), radius, ShapeStyle::from(color).filled())
+        + Text::new(
+            text,
+            (radius + NODE_RADIUS, 0),
+            ("sans-serif", 3 * NODE_RADIUS).into_font(),
+        );
+}
@@ -0,0 +1,237 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CoordinateSystem, CostModel, DistanceMetric, EdgeCostFunction, EmbeddingOptions,
+    FlamecastInstance, FlamecastTestInstance, InitialSolutionFunction, MaxChildren, MaxPathLength,
+    VertexEmbedding, VertexEmbeddings,
+};
+
+use super::{migrate_instance, INSTANCE_SCHEMA_NAME, INSTANCE_SCHEMA_VERSION};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)] // fields of newer versions must not be dropped silently
+pub struct FlamecastInstanceFile {
+    pub schema: String,
+    pub version: u32,
+    pub alpha: f64,
+    pub num_layers: usize,
+    pub capacities: Vec<usize>,
+    pub sources: Vec<VertexEmbedding>,
+    pub drains: Vec<VertexEmbedding>,
+    #[serde(default)]
+    pub coordinate_system: CoordinateSystem, // geographic instances store projected coordinates
+    #[serde(default)]
+    pub metric: DistanceMetric,
+    #[serde(default)]
+    pub edge_cost_function: EdgeCostFunction,
+    #[serde(default)]
+    pub layer_alphas: Vec<f64>, // one entry per edge layer, empty for the same alpha everywhere
+    #[serde(default)]
+    pub layer_cost_multipliers: Vec<f64>,
+    #[serde(default)]
+    pub vertex_opening_costs: Vec<f64>, // one entry per layer, empty if vertices are free
+    #[serde(default)]
+    pub max_path_length: MaxPathLength,
+    #[serde(default)]
+    pub max_children: MaxChildren,
+    #[serde(default)]
+    pub robustness_penalty: f64,
+}
+
+impl FlamecastInstanceFile {
+    pub fn new(
+        alpha: f64,
+        num_layers: usize,
+        capacities: Vec<usize>,
+        sources: Vec<VertexEmbedding>,
+        drains: Vec<VertexEmbedding>,
+    ) -> Self {
+        Self {
+            schema: String::from(INSTANCE_SCHEMA_NAME),
+            version: INSTANCE_SCHEMA_VERSION,
+            alpha,
+            num_layers,
+            capacities,
+            sources,
+            drains,
+            coordinate_system: CoordinateSystem::Euclidean,
+            metric: DistanceMetric::Euclidean,
+            edge_cost_function: EdgeCostFunction::Power,
+            layer_alphas: Vec::new(),
+            layer_cost_multipliers: Vec::new(),
+            vertex_opening_costs: Vec::new(),
+            max_path_length: MaxPathLength::Unbounded,
+            max_children: MaxChildren::default(),
+            robustness_penalty: 0.0,
+        }
+    }
+
+    pub fn from_sources_drains_embeddings(
+        alpha: f64,
+        num_layers: usize,
+        capacities: Vec<usize>,
+        sources_drains_embeddings: &VertexEmbeddings,
+    ) -> Self {
+        return Self::new(
+            alpha,
+            num_layers,
+            capacities,
+            sources_drains_embeddings.embeddings[0].clone(),
+            sources_drains_embeddings.embeddings[num_layers - 1].clone(),
+        );
+    }
+
+    pub fn from_test_instance(test_instance: &FlamecastTestInstance) -> Self {
+        let mut instance_file = Self::from_sources_drains_embeddings(
+            test_instance.alpha,
+            test_instance.num_layers,
+            test_instance.capacities.clone(),
+            &test_instance.sources_drains_embeddings,
+        );
+        instance_file.layer_alphas = test_instance.layer_alphas.clone();
+        instance_file.layer_cost_multipliers = test_instance.layer_cost_multipliers.clone();
+        return instance_file;
+    }
+
+    pub fn from_flamecast_instance(instance: &FlamecastInstance) -> Self {
+        let mut instance_file = Self::from_sources_drains_embeddings(
+            instance.alpha,
+            instance.num_layers,
+            instance.capacities.clone(),
+            &instance.sources_drains_embeddings,
+        );
+        instance_file.coordinate_system = instance.coordinate_system;
+        instance_file.metric = instance.metric;
+        instance_file.edge_cost_function = instance.edge_cost_function.clone();
+        instance_file.layer_alphas = instance.layer_alphas.clone();
+        instance_file.layer_cost_multipliers = instance.layer_cost_multipliers.clone();
+        instance_file.vertex_opening_costs = instance.vertex_opening_costs.clone();
+        instance_file.max_path_length = instance.max_path_length.clone();
+        instance_file.max_children = instance.max_children.clone();
+        instance_file.robustness_penalty = instance.robustness_penalty;
+        return instance_file;
+    }
+
+    pub fn get_cost_model(&self) -> CostModel {
+        return CostModel::new(
+            self.alpha,
+            self.metric,
+            self.coordinate_system,
+            self.edge_cost_function.clone(),
+        )
+        .with_layer_costs(
+            self.layer_alphas.clone(),
+            self.layer_cost_multipliers.clone(),
+        )
+        .with_vertex_opening_costs(self.vertex_opening_costs.clone())
+        .with_max_path_length(self.max_path_length.clone())
+        .with_robustness_penalty(self.robustness_penalty);
+    }
+
+    pub fn get_sources_drains_embeddings(&self) -> VertexEmbeddings {
+        let mut sources_drains_embeddings = VertexEmbeddings::new_with_size(self.num_layers);
+        sources_drains_embeddings.embeddings[0] = self.sources.clone();
+        sources_drains_embeddings.embeddings[self.num_layers - 1] = self.drains.clone();
+        return sources_drains_embeddings;
+    }
+
+    pub fn to_test_instance(&self) -> FlamecastTestInstance {
+        let mut test_instance = FlamecastTestInstance::new(
+            self.alpha,
+            self.num_layers,
+            self.capacities.clone(),
+            self.get_sources_drains_embeddings(),
+        );
+        test_instance.layer_alphas = self.layer_alphas.clone();
+        test_instance.layer_cost_multipliers = self.layer_cost_multipliers.clone();
+        return test_instance;
+    }
+
+    pub fn to_flamecast_instance(
+        &self,
+        initial_solution_function: InitialSolutionFunction,
+    ) -> Result<FlamecastInstance, String> {
+        let mut instance = FlamecastInstance::new_with_max_children(
+            self.alpha,
+            self.num_layers,
+            self.capacities.clone(),
+            self.get_sources_drains_embeddings(),
+            initial_solution_function,
+            self.max_children.clone(),
+        )?;
+        instance.coordinate_system = self.coordinate_system;
+        // opening costs and the robustness penalty do not change the embedding, so the initial
+        // solution stays valid
+        instance.vertex_opening_costs = self.vertex_opening_costs.clone();
+        instance.robustness_penalty = self.robustness_penalty;
+        if self.metric != DistanceMetric::Euclidean
+            || !self.edge_cost_function.is_power()
+            || !self.layer_alphas.is_empty()
+            || !self.layer_cost_multipliers.is_empty()
+            || !self.max_path_length.is_unbounded()
+        {
+            // the initial solution is embedded again with the cost model of the file
+            instance.metric = self.metric;
+            instance.edge_cost_function = self.edge_cost_function.clone();
+            instance.layer_alphas = self.layer_alphas.clone();
+            instance.layer_cost_multipliers = self.layer_cost_multipliers.clone();
+            instance
+                .set_max_path_length(self.max_path_length.clone(), &EmbeddingOptions::default());
+        }
+        return Ok(instance);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_layers < 2 {
+            return Err(String::from("An instance needs at least two layers."));
+        }
+        if self.capacities.len() != self.num_layers {
+            return Err(format!(
+                "Expected {} capacities but got {}.",
+                self.num_layers,
+                self.capacities.len()
+            ));
+        }
+        if self.sources.is_empty() || self.drains.is_empty() {
+            return Err(String::from("An instance needs sources and drains."));
+        }
+        CostModel::validate_alpha(self.alpha)?;
+        CostModel::validate_layer_costs(
+            &self.layer_alphas,
+            &self.layer_cost_multipliers,
+            self.num_layers,
+        )?;
+        CostModel::validate_vertex_opening_costs(&self.vertex_opening_costs, self.num_layers)?;
+        self.metric.validate()?;
+        self.edge_cost_function.validate()?;
+        self.max_path_length.validate(self.sources.len())?;
+        CostModel::validate_robustness_penalty(self.robustness_penalty)?;
+        self.max_children
+            .validate(&self.capacities, self.sources.len(), self.drains.len())?;
+        return Ok(());
+    }
+
+    pub fn from_json(content: &str, fallback_alpha: Option<f64>) -> Result<Self, String> {
+        // older file shapes are migrated, the fallback alpha is used for shapes without alpha
+        let instance = migrate_instance(content, fallback_alpha)?;
+        instance.validate()?;
+        return Ok(instance);
+    }
+
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string_pretty(self).unwrap();
+    }
+
+    pub fn load(file_path: &str, fallback_alpha: Option<f64>) -> Result<Self, String> {
+        let content = fs::read_to_string(file_path)
+            .map_err(|error| format!("Failed to read {}: {}", file_path, error))?;
+        return Self::from_json(&content, fallback_alpha);
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        return fs::write(file_path, self.to_json())
+            .map_err(|error| format!("Failed to write {}: {}", file_path, error));
+    }
+}
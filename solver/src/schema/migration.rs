@@ -0,0 +1,197 @@
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    CoordinateSystem, DistanceMetric, EdgeCostFunction, FlamecastTestInstance, MaxChildren,
+    MaxPathLength, VertexEmbedding,
+};
+
+use super::{
+    FlamecastInstanceFile, FlamecastSolutionFile, INSTANCE_SCHEMA_NAME, INSTANCE_SCHEMA_VERSION,
+    SOLUTION_SCHEMA_NAME, SOLUTION_SCHEMA_VERSION,
+};
+
+// file shapes that were used before the versioned schema existed
+
+#[derive(Debug, Clone, Deserialize)]
+struct BaseInstanceShape {
+    sources: Vec<VertexEmbedding>,
+    drains: Vec<VertexEmbedding>,
+    layers: usize,
+    capacities: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CircleInstanceShape {
+    sources: Vec<Vec<f64>>,
+    sinks: Vec<Vec<f64>>,
+    layers: usize,
+    capacities: Vec<Option<usize>>, // capacities of all layers except the sources, None means unlimited
+}
+
+pub fn migrate_instance(
+    content: &str,
+    fallback_alpha: Option<f64>,
+) -> Result<FlamecastInstanceFile, String> {
+    let value = serde_json::from_str::<Value>(content)
+        .map_err(|error| format!("Invalid JSON: {}", error))?;
+
+    if value.get("schema").is_some() {
+        return migrate_versioned_instance(value);
+    }
+
+    // an instance with solution state contains all fields of a test instance
+    if value.get("sources_drains_embeddings").is_some() {
+        // fields of a flamecast instance that a test instance does not have
+        let coordinate_system: CoordinateSystem = read_optional_field(&value, "coordinate_system")?;
+        let metric: DistanceMetric = read_optional_field(&value, "metric")?;
+        let edge_cost_function: EdgeCostFunction =
+            read_optional_field(&value, "edge_cost_function")?;
+        let vertex_opening_costs: Vec<f64> = read_optional_field(&value, "vertex_opening_costs")?;
+        let max_path_length: MaxPathLength = read_optional_field(&value, "max_path_length")?;
+        let max_children: MaxChildren = read_optional_field(&value, "max_children")?;
+        let robustness_penalty: f64 = read_optional_field(&value, "robustness_penalty")?;
+        let test_instance = serde_json::from_value::<FlamecastTestInstance>(value)
+            .map_err(|error| format!("Invalid flamecast instance: {}", error))?;
+        let mut instance_file = FlamecastInstanceFile::from_test_instance(&test_instance);
+        instance_file.coordinate_system = coordinate_system;
+        instance_file.metric = metric;
+        instance_file.edge_cost_function = edge_cost_function;
+        instance_file.vertex_opening_costs = vertex_opening_costs;
+        instance_file.max_path_length = max_path_length;
+        instance_file.max_children = max_children;
+        instance_file.robustness_penalty = robustness_penalty;
+        return Ok(instance_file);
+    }
+
+    let alpha = match value.get("alpha").and_then(|alpha| alpha.as_f64()) {
+        Some(alpha) => alpha,
+        None => fallback_alpha.ok_or("The instance has no alpha and no fallback was given.")?,
+    };
+
+    if value.get("sinks").is_some() {
+        let circle_instance = serde_json::from_value::<CircleInstanceShape>(value)
+            .map_err(|error| format!("Invalid circle instance: {}", error))?;
+        return Ok(migrate_circle_instance(circle_instance, alpha));
+    }
+
+    if value.get("drains").is_some() {
+        let base_instance = serde_json::from_value::<BaseInstanceShape>(value)
+            .map_err(|error| format!("Invalid base instance: {}", error))?;
+        return Ok(FlamecastInstanceFile::new(
+            alpha,
+            base_instance.layers,
+            base_instance.capacities,
+            base_instance.sources,
+            base_instance.drains,
+        ));
+    }
+
+    return Err(String::from("Unknown instance format."));
+}
+
+fn read_optional_field<T: DeserializeOwned + Default>(
+    value: &Value,
+    field: &str,
+) -> Result<T, String> {
+    // a missing field gets its default, a field that cannot be read is an error
+    return match value.get(field) {
+        Some(field_value) => serde_json::from_value(field_value.clone())
+            .map_err(|error| format!("Invalid field {}: {}", field, error)),
+        None => Ok(T::default()),
+    };
+}
+
+fn migrate_versioned_instance(mut value: Value) -> Result<FlamecastInstanceFile, String> {
+    let version = check_schema(&value, INSTANCE_SCHEMA_NAME, INSTANCE_SCHEMA_VERSION)?;
+
+    // every step migrates from one version to the next
+    if version < 2 {
+        migrate_instance_from_version_1(&mut value);
+    }
+    return serde_json::from_value::<FlamecastInstanceFile>(value)
+        .map_err(|error| format!("Invalid instance file: {}", error));
+}
+
+fn migrate_instance_from_version_1(value: &mut Value) {
+    // version 1 only stored the euclidean single alpha model, the missing cost model and
+    // constraint fields of version 2 default to it
+    value["version"] = Value::from(2);
+}
+
+fn migrate_circle_instance(
+    circle_instance: CircleInstanceShape,
+    alpha: f64,
+) -> FlamecastInstanceFile {
+    let num_sources = circle_instance.sources.len();
+    let mut capacities = vec![1];
+    capacities.extend(
+        circle_instance
+            .capacities
+            .iter()
+            .map(|capacity| capacity.unwrap_or(num_sources)),
+    );
+
+    let to_embeddings = |points: &Vec<Vec<f64>>| {
+        points
+            .iter()
+            .map(|point| (point[0], point[1]))
+            .collect::<Vec<VertexEmbedding>>()
+    };
+
+    return FlamecastInstanceFile::new(
+        alpha,
+        circle_instance.layers,
+        capacities,
+        to_embeddings(&circle_instance.sources),
+        to_embeddings(&circle_instance.sinks),
+    );
+}
+
+pub fn migrate_solution(content: &str) -> Result<FlamecastSolutionFile, String> {
+    let mut value = serde_json::from_str::<Value>(content)
+        .map_err(|error| format!("Invalid JSON: {}", error))?;
+
+    if value.get("schema").is_some() {
+        let version = check_schema(&value, SOLUTION_SCHEMA_NAME, SOLUTION_SCHEMA_VERSION)?;
+        // the instance of a version 1 solution is a version 1 instance
+        if version < 2 {
+            migrate_instance_from_version_1(&mut value["instance"]);
+            value["version"] = Value::from(2);
+        }
+        return serde_json::from_value::<FlamecastSolutionFile>(value)
+            .map_err(|error| format!("Invalid solution file: {}", error));
+    }
+
+    // serialized flamecast instances contain the current solution and the logger
+    if value.get("solution_state").is_some() {
+        let instance = migrate_instance(content, None)?;
+        let solution = serde_json::from_value(value["solution_state"]["current_solution"].clone())
+            .map_err(|error| format!("Invalid solution: {}", error))?;
+        // the logger of an unsolved instance contains infinite values which are stored as null
+        let logger = serde_json::from_value(value["logger"].clone()).ok();
+        return Ok(FlamecastSolutionFile::new(instance, solution, logger));
+    }
+
+    return Err(String::from("Unknown solution format."));
+}
+
+fn check_schema(value: &Value, schema_name: &str, current_version: u32) -> Result<u64, String> {
+    if value["schema"] != schema_name {
+        return Err(format!(
+            "Expected schema {} but got {}.",
+            schema_name, value["schema"]
+        ));
+    }
+
+    let version = value["version"]
+        .as_u64()
+        .ok_or("The file has no schema version.")?;
+    if version > current_version as u64 {
+        return Err(format!(
+            "Schema version {} is newer than the supported version {}.",
+            version, current_version
+        ));
+    }
+    return Ok(version);
+}
@@ -0,0 +1,17 @@
+mod instance_file;
+mod migration;
+mod solution_file;
+mod verification;
+
+pub use instance_file::*;
+pub use migration::*;
+pub use solution_file::*;
+pub use verification::*;
+
+pub const INSTANCE_SCHEMA_NAME: &str = "flamecast-instance";
+pub const SOLUTION_SCHEMA_NAME: &str = "flamecast-solution";
+
+// increase the version whenever the schema changes and add a migration from the previous version,
+// version 2 added the cost model and constraint fields to the instance
+pub const INSTANCE_SCHEMA_VERSION: u32 = 2;
+pub const SOLUTION_SCHEMA_VERSION: u32 = 2;
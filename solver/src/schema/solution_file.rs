@@ -0,0 +1,144 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    FlamecastInstance, GraphEmbedding, SimulatedAnnealingLogger, SolutionState, TopologyCache,
+};
+
+use super::{
+    migrate_solution, FlamecastInstanceFile, SOLUTION_SCHEMA_NAME, SOLUTION_SCHEMA_VERSION,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlamecastSolutionFile {
+    pub schema: String,
+    pub version: u32,
+    pub instance: FlamecastInstanceFile,
+    pub solution: GraphEmbedding,
+    pub objective_value: f64,
+    pub logger: Option<SimulatedAnnealingLogger>,
+}
+
+impl FlamecastSolutionFile {
+    pub fn new(
+        instance: FlamecastInstanceFile,
+        solution: GraphEmbedding,
+        logger: Option<SimulatedAnnealingLogger>,
+    ) -> Self {
+        let objective_value = solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+        Self {
+            schema: String::from(SOLUTION_SCHEMA_NAME),
+            version: SOLUTION_SCHEMA_VERSION,
+            instance,
+            solution,
+            objective_value,
+            logger,
+        }
+    }
+
+    pub fn from_flamecast_instance(instance: &FlamecastInstance, include_logger: bool) -> Self {
+        let logger = if include_logger {
+            Some(instance.logger.clone())
+        } else {
+            None
+        };
+        return Self::new(
+            FlamecastInstanceFile::from_flamecast_instance(instance),
+            instance.solution_state.current_solution.clone(),
+            logger,
+        );
+    }
+
+    pub fn to_flamecast_instance(&self) -> FlamecastInstance {
+        // the stored solution becomes the initial and current solution of the instance
+        return FlamecastInstance {
+            alpha: self.instance.alpha,
+            num_layers: self.instance.num_layers,
+            capacities: self.instance.capacities.clone(),
+            sources_drains_embeddings: self.instance.get_sources_drains_embeddings(),
+            coordinate_system: self.instance.coordinate_system,
+            metric: self.instance.metric,
+            edge_cost_function: self.instance.edge_cost_function.clone(),
+            layer_alphas: self.instance.layer_alphas.clone(),
+            layer_cost_multipliers: self.instance.layer_cost_multipliers.clone(),
+            vertex_opening_costs: self.instance.vertex_opening_costs.clone(),
+            max_path_length: self.instance.max_path_length.clone(),
+            max_children: self.instance.max_children.clone(),
+            robustness_penalty: self.instance.robustness_penalty,
+            solution_state: SolutionState::new(self.solution.clone()),
+            logger: match &self.logger {
+                Some(logger) => logger.clone(),
+                None => SimulatedAnnealingLogger::new_empty(),
+            },
+            source_updates: Vec::new(),
+            topology_cache: TopologyCache::default(),
+        };
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.instance.validate()?;
+
+        let graph = &self.solution.base_graph;
+        if !graph.is_valid_flamecast_topology_check_all(
+            &self.instance.capacities,
+            self.instance.sources.len(),
+            self.instance.drains.len(),
+            self.instance.num_layers,
+        ) {
+            return Err(String::from(
+                "The solution is not a valid topology for the instance.",
+            ));
+        }
+
+        let violating_vertices = self.instance.max_children.get_violating_vertices(graph);
+        if let Some(vertex) = violating_vertices.first() {
+            return Err(format!(
+                "The vertex {} has {} children but at most {} are allowed.",
+                vertex.to_string(),
+                graph.get_vertex(vertex).get_number_of_children(),
+                self.instance.max_children.get_max_children(vertex.layer)
+            ));
+        }
+
+        let source_path_lengths = self
+            .solution
+            .calculate_projected_source_path_lengths(&self.instance.get_cost_model());
+        let violated_sources = self
+            .instance
+            .max_path_length
+            .get_violated_sources(&source_path_lengths);
+        if let Some(source_index) = violated_sources.first() {
+            return Err(format!(
+                "The path of source {} has length {} but at most {} is allowed.",
+                source_index,
+                source_path_lengths[*source_index],
+                self.instance
+                    .max_path_length
+                    .get_max_path_length(*source_index)
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let solution = migrate_solution(content)?;
+        solution.validate()?;
+        return Ok(solution);
+    }
+
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string_pretty(self).unwrap();
+    }
+
+    pub fn load(file_path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(file_path)
+            .map_err(|error| format!("Failed to read {}: {}", file_path, error))?;
+        return Self::from_json(&content);
+    }
+
+    pub fn save(&self, file_path: &str) -> Result<(), String> {
+        return fs::write(file_path, self.to_json())
+            .map_err(|error| format!("Failed to write {}: {}", file_path, error));
+    }
+}
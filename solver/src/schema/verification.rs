@@ -0,0 +1,495 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    graph_embedding::embed_directed_graph, CoordinateSystem, DistanceMetric, EdgeCostFunction,
+    EmbeddingOptions, GraphEmbedding, SearchDepth, VertexEmbedding, VertexID, EARTH_RADIUS_KM,
+};
+
+use super::{migrate_solution, FlamecastInstanceFile, FlamecastSolutionFile};
+
+pub const VERIFICATION_TOLERANCE: f64 = 1e-6; // relative deviation between stored and recomputed values
+pub const REEMBEDDING_TOLERANCE: f64 = 1e-4; // relative improvement a deeper embedding may find
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationMismatch {
+    pub quantity: String,
+    pub recomputed_value: f64,
+    pub reported_value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    pub instance_matches: bool, // the solution file stores the same instance as the instance file
+    pub feasibility_errors: Vec<String>,
+    pub recomputed_objective_value: Option<f64>, // None if the solution is not a flamecast graph
+    pub reembedded_objective_value: Option<f64>,
+    pub mismatches: Vec<VerificationMismatch>,
+}
+
+impl VerificationReport {
+    pub fn is_verified(&self) -> bool {
+        // a solution of another instance is not verified, even if it is feasible for this one
+        return self.instance_matches
+            && self.feasibility_errors.is_empty()
+            && self.mismatches.is_empty();
+    }
+}
+
+impl FlamecastSolutionFile {
+    pub fn load_without_validation(file_path: &str) -> Result<Self, String> {
+        // the verifier reports every error of a solution instead of rejecting it on the first one
+        let content = fs::read_to_string(file_path)
+            .map_err(|error| format!("Failed to read {}: {}", file_path, error))?;
+        return migrate_solution(&content);
+    }
+
+    // checks the solution against the given instance and recomputes its costs without the
+    // flows, lengths and costs of the graph, embedding and cost model types, the stored objective
+    // values are only compared with the recomputed one, with a search depth the topology is
+    // embedded again to check that the stored embedding is optimal
+    pub fn verify(
+        &self,
+        instance: &FlamecastInstanceFile,
+        search_depth: Option<SearchDepth>,
+    ) -> VerificationReport {
+        let mut report = VerificationReport {
+            instance_matches: values_match(
+                &serde_json::to_value(&self.instance).unwrap(),
+                &serde_json::to_value(instance).unwrap(),
+            ),
+            feasibility_errors: Vec::new(),
+            recomputed_objective_value: None,
+            reembedded_objective_value: None,
+            mismatches: Vec::new(),
+        };
+        if let Err(error) = instance.validate() {
+            report
+                .feasibility_errors
+                .push(format!("The instance is invalid: {}", error));
+            return report;
+        }
+
+        let vertex_flows = match self.verify_structure(instance, &mut report.feasibility_errors) {
+            Some(vertex_flows) => vertex_flows,
+            None => return report,
+        };
+        // the sources and drains are taken from the instance, moved ones are reported above
+        let mut embeddings = self.solution.vertices_embeddings.embeddings.clone();
+        embeddings[0] = instance.sources.clone();
+        embeddings[instance.num_layers - 1] = instance.drains.clone();
+        self.verify_constraints(
+            instance,
+            &vertex_flows,
+            &embeddings,
+            &mut report.feasibility_errors,
+        );
+
+        let recomputed_objective_value =
+            self.recompute_objective_value(instance, &vertex_flows, &embeddings);
+        report.recomputed_objective_value = Some(recomputed_objective_value);
+
+        let cost_model = instance.get_cost_model();
+        let mut reported_values = vec![
+            (String::from("stored objective value"), self.objective_value),
+            (
+                String::from("library objective value"),
+                self.solution.calculate_costs_with_cost_model(&cost_model),
+            ),
+        ];
+        if let Some(logger) = &self.logger {
+            if logger.final_objective_value.is_finite() {
+                reported_values.push((
+                    String::from("logged final objective value"),
+                    logger.final_objective_value,
+                ));
+            }
+        }
+        for (quantity, reported_value) in reported_values {
+            let tolerance = VERIFICATION_TOLERANCE * recomputed_objective_value.abs().max(1.0);
+            if (reported_value - recomputed_objective_value).abs() > tolerance {
+                report.mismatches.push(VerificationMismatch {
+                    quantity,
+                    recomputed_value: recomputed_objective_value,
+                    reported_value,
+                });
+            }
+        }
+
+        if let Some(search_depth) = search_depth {
+            let graph = &self.solution.base_graph;
+            let reembedding = GraphEmbedding::new(
+                graph.clone(),
+                embed_directed_graph(
+                    graph,
+                    &instance.get_sources_drains_embeddings(),
+                    &graph.calculate_edge_flows(),
+                    &cost_model,
+                    &EmbeddingOptions::from_depth(search_depth),
+                ),
+            );
+            let reembedded_objective_value =
+                reembedding.calculate_costs_with_cost_model(&cost_model);
+            report.reembedded_objective_value = Some(reembedded_objective_value);
+
+            // a cheaper embedding of the same topology means the stored one is not optimal
+            let tolerance = REEMBEDDING_TOLERANCE * recomputed_objective_value.abs().max(1.0);
+            if reembedded_objective_value < recomputed_objective_value - tolerance {
+                report.mismatches.push(VerificationMismatch {
+                    quantity: String::from("re-embedded objective value"),
+                    recomputed_value: reembedded_objective_value,
+                    reported_value: recomputed_objective_value,
+                });
+            }
+        }
+
+        return report;
+    }
+
+    fn verify_structure(
+        &self,
+        instance: &FlamecastInstanceFile,
+        errors: &mut Vec<String>,
+    ) -> Option<Vec<Vec<usize>>> {
+        // checks that the solution is a forest from the sources to the drains of the instance and
+        // returns the number of sources whose path leads through every vertex
+        let graph = &self.solution.base_graph;
+        let embeddings = &self.solution.vertices_embeddings.embeddings;
+        let num_layers = instance.num_layers;
+        if graph.layers.len() != num_layers || embeddings.len() != num_layers {
+            errors.push(format!(
+                "The instance has {} layers but the solution has {} layers and {} embedded layers.",
+                num_layers,
+                graph.layers.len(),
+                embeddings.len()
+            ));
+            return None;
+        }
+
+        let number_of_errors = errors.len();
+        for (layer_index, name, expected_size) in [
+            (0, "sources", instance.sources.len()),
+            (num_layers - 1, "drains", instance.drains.len()),
+        ] {
+            let size = graph.layers[layer_index].vertices.len();
+            if size != expected_size {
+                errors.push(format!(
+                    "The instance has {} {} but the solution has {}.",
+                    expected_size, name, size
+                ));
+            }
+        }
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            if embeddings[layer_index].len() != layer.vertices.len() {
+                errors.push(format!(
+                    "Layer {} has {} vertices but {} embeddings.",
+                    layer_index,
+                    layer.vertices.len(),
+                    embeddings[layer_index].len()
+                ));
+            }
+
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                let vertex_id = VertexID::new(layer_index, vertex_index);
+                match vertex.parent_index {
+                    Some(_) if layer_index == num_layers - 1 => {
+                        errors.push(format!("The drain {} has a parent.", vertex_id.to_string()))
+                    }
+                    Some(parent_index)
+                        if parent_index >= graph.layers[layer_index + 1].vertices.len() =>
+                    {
+                        errors.push(format!(
+                            "The vertex {} has the parent {} which does not exist.",
+                            vertex_id.to_string(),
+                            VertexID::new(layer_index + 1, parent_index).to_string()
+                        ))
+                    }
+                    None if layer_index < num_layers - 1 => errors.push(format!(
+                        "The vertex {} has no parent.",
+                        vertex_id.to_string()
+                    )),
+                    _ => {}
+                }
+            }
+        }
+        if errors.len() > number_of_errors {
+            return None;
+        }
+
+        // the children of every vertex are exactly the vertices that have it as their parent
+        for layer_index in 1..num_layers {
+            let mut parents_children = vec![Vec::new(); graph.layers[layer_index].vertices.len()];
+            for (child_index, child) in graph.layers[layer_index - 1].vertices.iter().enumerate() {
+                parents_children[child.parent_index.unwrap()].push(child_index);
+            }
+            for (vertex_index, vertex) in graph.layers[layer_index].vertices.iter().enumerate() {
+                let mut children = vertex.children_indices.clone().unwrap_or_default();
+                children.sort();
+                if children != parents_children[vertex_index] {
+                    errors.push(format!(
+                        "The vertex {} lists the children {:?} but the vertices {:?} have it as their parent.",
+                        VertexID::new(layer_index, vertex_index).to_string(),
+                        children,
+                        parents_children[vertex_index]
+                    ));
+                }
+            }
+        }
+
+        let mut vertex_flows = graph
+            .layers
+            .iter()
+            .map(|layer| vec![0; layer.vertices.len()])
+            .collect::<Vec<Vec<usize>>>();
+        for source_index in 0..graph.layers[0].vertices.len() {
+            let mut current = VertexID::new(0, source_index);
+            vertex_flows[0][source_index] += 1;
+            while let Some(parent_index) = graph.get_vertex(&current).parent_index {
+                current = VertexID::new(current.layer + 1, parent_index);
+                vertex_flows[current.layer][current.index] += 1;
+            }
+        }
+        // drains may stay unused, every other vertex has to serve a source
+        for (layer_index, layer_flows) in vertex_flows.iter().enumerate().take(num_layers - 1) {
+            for (vertex_index, flow) in layer_flows.iter().enumerate() {
+                if *flow == 0 {
+                    errors.push(format!(
+                        "The vertex {} is not on the path of any source.",
+                        VertexID::new(layer_index, vertex_index).to_string()
+                    ));
+                }
+            }
+        }
+
+        if errors.len() > number_of_errors {
+            return None;
+        }
+        return Some(vertex_flows);
+    }
+
+    fn verify_constraints(
+        &self,
+        instance: &FlamecastInstanceFile,
+        vertex_flows: &Vec<Vec<usize>>,
+        embeddings: &Vec<Vec<VertexEmbedding>>,
+        errors: &mut Vec<String>,
+    ) {
+        let graph = &self.solution.base_graph;
+        let solution_embeddings = &self.solution.vertices_embeddings.embeddings;
+        for (layer_index, name) in [(0, "source"), (instance.num_layers - 1, "drain")] {
+            for (vertex_index, position) in embeddings[layer_index].iter().enumerate() {
+                let solution_position = solution_embeddings[layer_index][vertex_index];
+                // json parsing may change the last digits of the coordinates
+                if (position.0 - solution_position.0).abs() > VERIFICATION_TOLERANCE
+                    || (position.1 - solution_position.1).abs() > VERIFICATION_TOLERANCE
+                {
+                    errors.push(format!(
+                        "The {} {} is at {:?} instead of {:?}.",
+                        name, vertex_index, solution_position, position
+                    ));
+                }
+            }
+        }
+
+        for (layer_index, layer_flows) in vertex_flows.iter().enumerate() {
+            for (vertex_index, flow) in layer_flows.iter().enumerate() {
+                if *flow > instance.capacities[layer_index] {
+                    errors.push(format!(
+                        "The vertex {} serves {} sources but the capacity of layer {} is {}.",
+                        VertexID::new(layer_index, vertex_index).to_string(),
+                        flow,
+                        layer_index,
+                        instance.capacities[layer_index]
+                    ));
+                }
+            }
+        }
+
+        for vertex in instance.max_children.get_violating_vertices(graph) {
+            errors.push(format!(
+                "The vertex {} has {} children but at most {} are allowed.",
+                vertex.to_string(),
+                graph.get_vertex(&vertex).get_number_of_children(),
+                instance.max_children.get_max_children(vertex.layer)
+            ));
+        }
+
+        // the max path lengths bound the lengths on the projection
+        let source_path_lengths = (0..graph.layers[0].vertices.len())
+            .map(|source_index| {
+                let mut path_length = 0.0;
+                let mut current = VertexID::new(0, source_index);
+                while let Some(parent_index) = graph.get_vertex(&current).parent_index {
+                    let parent = VertexID::new(current.layer + 1, parent_index);
+                    path_length += get_norm(
+                        &instance.metric,
+                        &embeddings[current.layer][current.index],
+                        &embeddings[parent.layer][parent.index],
+                    );
+                    current = parent;
+                }
+                path_length
+            })
+            .collect::<Vec<f64>>();
+        for source_index in instance
+            .max_path_length
+            .get_violated_sources(&source_path_lengths)
+        {
+            errors.push(format!(
+                "The path of source {} has length {} but at most {} is allowed.",
+                source_index,
+                source_path_lengths[source_index],
+                instance.max_path_length.get_max_path_length(source_index)
+            ));
+        }
+    }
+
+    fn recompute_objective_value(
+        &self,
+        instance: &FlamecastInstanceFile,
+        vertex_flows: &Vec<Vec<usize>>,
+        embeddings: &Vec<Vec<VertexEmbedding>>,
+    ) -> f64 {
+        // in a forest the flow on the outgoing edge of a vertex is the flow of the vertex
+        let graph = &self.solution.base_graph;
+        let num_layers = graph.layers.len();
+        let mut objective_value = 0.0;
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            objective_value += layer.vertices.len() as f64
+                * instance
+                    .vertex_opening_costs
+                    .get(layer_index)
+                    .unwrap_or(&0.0);
+            if layer_index == num_layers - 1 {
+                continue;
+            }
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                objective_value += get_edge_length(
+                    instance,
+                    &embeddings[layer_index][vertex_index],
+                    &embeddings[layer_index + 1][vertex.parent_index.unwrap()],
+                ) * get_flow_weight(
+                    instance,
+                    layer_index,
+                    vertex_flows[layer_index][vertex_index] as f64,
+                );
+            }
+        }
+
+        // an inner vertex that fails disconnects the sources it serves
+        let inner_flows = vertex_flows
+            .iter()
+            .take(num_layers - 1)
+            .skip(1)
+            .flat_map(|layer_flows| layer_flows.iter())
+            .collect::<Vec<&usize>>();
+        if instance.robustness_penalty != 0.0 && !inner_flows.is_empty() {
+            objective_value += instance.robustness_penalty
+                * inner_flows.iter().map(|flow| **flow).sum::<usize>() as f64
+                / inner_flows.len() as f64;
+        }
+        return objective_value;
+    }
+}
+
+fn values_match(value: &Value, other_value: &Value) -> bool {
+    // json parsing may change the last digits of the numbers
+    return match (value, other_value) {
+        (Value::Number(number), Value::Number(other_number)) => {
+            let (number, other_number) = (number.as_f64().unwrap(), other_number.as_f64().unwrap());
+            (number - other_number).abs() <= VERIFICATION_TOLERANCE * number.abs().max(1.0)
+        }
+        (Value::Array(values), Value::Array(other_values)) => {
+            values.len() == other_values.len()
+                && values
+                    .iter()
+                    .zip(other_values.iter())
+                    .all(|(value, other_value)| values_match(value, other_value))
+        }
+        (Value::Object(fields), Value::Object(other_fields)) => {
+            fields.len() == other_fields.len()
+                && fields.iter().all(|(key, value)| {
+                    other_fields
+                        .get(key)
+                        .is_some_and(|other_value| values_match(value, other_value))
+                })
+        }
+        _ => value == other_value,
+    };
+}
+
+// the lengths and flow weights are computed from their definitions instead of with the cost
+// model, so an error in the cost model of the library cannot verify its own results
+
+fn get_norm(metric: &DistanceMetric, from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+    let (dx, dy) = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+    return match metric {
+        DistanceMetric::Euclidean => (dx * dx + dy * dy).sqrt(),
+        DistanceMetric::Manhattan => dx + dy,
+        DistanceMetric::Lp(p) => (dx.powf(*p) + dy.powf(*p)).powf(1.0 / p),
+    };
+}
+
+fn get_edge_length(
+    instance: &FlamecastInstanceFile,
+    from: &VertexEmbedding,
+    to: &VertexEmbedding,
+) -> f64 {
+    let reference = match (&instance.coordinate_system, &instance.metric) {
+        (CoordinateSystem::Geographic(projection), DistanceMetric::Euclidean) => {
+            projection.reference
+        }
+        // other metrics are measured on the projected coordinates
+        _ => return get_norm(&instance.metric, from, to),
+    };
+
+    // great circle distance of the points, the stored coordinates are kilometers of an
+    // equirectangular projection around the reference
+    let reference_latitude = reference.1.to_radians();
+    let to_radians = |point: &VertexEmbedding| {
+        (
+            reference.0.to_radians() + point.0 / (EARTH_RADIUS_KM * reference_latitude.cos()),
+            reference_latitude + point.1 / EARTH_RADIUS_KM,
+        )
+    };
+    let (from_longitude, from_latitude) = to_radians(from);
+    let (to_longitude, to_latitude) = to_radians(to);
+    let haversine = ((to_latitude - from_latitude) / 2.0).sin().powi(2)
+        + from_latitude.cos()
+            * to_latitude.cos()
+            * ((to_longitude - from_longitude) / 2.0).sin().powi(2);
+    return 2.0 * EARTH_RADIUS_KM * haversine.sqrt().min(1.0).asin();
+}
+
+fn get_flow_weight(instance: &FlamecastInstanceFile, layer_index: usize, flow: f64) -> f64 {
+    // the edges from layer i to layer i + 1 use the layer alpha and multiplier i if given
+    let alpha = *instance
+        .layer_alphas
+        .get(layer_index)
+        .unwrap_or(&instance.alpha);
+    let multiplier = *instance
+        .layer_cost_multipliers
+        .get(layer_index)
+        .unwrap_or(&1.0);
+    let flow_costs = match &instance.edge_cost_function {
+        EdgeCostFunction::Power => flow.powf(alpha),
+        EdgeCostFunction::PiecewiseLinear(edge_cost) => {
+            // linear between (0, 0) and the breakpoints, the last segment is extended
+            let mut points = vec![(0.0, 0.0)];
+            points.extend(edge_cost.breakpoints.iter().cloned());
+            let segment = (1..points.len())
+                .find(|index| flow <= points[*index].0)
+                .unwrap_or(points.len() - 1);
+            let (start, end) = (points[segment - 1], points[segment]);
+            start.1 + (end.1 - start.1) / (end.0 - start.0) * (flow - start.0)
+        }
+        EdgeCostFunction::FixedVariable(edge_cost) if flow > 0.0 => {
+            edge_cost.fixed + edge_cost.variable * flow
+        }
+        EdgeCostFunction::FixedVariable(_) => 0.0,
+        EdgeCostFunction::Logarithmic(edge_cost) => edge_cost.scale * (1.0 + flow).ln(),
+    };
+    return multiplier * flow_costs;
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{CostModel, EmbeddingOptions, FlamecastInstance, GraphEmbedding, SolutionStatistics};
+
+use super::{OptimizationOptions, SimulatedAnnealingLogger};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlphaSweepPoint {
+    pub alpha: f64,
+    pub objective_value: f64,
+    pub solution: GraphEmbedding,
+    pub logger: SimulatedAnnealingLogger,
+    pub statistics: SolutionStatistics,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlphaSweep {
+    pub points: Vec<AlphaSweepPoint>, // in the order in which the alphas were solved
+}
+
+impl AlphaSweep {
+    pub fn new() -> Self {
+        Self { points: Vec::new() }
+    }
+
+    pub fn get_alphas(&self) -> Vec<f64> {
+        return self.points.iter().map(|point| point.alpha).collect();
+    }
+
+    pub fn get_objective_values(&self) -> Vec<f64> {
+        return self
+            .points
+            .iter()
+            .map(|point| point.objective_value)
+            .collect();
+    }
+
+    // (alpha, objective value) pairs of the best solution found for every alpha
+    pub fn get_cost_curve(&self) -> Vec<(f64, f64)> {
+        return self
+            .points
+            .iter()
+            .map(|point| (point.alpha, point.objective_value))
+            .collect();
+    }
+
+    pub fn get_number_of_vertices(&self) -> Vec<usize> {
+        return self
+            .points
+            .iter()
+            .map(|point| point.solution.base_graph.get_number_of_vertices())
+            .collect();
+    }
+}
+
+impl FlamecastInstance {
+    // solves the instance for every alpha in the given order, every solve starts from the best
+    // topology of the previous alpha, the options are generated from the warm started objective
+    // value and the number of vertices of the warm started solution
+    pub fn solve_alpha_sweep(
+        &mut self,
+        alphas: &Vec<f64>,
+        options_generator: impl Fn(f64, usize) -> OptimizationOptions,
+    ) -> Result<AlphaSweep, String> {
+        if alphas.is_empty() {
+            return Err(String::from("At least one alpha has to be given."));
+        }
+        if !self.layer_alphas.is_empty() {
+            return Err(String::from(
+                "An alpha sweep needs the same alpha in every layer.",
+            ));
+        }
+        // an invalid alpha is rejected before the sweep starts
+        for alpha in alphas.iter() {
+            CostModel::validate_alpha(*alpha)?;
+        }
+
+        let mut alpha_sweep = AlphaSweep::new();
+        for alpha in alphas.iter() {
+            self.set_alpha(*alpha, &EmbeddingOptions::default())?;
+
+            let options = options_generator(
+                self.get_objective_function_value(),
+                self.solution_state
+                    .current_solution
+                    .base_graph
+                    .get_number_of_vertices(),
+            );
+            self.solve(options);
+
+            alpha_sweep.points.push(AlphaSweepPoint {
+                alpha: *alpha,
+                objective_value: self.get_objective_function_value(),
+                solution: self.solution_state.current_solution.clone(),
+                logger: self.logger.clone(),
+                statistics: self.get_solution_statistics(),
+            });
+        }
+
+        return Ok(alpha_sweep);
+    }
+}
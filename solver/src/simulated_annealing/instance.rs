@@ -2,13 +2,14 @@ use rand::Rng;
 
 use crate::{
     neighborhood::{Neighbor, NeighborCost},
-    EmbeddingOptions, FlamecastInstance, NeighborChange, Stopwatch,
+    CancellationToken, EmbeddingOptions, FlamecastInstance, IterationProgress, NeighborChange,
+    ParetoArchive, ParetoPoint, SolveObserver, Stopwatch, TopologyCache,
 };
 
 use super::{
     neighborhood_change_probability, select_neighbor_from_complete,
-    select_neighbor_from_heuristical, CoolingSchedule, NeighborSearchOption, OptimizationOptions,
-    SimulatedAnnealingLogger,
+    select_neighbor_from_heuristical, CoolingSchedule, MultiObjectiveOption, NeighborSearchOption,
+    OptimizationOptions, SimulatedAnnealingLogger,
 };
 
 #[derive(Debug)]
@@ -25,6 +26,7 @@ pub struct SimulatedAnnealing<'a> {
     pub neighbor_test_options: EmbeddingOptions,
     pub neighbor_cost_options: EmbeddingOptions,
     pub final_cost_options: EmbeddingOptions,
+    pub multi_objective_option: MultiObjectiveOption,
 }
 
 impl<'a> SimulatedAnnealing<'a> {
@@ -32,10 +34,7 @@ impl<'a> SimulatedAnnealing<'a> {
         flamecast_instance: &'a mut FlamecastInstance,
         optimization_options: OptimizationOptions,
     ) -> Self {
-        let current_objective_value = flamecast_instance
-            .solution_state
-            .current_solution
-            .calculate_costs(flamecast_instance.alpha);
+        let current_objective_value = flamecast_instance.get_objective_function_value();
         let logger = SimulatedAnnealingLogger::new(
             flamecast_instance.logger.init_time.clone(),
             &flamecast_instance.solution_state.current_solution,
@@ -45,6 +44,9 @@ impl<'a> SimulatedAnnealing<'a> {
             optimization_options.cooling_schedule.clone(),
         );
         flamecast_instance.logger = logger;
+        flamecast_instance.topology_cache =
+            TopologyCache::new(optimization_options.topology_cache_size);
+        flamecast_instance.solution_state.pareto_archive = ParetoArchive::new();
         Self {
             flamecast_instance,
             current_objective_value,
@@ -58,6 +60,7 @@ impl<'a> SimulatedAnnealing<'a> {
             neighbor_test_options: optimization_options.neighbor_test_options,
             neighbor_cost_options: optimization_options.neighbor_cost_options,
             final_cost_options: optimization_options.final_cost_options,
+            multi_objective_option: optimization_options.multi_objective_option,
         }
     }
 
@@ -89,7 +92,35 @@ impl<'a> SimulatedAnnealing<'a> {
         };
     }
 
-    pub fn neighbor_change(&mut self, neighbor_cost: f64, neighbor: &Neighbor) {
+    pub fn update_pareto_archive(&mut self, iteration: usize) {
+        // archives the current solution, its embedding has to match the current objective value
+        if self.multi_objective_option == MultiObjectiveOption::Disabled {
+            return;
+        }
+
+        let current_solution = &self.flamecast_instance.solution_state.current_solution;
+        let max_path_length = match self.multi_objective_option {
+            MultiObjectiveOption::InnerVerticesAndPathLength => {
+                Some(current_solution.calculate_max_path_length_with_cost_model(
+                    &self.flamecast_instance.get_cost_model(),
+                ))
+            }
+            _ => None,
+        };
+        let point = ParetoPoint::new(
+            self.current_objective_value,
+            current_solution.base_graph.get_number_of_inner_vertices(),
+            max_path_length,
+            iteration,
+        );
+        self.flamecast_instance
+            .solution_state
+            .pareto_archive
+            .insert(point);
+    }
+
+    pub fn neighbor_change(&mut self, neighbor_cost: f64, neighbor: &Neighbor) -> bool {
+        // returns whether the neighbor was accepted
         if neighbor_cost >= self.current_objective_value {
             // accept the neighbor with a probability
             // based on the current temperature
@@ -114,7 +145,7 @@ impl<'a> SimulatedAnnealing<'a> {
                     self.flamecast_instance
                         .embed_current_solution(&self.neighbor_cost_options);
                 }
-                return;
+                return false;
             }
         }
 
@@ -129,6 +160,18 @@ impl<'a> SimulatedAnnealing<'a> {
             .flamecast_instance
             .calculate_objective_function_value(&self.neighbor_cost_options);
 
+        let topology_hash = self
+            .flamecast_instance
+            .solution_state
+            .current_solution
+            .base_graph
+            .calculate_topology_hash();
+        self.flamecast_instance.topology_cache.insert(
+            topology_hash,
+            &self.neighbor_cost_options,
+            self.current_objective_value,
+        );
+
         let solution_state = &mut self.flamecast_instance.solution_state;
         let neighbor_change = NeighborChange::new(
             neighbor.clone(),
@@ -144,12 +187,25 @@ impl<'a> SimulatedAnnealing<'a> {
         solution_state
             .best_iteration
             .update(self.current_objective_value, self.iteration);
+        self.update_pareto_archive(self.iteration);
+
+        return true;
     }
 
     pub fn solve(&mut self) {
+        self.solve_with_observer(&mut |_: &IterationProgress| {}, &CancellationToken::new());
+    }
+
+    pub fn solve_with_observer(
+        &mut self,
+        observer: &mut dyn SolveObserver,
+        cancellation_token: &CancellationToken,
+    ) {
         let initial_objective_value = self.current_objective_value;
+        let initial_solution_feasible = self.flamecast_instance.is_path_length_feasible();
 
         let start_watch = Stopwatch::new();
+        self.update_pareto_archive(usize::MAX);
 
         if self.verbose {
             println!("Simulated Annealing Started");
@@ -157,15 +213,23 @@ impl<'a> SimulatedAnnealing<'a> {
         }
 
         while self.iteration < self.max_iterations {
+            // stop the iteration if the caller cancelled the run
+            if cancellation_token.is_cancelled() {
+                self.flamecast_instance.logger.cancelled = true;
+                if self.verbose {
+                    println!("Simulated Annealing Cancelled");
+                }
+                break;
+            }
+
             // details for logging
             let current_iteration_watch = Stopwatch::new();
 
             // if temperature gets negative, stop iteration
-            if self
+            let current_temperature = self
                 .cooling_schedule
-                .get_temperature(self.initial_temperature, self.iteration)
-                <= 0.0
-            {
+                .get_temperature(self.initial_temperature, self.iteration);
+            if current_temperature <= 0.0 {
                 break;
             }
 
@@ -185,7 +249,7 @@ impl<'a> SimulatedAnnealing<'a> {
                 .get_neighbor_cost(&possible_neighbor, &self.neighbor_cost_options);
 
             // perform the neighbor change
-            self.neighbor_change(neighbor_cost, &possible_neighbor);
+            let accepted = self.neighbor_change(neighbor_cost, &possible_neighbor);
 
             // log the current state of the algorithm
             let time_needed = current_iteration_watch.elapsed();
@@ -201,6 +265,7 @@ impl<'a> SimulatedAnnealing<'a> {
                     .current_solution
                     .base_graph
                     .get_number_of_vertices(),
+                self.flamecast_instance.get_opening_costs(),
             );
 
             if self.verbose {
@@ -216,6 +281,22 @@ impl<'a> SimulatedAnnealing<'a> {
                 );
             }
 
+            observer.on_iteration(&IterationProgress {
+                iteration: self.iteration,
+                max_iterations: self.max_iterations,
+                temperature: current_temperature,
+                current_cost: self.current_objective_value,
+                best_cost: self
+                    .flamecast_instance
+                    .solution_state
+                    .best_iteration
+                    .best_value,
+                proposed_neighbor: possible_neighbor,
+                accepted,
+                time_needed,
+                total_time: start_watch.elapsed(),
+            });
+
             self.iteration += 1;
         }
 
@@ -226,7 +307,8 @@ impl<'a> SimulatedAnnealing<'a> {
             .best_iteration
             .best_value;
 
-        if initial_objective_value < best_objective_value {
+        // an initial solution that violates the path length bounds is only kept if no other solution was accepted
+        if initial_solution_feasible && initial_objective_value < best_objective_value {
             self.flamecast_instance.solution_state.current_solution = self
                 .flamecast_instance
                 .solution_state
@@ -237,32 +319,10 @@ impl<'a> SimulatedAnnealing<'a> {
                 .best_iteration
                 .update(initial_objective_value, usize::MAX);
         } else if best_objective_value < current_objective_value {
-            let mut best_solution = self
-                .flamecast_instance
-                .solution_state
-                .initial_solution
-                .clone();
-            for neighbor_change in self
-                .flamecast_instance
+            self.flamecast_instance
                 .solution_state
-                .accepted_neighbors
-                .iter()
-            {
-                best_solution
-                    .base_graph
-                    .apply_neighbor_change(&neighbor_change.neighbor);
-
-                if neighbor_change.iteration
-                    == self
-                        .flamecast_instance
-                        .solution_state
-                        .best_iteration
-                        .iteration
-                {
-                    break;
-                }
-            }
-            self.flamecast_instance.solution_state.current_solution = best_solution;
+                .current_solution
+                .base_graph = self.flamecast_instance.solution_state.get_best_solution();
         }
 
         // Calculate embedding and objective function value with the final options
@@ -270,6 +330,17 @@ impl<'a> SimulatedAnnealing<'a> {
             .flamecast_instance
             .calculate_objective_function_value(&self.final_cost_options);
 
+        self.flamecast_instance.logger.pareto_archive = self
+            .flamecast_instance
+            .solution_state
+            .pareto_archive
+            .clone();
+
+        let topology_cache = &self.flamecast_instance.topology_cache;
+        self.flamecast_instance
+            .logger
+            .log_cache_statistics(topology_cache.hits, topology_cache.misses);
+
         self.flamecast_instance.logger.set_final_solution(
             &self.flamecast_instance.solution_state.current_solution,
             self.current_objective_value,
@@ -277,6 +348,10 @@ impl<'a> SimulatedAnnealing<'a> {
         );
 
         if self.verbose {
+            println!(
+                "Topology Cache Hit Rate: {}",
+                self.flamecast_instance.logger.get_cache_hit_rate()
+            );
             println!("Simulated Annealing Finished");
         }
     }
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{FlamecastInstance, FlamecastInstanceFile, GraphEmbedding};
+
+use super::{InitialSolutionFunction, OptimizationOptions, SimulatedAnnealingLogger};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerCountCandidate {
+    pub num_layers: usize,
+    pub capacities: Vec<usize>,
+    pub objective_value: f64,
+    pub solution: GraphEmbedding,
+    pub logger: SimulatedAnnealingLogger,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerCountSearch {
+    pub candidates: Vec<LayerCountCandidate>, // in the order in which the depths were solved
+    pub best_instance: FlamecastInstance,     // the solved instance of the cheapest depth
+}
+
+impl LayerCountSearch {
+    pub fn get_layer_counts(&self) -> Vec<usize> {
+        return self
+            .candidates
+            .iter()
+            .map(|candidate| candidate.num_layers)
+            .collect();
+    }
+
+    pub fn get_objective_values(&self) -> Vec<f64> {
+        return self
+            .candidates
+            .iter()
+            .map(|candidate| candidate.objective_value)
+            .collect();
+    }
+
+    pub fn get_best_num_layers(&self) -> usize {
+        return self.best_instance.num_layers;
+    }
+}
+
+impl FlamecastInstance {
+    // solves the sources and drains of the instance once for every given capacities vector,
+    // the number of layers of a candidate is the length of its capacities, every candidate
+    // starts from its own initial solution and the options are generated like for an alpha sweep
+    pub fn solve_layer_counts(
+        &self,
+        candidate_capacities: &Vec<Vec<usize>>,
+        initial_solution_function: InitialSolutionFunction,
+        options_generator: fn(f64, usize) -> OptimizationOptions,
+    ) -> Result<LayerCountSearch, String> {
+        if candidate_capacities.is_empty() {
+            return Err(String::from("At least one layer count has to be given."));
+        }
+        // settings with one entry per layer cannot be transferred to another depth
+        if !self.layer_alphas.is_empty()
+            || !self.layer_cost_multipliers.is_empty()
+            || !self.vertex_opening_costs.is_empty()
+            || !self.max_children.is_unlimited()
+        {
+            return Err(String::from(
+                "Layer counts can only be compared without per layer settings.",
+            ));
+        }
+
+        let mut candidates = Vec::new();
+        let mut best_instance: Option<FlamecastInstance> = None;
+        for capacities in candidate_capacities.iter() {
+            let mut instance_file = FlamecastInstanceFile::from_flamecast_instance(self);
+            instance_file.num_layers = capacities.len();
+            instance_file.capacities = capacities.clone();
+            instance_file.validate()?;
+
+            let mut instance =
+                instance_file.to_flamecast_instance(initial_solution_function.clone())?;
+            let options = options_generator(
+                instance.get_objective_function_value(),
+                instance
+                    .solution_state
+                    .current_solution
+                    .base_graph
+                    .get_number_of_vertices(),
+            );
+            instance.solve(options);
+
+            let objective_value = instance.get_objective_function_value();
+            candidates.push(LayerCountCandidate {
+                num_layers: instance.num_layers,
+                capacities: capacities.clone(),
+                objective_value,
+                solution: instance.solution_state.current_solution.clone(),
+                logger: instance.logger.clone(),
+            });
+
+            best_instance = match best_instance {
+                Some(best) if best.get_objective_function_value() <= objective_value => Some(best),
+                _ => Some(instance),
+            };
+        }
+
+        return Ok(LayerCountSearch {
+            candidates,
+            best_instance: best_instance.unwrap(),
+        });
+    }
+}
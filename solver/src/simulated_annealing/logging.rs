@@ -3,7 +3,7 @@ use std::{f64::INFINITY, usize::MAX};
 use chrono::TimeDelta;
 use serde::{Deserialize, Serialize};
 
-use crate::{GraphEmbedding, NeighborChange};
+use crate::{GraphEmbedding, NeighborChange, ParetoArchive, SolutionHistory};
 
 use super::CoolingSchedule;
 
@@ -41,11 +41,21 @@ pub struct SimulatedAnnealingLogger {
     pub current_costs: Vec<f64>,
     pub current_best_costs: Vec<f64>,
     pub current_amount_nodes: Vec<usize>,
+    #[serde(default)]
+    pub current_opening_costs: Vec<f64>, // part of the current costs that comes from opening vertices
     pub accepted_neighbors: Vec<NeighborChange>,
     pub max_iterations: usize,
     pub initial_temperature: f64,
     pub cooling_schedule: CoolingSchedule,
     pub total_time: TimeDeltaSave,
+    #[serde(default)]
+    pub cache_hits: usize,
+    #[serde(default)]
+    pub cache_misses: usize,
+    #[serde(default)]
+    pub cancelled: bool,
+    #[serde(default)]
+    pub pareto_archive: ParetoArchive, // empty if the multi objective mode is disabled
 }
 
 impl SimulatedAnnealingLogger {
@@ -60,11 +70,16 @@ impl SimulatedAnnealingLogger {
             current_costs: Vec::new(),
             current_best_costs: Vec::new(),
             current_amount_nodes: Vec::new(),
+            current_opening_costs: Vec::new(),
             accepted_neighbors: Vec::new(),
             max_iterations: MAX,
             initial_temperature: INFINITY,
             cooling_schedule: CoolingSchedule::Exponential(0.0),
             total_time: TimeDeltaSave::new_empty(),
+            cache_hits: 0,
+            cache_misses: 0,
+            cancelled: false,
+            pareto_archive: ParetoArchive::new(),
         }
     }
 
@@ -79,11 +94,16 @@ impl SimulatedAnnealingLogger {
             current_costs: Vec::new(),
             current_best_costs: Vec::new(),
             current_amount_nodes: Vec::new(),
+            current_opening_costs: Vec::new(),
             accepted_neighbors: Vec::new(),
             max_iterations: MAX,
             initial_temperature: INFINITY,
             cooling_schedule: CoolingSchedule::Exponential(0.0),
             total_time: TimeDeltaSave::new_empty(),
+            cache_hits: 0,
+            cache_misses: 0,
+            cancelled: false,
+            pareto_archive: ParetoArchive::new(),
         }
     }
 
@@ -105,11 +125,16 @@ impl SimulatedAnnealingLogger {
             current_costs: Vec::new(),
             current_best_costs: Vec::new(),
             current_amount_nodes: Vec::new(),
+            current_opening_costs: Vec::new(),
             accepted_neighbors: Vec::new(),
             max_iterations,
             initial_temperature,
             cooling_schedule,
             total_time: TimeDeltaSave::new_empty(),
+            cache_hits: 0,
+            cache_misses: 0,
+            cancelled: false,
+            pareto_archive: ParetoArchive::new(),
         }
     }
 
@@ -119,18 +144,37 @@ impl SimulatedAnnealingLogger {
         current_cost: f64,
         current_best_cost: f64,
         current_amount_nodes: usize,
+        current_opening_cost: f64,
     ) {
         self.times_needed
             .push(TimeDeltaSave::from_time_delta(&time_needed));
         self.current_costs.push(current_cost);
         self.current_best_costs.push(current_best_cost);
         self.current_amount_nodes.push(current_amount_nodes);
+        self.current_opening_costs.push(current_opening_cost);
     }
 
     pub fn log_accepted_neighbor(&mut self, neighbor: NeighborChange) {
         self.accepted_neighbors.push(neighbor);
     }
 
+    pub fn log_cache_statistics(&mut self, cache_hits: usize, cache_misses: usize) {
+        self.cache_hits = cache_hits;
+        self.cache_misses = cache_misses;
+    }
+
+    pub fn get_cache_hit_rate(&self) -> f64 {
+        if self.cache_hits + self.cache_misses == 0 {
+            return 0.0;
+        }
+        return self.cache_hits as f64 / (self.cache_hits + self.cache_misses) as f64;
+    }
+
+    pub fn get_solution_history(&self) -> SolutionHistory<'_> {
+        // the logged initial solution is the solution the annealing started from
+        return SolutionHistory::new(&self.initial_solution, &self.accepted_neighbors);
+    }
+
     pub fn set_final_solution(
         &mut self,
         final_solution: &GraphEmbedding,
@@ -1,13 +1,19 @@
+mod alpha_sweep;
 mod change_probability;
 mod cooling;
 mod instance;
+mod layer_count_search;
 mod logging;
 mod neighbor_selection;
 mod options;
+mod progress;
 
+pub use alpha_sweep::*;
 pub use change_probability::*;
 pub use cooling::*;
 pub use instance::*;
+pub use layer_count_search::*;
 pub use logging::*;
 pub use neighbor_selection::*;
 pub use options::*;
+pub use progress::*;
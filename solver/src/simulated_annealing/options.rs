@@ -1,11 +1,21 @@
 use crate::{CoolingSchedule, EmbeddingOptions, SearchDepth};
 
+pub const DEFAULT_TOPOLOGY_CACHE_SIZE: usize = 1000;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NeighborSearchOption {
     CompleteEmbedding,
     CompleteHeuristical,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MultiObjectiveOption {
+    #[default]
+    Disabled,
+    InnerVertices, // archive the trade off between the costs and the number of inner vertices
+    InnerVerticesAndPathLength, // additionally archive the longest path from a source to a drain
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InitialSolutionFunction {
     Random,
@@ -42,6 +52,8 @@ pub struct OptimizationOptions {
     pub neighbor_test_options: EmbeddingOptions,
     pub neighbor_cost_options: EmbeddingOptions,
     pub final_cost_options: EmbeddingOptions,
+    pub topology_cache_size: usize, // maximal number of cached topology costs, 0 disables the cache
+    pub multi_objective_option: MultiObjectiveOption,
 }
 
 impl OptimizationOptions {
@@ -66,6 +78,8 @@ impl OptimizationOptions {
             neighbor_test_options,
             neighbor_cost_options,
             final_cost_options,
+            topology_cache_size: DEFAULT_TOPOLOGY_CACHE_SIZE,
+            multi_objective_option: MultiObjectiveOption::Disabled,
         }
     }
 
@@ -98,6 +112,8 @@ impl OptimizationOptions {
                 false,
                 false,
             ),
+            topology_cache_size: DEFAULT_TOPOLOGY_CACHE_SIZE,
+            multi_objective_option: MultiObjectiveOption::Disabled,
         }
     }
 }
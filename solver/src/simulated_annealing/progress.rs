@@ -0,0 +1,54 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use chrono::TimeDelta;
+
+use crate::Neighbor;
+
+#[derive(Debug, Clone)]
+pub struct IterationProgress {
+    pub iteration: usize,
+    pub max_iterations: usize,
+    pub temperature: f64,
+    pub current_cost: f64,
+    pub best_cost: f64,
+    pub proposed_neighbor: Neighbor,
+    pub accepted: bool,
+    pub time_needed: TimeDelta,
+    pub total_time: TimeDelta,
+}
+
+pub trait SolveObserver {
+    // called after every iteration of the annealing in which a neighbor was proposed
+    fn on_iteration(&mut self, progress: &IterationProgress);
+}
+
+impl<F: FnMut(&IterationProgress)> SolveObserver for F {
+    fn on_iteration(&mut self, progress: &IterationProgress) {
+        self(progress);
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        // all clones of the token share the same state, so a run can be stopped from another thread
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
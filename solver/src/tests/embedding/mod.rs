@@ -1,7 +1,13 @@
 mod embedding_test_graphs;
+mod test_coordinate_system;
+mod test_distance_metric;
+mod test_edge_cost;
 mod test_graph_embedding;
+mod test_layer_costs;
+mod test_max_path_length;
+mod test_opening_costs;
 
 pub use embedding_test_graphs::*;
 
 #[cfg(test)]
-pub use test_graph_embedding::compare_with_generalized_weiszfeld;
\ No newline at end of file
+pub use test_graph_embedding::compare_with_generalized_weiszfeld;
@@ -0,0 +1,151 @@
+#![cfg(test)]
+use std::{
+    env::temp_dir,
+    fs::{read_to_string, remove_file},
+};
+
+use rand::Rng;
+use serde_json::Value;
+
+use crate::{
+    haversine_distance, tests::EPSILON, CoordinateSystem, ExportFormat, FlamecastInstance,
+    FlamecastInstanceFile, GeographicProjection, InitialSolutionFunction, PlotFormat,
+    PlottingOptions, VertexEmbeddings,
+};
+
+#[cfg(test)]
+const BERLIN: (f64, f64) = (13.405, 52.52);
+
+#[cfg(test)]
+const PARIS: (f64, f64) = (2.3522, 48.8566);
+
+#[cfg(test)]
+fn create_geographic_instance() -> (FlamecastInstance, VertexEmbeddings) {
+    // random points in a region of roughly 70 km around berlin
+    let mut rng = rand::thread_rng();
+    let mut random_point = || {
+        (
+            BERLIN.0 + rng.gen_range(-0.5..0.5),
+            BERLIN.1 + rng.gen_range(-0.3..0.3),
+        )
+    };
+    let sources = (0..12).map(|_| random_point()).collect();
+    let drains = (0..2).map(|_| random_point()).collect();
+    let coordinates = VertexEmbeddings::from(vec![sources, vec![], vec![], drains]);
+
+    let instance = FlamecastInstance::new_geographic(
+        0.5,
+        4,
+        vec![1, 4, 12, 12],
+        coordinates.clone(),
+        InitialSolutionFunction::Random,
+    );
+    return (instance, coordinates);
+}
+
+#[test]
+fn test_haversine_distance() {
+    // the great circle distance between berlin and paris is about 878 km
+    let distance = haversine_distance(&BERLIN, &PARIS);
+    assert!((distance - 878.0).abs() < 5.0);
+    assert_eq!(haversine_distance(&BERLIN, &BERLIN), 0.0);
+    assert!((haversine_distance(&PARIS, &BERLIN) - distance).abs() < EPSILON);
+}
+
+#[test]
+fn test_projection() {
+    let projection = GeographicProjection::new(BERLIN);
+    assert_eq!(projection.project(&BERLIN), (0.0, 0.0));
+
+    let point = (13.9, 52.3);
+    let projected = projection.project(&point);
+    let unprojected = projection.unproject(&projected);
+    assert!((unprojected.0 - point.0).abs() < EPSILON);
+    assert!((unprojected.1 - point.1).abs() < EPSILON);
+
+    // on a local scale the projected distances are close to the great circle distances
+    let projected_distance = CoordinateSystem::Euclidean.get_distance(&(0.0, 0.0), &projected);
+    let geographic_distance =
+        CoordinateSystem::Geographic(projection).get_distance(&(0.0, 0.0), &projected);
+    assert!((geographic_distance - haversine_distance(&BERLIN, &point)).abs() < EPSILON);
+    assert!((projected_distance - geographic_distance).abs() / geographic_distance < 0.01);
+
+    let embeddings = VertexEmbeddings::from(vec![vec![(10.0, 50.0), (12.0, 54.0)], vec![]]);
+    let projection = GeographicProjection::from_embeddings(&embeddings);
+    assert_eq!(projection.reference, (11.0, 52.0));
+}
+
+#[test]
+fn test_geographic_instance() {
+    let (instance, coordinates) = create_geographic_instance();
+    assert!(instance.coordinate_system.is_geographic());
+
+    // the costs are reported in kilometers and match the costs on the projection closely
+    let objective = instance.get_objective_function_value();
+    let projected_objective = instance
+        .solution_state
+        .current_solution
+        .calculate_costs(instance.alpha);
+    assert!(objective > 10.0);
+    assert!((objective - projected_objective).abs() / objective < 0.01);
+
+    let geographic_solution = instance.get_current_solution_in_geographic_coordinates();
+    for layer_index in [0, 3] {
+        let expected = &coordinates.embeddings[layer_index];
+        let actual = &geographic_solution.vertices_embeddings.embeddings[layer_index];
+        for (expected, actual) in expected.iter().zip(actual) {
+            assert!((expected.0 - actual.0).abs() < EPSILON);
+            assert!((expected.1 - actual.1).abs() < EPSILON);
+        }
+    }
+
+    // the coordinate system is kept when the instance is stored
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    let loaded_reference = loaded.coordinate_system.get_projection().unwrap().reference;
+    let reference = instance
+        .coordinate_system
+        .get_projection()
+        .unwrap()
+        .reference;
+    assert!((loaded_reference.0 - reference.0).abs() < EPSILON);
+    assert!((loaded_reference.1 - reference.1).abs() < EPSILON);
+}
+
+#[test]
+fn test_geographic_export() {
+    let (instance, coordinates) = create_geographic_instance();
+    let file_path = temp_dir().join(format!("test_geographic_{}.geojson", rand::random::<u64>()));
+    let file_path = file_path.to_str().unwrap();
+    instance
+        .export_current_solution(file_path, ExportFormat::GeoJson)
+        .unwrap();
+
+    let geojson: Value = serde_json::from_str(&read_to_string(file_path).unwrap()).unwrap();
+    remove_file(file_path).unwrap();
+
+    // geojson is written in longitude and latitude with the costs in kilometers
+    let cost = geojson["properties"]["cost"].as_f64().unwrap();
+    assert!((cost - instance.get_objective_function_value()).abs() < EPSILON);
+    let first_source = &geojson["features"][0]["geometry"]["coordinates"];
+    assert!((first_source[0].as_f64().unwrap() - coordinates.embeddings[0][0].0).abs() < EPSILON);
+    assert!((first_source[1].as_f64().unwrap() - coordinates.embeddings[0][0].1).abs() < EPSILON);
+}
+
+#[test]
+fn test_geographic_plot() {
+    let (instance, _) = create_geographic_instance();
+    let mut options = PlottingOptions::default();
+    options.format = PlotFormat::Svg;
+    options.show_scale_bar = true;
+
+    let file_path = temp_dir().join(format!("test_geographic_{}.svg", rand::random::<u64>()));
+    let file_path = file_path.to_str().unwrap();
+    instance.plot_current_solution_with_options(file_path, &options);
+
+    let content = read_to_string(file_path).unwrap();
+    remove_file(file_path).unwrap();
+    assert!(content.contains("Longitude"));
+    assert!(content.contains("Latitude"));
+    assert!(content.contains(" km"));
+}
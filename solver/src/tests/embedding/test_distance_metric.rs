@@ -0,0 +1,192 @@
+#![cfg(test)]
+use crate::{
+    graph_embedding::embed_directed_graph,
+    tests::{create_random_flamecast_instance, EPSILON},
+    CoordinateSystem, CostModel, DistanceMetric, EdgeCostFunction, EmbeddingOptions,
+    FlamecastInstanceFile, GraphEmbedding, InitialSolutionFunction, LayeredGraph, NeighborLoader,
+    NeighborSearchOption, OptimizationOptions, SearchDepth, Vertex, VertexEmbeddings,
+};
+
+#[cfg(test)]
+const STAR_SOURCES: [(f64, f64); 4] = [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0)];
+
+#[cfg(test)]
+const STAR_DRAIN: (f64, f64) = (0.5, 3.0);
+
+#[cfg(test)]
+fn embed_star(metric: &DistanceMetric) -> GraphEmbedding {
+    // all sources are connected to one inner vertex, with alpha = 0 every edge has the weight 1
+    let mut graph = LayeredGraph::new_with_size(3);
+    STAR_SOURCES.iter().for_each(|_| {
+        graph.layers[0].add_vertex(Vertex::new(Some(0), None));
+    });
+    graph.layers[1].add_vertex(Vertex::new(
+        Some(0),
+        Some((0..STAR_SOURCES.len()).collect()),
+    ));
+    graph.layers[2].add_vertex(Vertex::new(None, Some(vec![0])));
+
+    let sources_drains_embeddings =
+        VertexEmbeddings::from(vec![STAR_SOURCES.to_vec(), vec![], vec![STAR_DRAIN]]);
+    let embeddings = embed_directed_graph(
+        &graph,
+        &sources_drains_embeddings,
+        &graph.calculate_edge_flows(),
+        &CostModel::new(
+            0.0,
+            *metric,
+            CoordinateSystem::Euclidean,
+            EdgeCostFunction::Power,
+        ),
+        &EmbeddingOptions::from_depth(SearchDepth::Deep),
+    );
+    return GraphEmbedding::new(graph, embeddings);
+}
+
+#[cfg(test)]
+fn calculate_star_costs(position: (f64, f64), metric: &DistanceMetric) -> f64 {
+    return STAR_SOURCES
+        .iter()
+        .chain([STAR_DRAIN].iter())
+        .map(|point| metric.get_distance(&position, point))
+        .sum();
+}
+
+#[test]
+fn test_distance_metric_norms() {
+    assert_eq!(DistanceMetric::from_p(1.0), Ok(DistanceMetric::Manhattan));
+    assert_eq!(DistanceMetric::from_p(2.0), Ok(DistanceMetric::Euclidean));
+    assert_eq!(DistanceMetric::from_p(3.0), Ok(DistanceMetric::Lp(3.0)));
+    for p in [0.5, f64::NAN, f64::INFINITY] {
+        assert!(DistanceMetric::from_p(p).is_err());
+    }
+    assert!(DistanceMetric::Lp(1.0).validate().is_err());
+
+    assert!((DistanceMetric::Euclidean.get_norm(3.0, -4.0) - 5.0).abs() < EPSILON);
+    assert!((DistanceMetric::Manhattan.get_norm(3.0, -4.0) - 7.0).abs() < EPSILON);
+    assert!((DistanceMetric::Lp(3.0).get_norm(3.0, -4.0) - 91.0_f64.cbrt()).abs() < EPSILON);
+
+    // the p-norms are ordered, a higher p never gives a longer distance
+    let distances = [1.0, 1.5, 2.0, 3.0, 10.0]
+        .iter()
+        .map(|p| DistanceMetric::from_p(*p).unwrap().get_norm(0.3, 0.7))
+        .collect::<Vec<f64>>();
+    assert!(distances.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn test_manhattan_embedding() {
+    // the optimal position is the coordinate wise median of all neighbors
+    let metric = DistanceMetric::Manhattan;
+    let solution = embed_star(&metric);
+    let position = solution.vertices_embeddings.embeddings[1][0];
+    assert!((position.0 - 1.0).abs() < 1e-4);
+    assert!((position.1 - 2.0).abs() < 1e-4);
+
+    let mut cost_model = CostModel::from_alpha(0.0);
+    cost_model.metric = metric;
+    let costs = solution.calculate_costs_with_cost_model(&cost_model);
+    assert!((costs - 9.5).abs() < 1e-4);
+    assert!((costs - calculate_star_costs(position, &metric)).abs() < EPSILON);
+}
+
+#[test]
+fn test_lp_embedding() {
+    let metric = DistanceMetric::Lp(3.0);
+    let solution = embed_star(&metric);
+    let position = solution.vertices_embeddings.embeddings[1][0];
+    let costs = calculate_star_costs(position, &metric);
+
+    // compare with the best position of a grid search
+    let grid_size = 300;
+    let mut best_grid_costs = f64::INFINITY;
+    for x in 0..=grid_size {
+        for y in 0..=grid_size {
+            let grid_position = (
+                3.0 * x as f64 / grid_size as f64,
+                3.0 * y as f64 / grid_size as f64,
+            );
+            best_grid_costs = best_grid_costs.min(calculate_star_costs(grid_position, &metric));
+        }
+    }
+    assert!(costs <= best_grid_costs + 1e-4);
+    assert!(costs >= best_grid_costs - 0.05);
+}
+
+#[test]
+fn test_instance_with_metric() {
+    for metric in [DistanceMetric::Manhattan, DistanceMetric::Lp(3.0)] {
+        let mut instance =
+            create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+        let euclidean_solution = instance.solution_state.current_solution.clone();
+
+        instance
+            .set_distance_metric(metric, &EmbeddingOptions::from_depth(SearchDepth::Deep))
+            .unwrap();
+        assert_eq!(instance.metric, metric);
+        assert_eq!(
+            instance.solution_state.initial_solution.base_graph,
+            euclidean_solution.base_graph
+        );
+
+        // the embedding minimizes the costs in the metric of the instance
+        let costs = instance.get_objective_function_value();
+        let euclidean_costs =
+            euclidean_solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+        assert!(costs <= euclidean_costs + 1e-6 * euclidean_costs);
+
+        // the metric is kept when the instance is stored
+        let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+        let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+        assert_eq!(loaded.metric, metric);
+
+        // p-norms the embedding cannot model are rejected
+        let mut invalid_instance_file = instance_file.clone();
+        invalid_instance_file.metric = DistanceMetric::Lp(0.5);
+        assert!(FlamecastInstanceFile::from_json(&invalid_instance_file.to_json(), None).is_err());
+        assert!(instance
+            .set_distance_metric(DistanceMetric::Lp(0.5), &EmbeddingOptions::default())
+            .is_err());
+        assert_eq!(instance.metric, metric);
+
+        let mut options = OptimizationOptions::default();
+        options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+        options.max_iterations = 10;
+        options.verbose = false;
+        instance.solve(options);
+        assert!(instance.get_objective_function_value() <= costs + EPSILON);
+    }
+}
+
+#[test]
+fn test_heuristical_costs_with_metric() {
+    let mut instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+    instance
+        .set_distance_metric(DistanceMetric::Manhattan, &EmbeddingOptions::default())
+        .unwrap();
+
+    let current_costs = instance.get_objective_function_value();
+    let current_vertex_flows = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_vertex_flows();
+    let vertices = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .get_sorted_random_vertices(10);
+    let recablings =
+        instance.get_heuristical_recablings(&vertices, current_costs, &current_vertex_flows);
+
+    // a recabling keeps all positions, so the estimate is the exact cost in the metric of the instance
+    let mut solution = instance.solution_state.current_solution.clone();
+    let mut neighbor_loader = NeighborLoader::new();
+    for recabling in recablings.iter() {
+        neighbor_loader.load_neighbor(&mut solution.base_graph, &recabling.neighbor);
+        let costs = solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+        assert!((costs - recabling.cost).abs() < EPSILON * costs.max(1.0));
+        neighbor_loader.unload_neighbor(&mut solution.base_graph, &recabling.neighbor);
+    }
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    EdgeCost, EdgeCostFunction, EmbeddingOptions, FixedVariableEdgeCost, FlamecastInstanceFile,
+    InitialSolutionFunction, LogarithmicEdgeCost, NeighborLoader, NeighborSearchOption,
+    OptimizationOptions, PiecewiseLinearEdgeCost, PowerEdgeCost, SearchDepth,
+};
+
+#[cfg(test)]
+fn get_edge_cost_functions() -> Vec<EdgeCostFunction> {
+    return vec![
+        EdgeCostFunction::PiecewiseLinear(
+            PiecewiseLinearEdgeCost::from_cable_catalogue(&vec![
+                (8.0, 3.0),
+                (2.0, 1.0),
+                (32.0, 6.0),
+            ])
+            .unwrap(),
+        ),
+        EdgeCostFunction::FixedVariable(FixedVariableEdgeCost::new(2.0, 0.25)),
+        EdgeCostFunction::Logarithmic(LogarithmicEdgeCost::new(1.5)),
+    ];
+}
+
+#[test]
+fn test_edge_cost_function_values() {
+    assert!((PowerEdgeCost.get_flow_costs(4.0, 0.5) - 2.0).abs() < EPSILON);
+    assert!((EdgeCostFunction::Power.get_flow_costs(8.0, 1.0 / 3.0) - 2.0).abs() < EPSILON);
+
+    let fixed_variable = FixedVariableEdgeCost::new(2.0, 0.25);
+    assert_eq!(fixed_variable.get_flow_costs(0.0, 0.5), 0.0);
+    assert!((fixed_variable.get_flow_costs(4.0, 0.5) - 3.0).abs() < EPSILON);
+
+    let logarithmic = LogarithmicEdgeCost::new(2.0);
+    assert_eq!(logarithmic.get_flow_costs(0.0, 0.5), 0.0);
+    assert!((logarithmic.get_flow_costs(3.0, 0.5) - 2.0 * 4.0_f64.ln()).abs() < EPSILON);
+
+    // the catalogue is sorted by capacity, costs in between are interpolated
+    let piecewise_linear =
+        PiecewiseLinearEdgeCost::from_cable_catalogue(&vec![(8.0, 3.0), (2.0, 1.0)]).unwrap();
+    assert_eq!(piecewise_linear.breakpoints, vec![(2.0, 1.0), (8.0, 3.0)]);
+    assert!((piecewise_linear.get_flow_costs(1.0, 0.5) - 0.5).abs() < EPSILON);
+    assert!((piecewise_linear.get_flow_costs(5.0, 0.5) - 2.0).abs() < EPSILON);
+    assert!((piecewise_linear.get_flow_costs(11.0, 0.5) - 4.0).abs() < EPSILON);
+
+    // all edge cost functions model economies of scale
+    for edge_cost_function in get_edge_cost_functions() {
+        assert!(edge_cost_function.validate().is_ok());
+        let increments = (1..20)
+            .map(|flow| {
+                edge_cost_function.get_flow_costs(flow as f64 + 1.0, 0.0)
+                    - edge_cost_function.get_flow_costs(flow as f64, 0.0)
+            })
+            .collect::<Vec<f64>>();
+        assert!(increments.iter().all(|increment| *increment >= 0.0));
+        assert!(increments
+            .windows(2)
+            .all(|pair| pair[1] <= pair[0] + EPSILON));
+    }
+}
+
+#[test]
+fn test_edge_cost_function_validation() {
+    let not_concave = EdgeCostFunction::PiecewiseLinear(PiecewiseLinearEdgeCost {
+        breakpoints: vec![(1.0, 1.0), (2.0, 3.0)],
+    });
+    assert!(not_concave.validate().is_err());
+
+    let not_sorted = EdgeCostFunction::PiecewiseLinear(PiecewiseLinearEdgeCost {
+        breakpoints: vec![(2.0, 1.0), (1.0, 1.0)],
+    });
+    assert!(not_sorted.validate().is_err());
+
+    let negative = EdgeCostFunction::FixedVariable(FixedVariableEdgeCost::new(-1.0, 1.0));
+    assert!(negative.validate().is_err());
+
+    // invalid edge cost functions are rejected when an instance file is loaded
+    let instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+    let mut instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    instance_file.edge_cost_function = not_concave;
+    assert!(FlamecastInstanceFile::from_json(&instance_file.to_json(), None).is_err());
+}
+
+#[test]
+fn test_piecewise_linear_edge_cost_invalid_breakpoints() {
+    assert!(PiecewiseLinearEdgeCost::new(vec![(1.0, 1.0), (2.0, 3.0)]).is_err());
+    assert!(PiecewiseLinearEdgeCost::new(vec![(2.0, 1.0), (1.0, 0.5)]).is_err());
+    assert!(PiecewiseLinearEdgeCost::new(Vec::new()).is_err());
+    assert!(PiecewiseLinearEdgeCost::new(vec![(1.0, 1.0), (2.0, 1.5)]).is_ok());
+}
+
+#[test]
+fn test_instance_with_edge_cost_function() {
+    for edge_cost_function in get_edge_cost_functions() {
+        let mut instance =
+            create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+        let power_solution = instance.solution_state.current_solution.clone();
+
+        instance.set_edge_cost_function(
+            edge_cost_function.clone(),
+            &EmbeddingOptions::from_depth(SearchDepth::Deep),
+        );
+        assert_eq!(instance.edge_cost_function, edge_cost_function);
+        assert_eq!(
+            instance.solution_state.initial_solution.base_graph,
+            power_solution.base_graph
+        );
+
+        // the embedding minimizes the costs with the edge cost function of the instance
+        let costs = instance.get_objective_function_value();
+        let power_costs =
+            power_solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+        assert!(costs <= power_costs + 1e-6 * power_costs);
+
+        // the edge cost function is kept when the instance is stored
+        let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+        let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+        assert_eq!(loaded.edge_cost_function, edge_cost_function);
+
+        let mut options = OptimizationOptions::default();
+        options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+        options.max_iterations = 10;
+        options.verbose = false;
+        instance.solve(options);
+        assert!(instance.get_objective_function_value() <= costs + EPSILON);
+    }
+}
+
+#[test]
+fn test_heuristical_costs_with_edge_cost_function() {
+    for edge_cost_function in get_edge_cost_functions() {
+        let mut instance =
+            create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+        instance.set_edge_cost_function(edge_cost_function, &EmbeddingOptions::default());
+
+        let current_costs = instance.get_objective_function_value();
+        let current_vertex_flows = instance
+            .solution_state
+            .current_solution
+            .base_graph
+            .calculate_vertex_flows();
+        let vertices = instance
+            .solution_state
+            .current_solution
+            .base_graph
+            .get_sorted_random_vertices(10);
+        let recablings =
+            instance.get_heuristical_recablings(&vertices, current_costs, &current_vertex_flows);
+
+        // a recabling keeps all positions, so the estimate is the exact cost of the instance
+        let mut solution = instance.solution_state.current_solution.clone();
+        let mut neighbor_loader = NeighborLoader::new();
+        for recabling in recablings.iter() {
+            neighbor_loader.load_neighbor(&mut solution.base_graph, &recabling.neighbor);
+            let costs = solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+            assert!((costs - recabling.cost).abs() < EPSILON * costs.max(1.0));
+            neighbor_loader.unload_neighbor(&mut solution.base_graph, &recabling.neighbor);
+        }
+    }
+}
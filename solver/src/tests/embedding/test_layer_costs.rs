@@ -0,0 +1,164 @@
+#![cfg(test)]
+use crate::{
+    tests::{
+        create_random_flamecast_instance, generate_random_flamecast_instance_with_layer_costs,
+        EPSILON,
+    },
+    CostModel, EmbeddingOptions, FlamecastInstance, FlamecastInstanceFile, GraphEmbedding,
+    InitialSolutionFunction, NeighborLoader, NeighborSearchOption, OptimizationOptions,
+    SearchDepth,
+};
+
+#[cfg(test)]
+fn create_random_instance_with_layer_costs() -> FlamecastInstance {
+    let test_instance = generate_random_flamecast_instance_with_layer_costs(5, 16, 2, false);
+    return FlamecastInstanceFile::from_test_instance(&test_instance)
+        .to_flamecast_instance(InitialSolutionFunction::Random)
+        .unwrap();
+}
+
+#[cfg(test)]
+fn calculate_costs_by_layer(solution: &GraphEmbedding, cost_model: &CostModel) -> f64 {
+    let edge_lengths = solution.calculate_edge_lengths();
+    let edge_flows = solution.base_graph.calculate_edge_flows();
+
+    let mut costs = 0.0;
+    for (layer_index, layer_lengths) in edge_lengths.iter().enumerate() {
+        for (vertex_index, edge_length) in layer_lengths.iter().enumerate() {
+            let flow = edge_flows[layer_index][vertex_index] as f64;
+            costs += cost_model.layer_cost_multipliers[layer_index]
+                * edge_length
+                * flow.powf(cost_model.layer_alphas[layer_index]);
+        }
+    }
+    return costs;
+}
+
+#[test]
+fn test_cost_model_layers() {
+    let cost_model = CostModel::from_alpha(0.5);
+    assert_eq!(cost_model.get_alpha(3), 0.5);
+    assert_eq!(cost_model.get_cost_multiplier(3), 1.0);
+
+    let cost_model = cost_model.with_layer_costs(vec![0.9, 0.6, 0.2], vec![1.0, 2.0, 5.0]);
+    assert_eq!(cost_model.get_alpha(1), 0.6);
+    assert_eq!(cost_model.get_cost_multiplier(2), 5.0);
+    assert!((cost_model.get_flow_weight(1, 4) - 2.0 * 4.0_f64.powf(0.6)).abs() < EPSILON);
+
+    // subgraphs start at an inner layer of the whole graph
+    let local_cost_model = cost_model.with_layer_offset(1);
+    assert_eq!(local_cost_model.layer_alphas, vec![0.6, 0.2]);
+    assert_eq!(local_cost_model.layer_cost_multipliers, vec![2.0, 5.0]);
+    assert_eq!(
+        local_cost_model.get_flow_weight(0, 7),
+        cost_model.get_flow_weight(1, 7)
+    );
+}
+
+#[test]
+fn test_uniform_layer_costs() {
+    // the same alpha in every layer without multipliers is the single alpha model
+    let mut instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Matching);
+    instance
+        .set_layer_costs(
+            vec![instance.alpha; 3],
+            vec![1.0; 3],
+            &EmbeddingOptions::default(),
+        )
+        .unwrap();
+
+    let costs = instance
+        .solution_state
+        .current_solution
+        .calculate_costs(instance.alpha);
+    assert!((instance.get_objective_function_value() - costs).abs() < EPSILON * costs);
+
+    // invalid layer costs are rejected and keep the current ones
+    let options = EmbeddingOptions::default();
+    assert!(instance
+        .set_layer_costs(vec![0.5; 4], vec![], &options)
+        .is_err());
+    assert!(instance
+        .set_layer_costs(vec![], vec![1.0, -1.0, 1.0], &options)
+        .is_err());
+    assert!(instance
+        .set_layer_costs(vec![0.5, 1.5, 0.5], vec![], &options)
+        .is_err());
+    assert_eq!(instance.layer_alphas, vec![instance.alpha; 3]);
+}
+
+#[test]
+fn test_instance_with_layer_costs() {
+    let mut instance = create_random_instance_with_layer_costs();
+    assert_eq!(instance.layer_alphas.len(), instance.num_layers - 1);
+    assert!(instance
+        .layer_alphas
+        .windows(2)
+        .all(|pair| pair[0] >= pair[1]));
+
+    // the objective uses the alpha and multiplier of the layer of every edge
+    let cost_model = instance.get_cost_model();
+    let costs = instance.get_objective_function_value();
+    let solution = &instance.solution_state.current_solution;
+    assert!((costs - calculate_costs_by_layer(solution, &cost_model)).abs() < EPSILON * costs);
+
+    // the embedding minimizes the layered costs
+    let mut uniform_instance = instance.clone();
+    uniform_instance.layer_alphas = vec![];
+    uniform_instance.layer_cost_multipliers = vec![];
+    uniform_instance.embed_current_solution(&EmbeddingOptions::from_depth(SearchDepth::Deep));
+    let uniform_solution = &uniform_instance.solution_state.current_solution;
+    let uniform_costs = uniform_solution.calculate_costs_with_cost_model(&cost_model);
+    assert!(costs <= uniform_costs + 1e-6 * uniform_costs);
+
+    // the layer costs are kept when the instance is stored
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    assert_eq!(loaded.layer_alphas.len(), instance.layer_alphas.len());
+    assert_eq!(
+        loaded.layer_cost_multipliers.len(),
+        instance.layer_cost_multipliers.len()
+    );
+
+    // wrong numbers of layer values are rejected
+    let mut invalid_file = instance_file.clone();
+    invalid_file.layer_alphas.push(0.5);
+    assert!(FlamecastInstanceFile::from_json(&invalid_file.to_json(), None).is_err());
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 10;
+    options.verbose = false;
+    instance.solve(options);
+    assert!(instance.get_objective_function_value() <= costs + EPSILON);
+}
+
+#[test]
+fn test_heuristical_costs_with_layer_costs() {
+    let instance = create_random_instance_with_layer_costs();
+
+    let current_costs = instance.get_objective_function_value();
+    let current_vertex_flows = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_vertex_flows();
+    let vertices = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .get_sorted_random_vertices(10);
+    let recablings =
+        instance.get_heuristical_recablings(&vertices, current_costs, &current_vertex_flows);
+
+    // a recabling keeps all positions, so the estimate is the exact layered cost
+    let mut solution = instance.solution_state.current_solution.clone();
+    let mut neighbor_loader = NeighborLoader::new();
+    for recabling in recablings.iter() {
+        neighbor_loader.load_neighbor(&mut solution.base_graph, &recabling.neighbor);
+        let costs = solution.calculate_costs_with_cost_model(&instance.get_cost_model());
+        assert!((costs - recabling.cost).abs() < EPSILON * costs.max(1.0));
+        neighbor_loader.unload_neighbor(&mut solution.base_graph, &recabling.neighbor);
+    }
+}
@@ -0,0 +1,209 @@
+#![cfg(test)]
+use rand::Rng;
+
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    EmbeddingOptions, FlamecastInstance, FlamecastInstanceFile, FlamecastSolutionFile,
+    InitialSolutionFunction, MaxPathLength, NeighborSearchOption, OptimizationOptions,
+    VertexEmbeddings, MAX_PATH_LENGTH_TOLERANCE,
+};
+
+#[cfg(test)]
+fn get_direct_distances(instance: &FlamecastInstance) -> Vec<f64> {
+    // no path can be shorter than the direct distance from the source to its drain
+    let solution = &instance.solution_state.current_solution;
+    let graph = &solution.base_graph;
+    let drain_layer = instance.num_layers - 1;
+    return (0..instance.get_number_of_sources())
+        .map(|source_index| {
+            let mut vertex_index = source_index;
+            for layer_index in 0..drain_layer {
+                vertex_index = graph.layers[layer_index].vertices[vertex_index]
+                    .parent_index
+                    .unwrap();
+            }
+            instance.get_cost_model().get_projected_distance(
+                &solution.vertices_embeddings.embeddings[0][source_index],
+                &solution.vertices_embeddings.embeddings[drain_layer][vertex_index],
+            )
+        })
+        .collect();
+}
+
+#[test]
+fn test_max_path_length_validation() {
+    assert!(MaxPathLength::Unbounded.validate(3).is_ok());
+    assert!(MaxPathLength::Global(2.0).validate(3).is_ok());
+    assert!(MaxPathLength::Global(0.0).validate(3).is_err());
+    assert!(MaxPathLength::PerSource(vec![Some(1.0), None])
+        .validate(3)
+        .is_err());
+    assert!(MaxPathLength::PerSource(vec![None; 3]).is_unbounded());
+
+    let max_path_length = MaxPathLength::PerSource(vec![Some(1.0), Some(2.0), None]);
+    assert_eq!(
+        max_path_length.get_violated_sources(&vec![1.5, 2.0, 100.0]),
+        vec![0]
+    );
+
+    // invalid bounds are rejected when an instance file is loaded
+    let instance =
+        create_random_flamecast_instance(5, 12, 2, None, InitialSolutionFunction::Matching);
+    let mut instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    instance_file.max_path_length = MaxPathLength::PerSource(vec![Some(1.0)]);
+    assert!(FlamecastInstanceFile::from_json(&instance_file.to_json(), None).is_err());
+}
+
+#[test]
+fn test_embedding_with_max_path_length() {
+    let mut instance =
+        create_random_flamecast_instance(5, 12, 2, None, InitialSolutionFunction::Matching);
+    let unbounded_costs = instance.get_objective_function_value();
+    let unbounded_path_lengths = instance
+        .solution_state
+        .current_solution
+        .calculate_source_path_lengths_with_cost_model(&instance.get_cost_model());
+
+    // a bound between the direct distances and the current path lengths of the sources
+    let direct_distances = get_direct_distances(&instance);
+    let max_path_lengths = unbounded_path_lengths
+        .iter()
+        .zip(direct_distances.iter())
+        .map(|(path_length, direct_distance)| (path_length + 2.0 * direct_distance) / 3.0)
+        .collect::<Vec<f64>>();
+    // the last source stays unbounded
+    let mut bounds = max_path_lengths
+        .iter()
+        .cloned()
+        .map(Some)
+        .collect::<Vec<_>>();
+    *bounds.last_mut().unwrap() = None;
+    instance.set_max_path_length(
+        MaxPathLength::PerSource(bounds.clone()),
+        &EmbeddingOptions::default(),
+    );
+
+    assert!(instance.is_path_length_feasible());
+    let path_lengths = instance
+        .solution_state
+        .current_solution
+        .calculate_source_path_lengths_with_cost_model(&instance.get_cost_model());
+    for (path_length, max_path_length) in path_lengths.iter().zip(bounds.iter().flatten()) {
+        assert!(*path_length <= max_path_length * (1.0 + MAX_PATH_LENGTH_TOLERANCE));
+    }
+
+    // the bounds can only make the embedding more expensive
+    let costs = instance.get_objective_function_value();
+    assert!(costs >= unbounded_costs - EPSILON * unbounded_costs);
+
+    // the bounds are kept when the solution is stored and the stored solution is valid
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    let loaded = FlamecastSolutionFile::from_json(&solution_file.to_json()).unwrap();
+    match loaded.instance.max_path_length {
+        MaxPathLength::PerSource(loaded_bounds) => {
+            assert_eq!(loaded_bounds.len(), bounds.len());
+            assert!(loaded_bounds.last().unwrap().is_none());
+            for (loaded, bound) in loaded_bounds.iter().flatten().zip(bounds.iter().flatten()) {
+                assert!((loaded - bound).abs() < EPSILON);
+            }
+        }
+        _ => panic!("The max path lengths were not stored"),
+    }
+}
+
+#[test]
+fn test_geographic_max_path_length() {
+    // random points in a region of roughly 70 km around berlin
+    let mut rng = rand::thread_rng();
+    let mut random_point = || (rng.gen_range(12.9..13.9), rng.gen_range(52.2..52.8));
+    let sources = (0..12).map(|_| random_point()).collect();
+    let drains = (0..2).map(|_| random_point()).collect();
+    let mut instance = FlamecastInstance::new_geographic(
+        0.5,
+        4,
+        vec![1, 4, 12, 12],
+        VertexEmbeddings::from(vec![sources, vec![], vec![], drains]),
+        InitialSolutionFunction::Matching,
+    );
+
+    // the bound is binding for the longest path, which is measured on the projection
+    let max_path_length = instance
+        .solution_state
+        .current_solution
+        .calculate_projected_source_path_lengths(&instance.get_cost_model())
+        .into_iter()
+        .fold(0.0, f64::max);
+    let max_direct_distance = get_direct_distances(&instance)
+        .into_iter()
+        .fold(0.0, f64::max);
+    instance.set_max_path_length(
+        MaxPathLength::Global((max_path_length + max_direct_distance) / 2.0),
+        &EmbeddingOptions::default(),
+    );
+    assert!(instance.is_path_length_feasible());
+    assert!(instance.get_objective_function_value().is_finite());
+
+    // the bounded embedding is not priced as infeasible and can be stored
+    let mut options = OptimizationOptions::default();
+    options.max_iterations = 5;
+    options.verbose = false;
+    instance.solve(options);
+    assert!(instance.is_path_length_feasible());
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    assert!(solution_file.validate().is_ok());
+    assert!(solution_file
+        .verify(&solution_file.instance, None)
+        .feasibility_errors
+        .is_empty());
+}
+
+#[test]
+fn test_infeasible_max_path_length() {
+    let mut instance =
+        create_random_flamecast_instance(5, 12, 2, None, InitialSolutionFunction::Matching);
+    let max_direct_distance = get_direct_distances(&instance)
+        .into_iter()
+        .fold(0.0, f64::max);
+
+    // the source with the longest direct distance cannot reach its drain in time,
+    // the embedding falls back to the unbounded one and the violation is reported
+    instance.set_max_path_length(
+        MaxPathLength::Global(0.5 * max_direct_distance),
+        &EmbeddingOptions::default(),
+    );
+    assert!(!instance.is_path_length_feasible());
+    assert!(instance.get_objective_function_value().is_finite());
+
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    assert!(solution_file.validate().is_err());
+}
+
+#[test]
+fn test_solve_with_max_path_length() {
+    for neighbor_search_option in [
+        NeighborSearchOption::CompleteHeuristical,
+        NeighborSearchOption::CompleteEmbedding,
+    ] {
+        let mut instance =
+            create_random_flamecast_instance(5, 12, 2, None, InitialSolutionFunction::Matching);
+        let path_lengths = instance
+            .solution_state
+            .current_solution
+            .calculate_source_path_lengths_with_cost_model(&instance.get_cost_model());
+        let max_path_length = path_lengths.into_iter().fold(0.0, f64::max);
+        instance.set_max_path_length(
+            MaxPathLength::Global(max_path_length),
+            &EmbeddingOptions::default(),
+        );
+        assert!(instance.is_path_length_feasible());
+
+        let mut options = OptimizationOptions::default();
+        options.neighbor_search_option = neighbor_search_option;
+        options.max_iterations = 10;
+        options.verbose = false;
+        instance.solve(options);
+
+        // no accepted neighbor violates the bound, so the final solution respects it
+        assert!(instance.is_path_length_feasible());
+    }
+}
@@ -0,0 +1,189 @@
+#![cfg(test)]
+use crate::{
+    neighborhood::NeighborCost,
+    tests::{create_random_flamecast_instance, EPSILON},
+    EmbeddingOptions, FlamecastInstance, FlamecastInstanceFile, InitialSolutionFunction, Neighbor,
+    NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+const OPENING_COSTS: [f64; 5] = [0.0, 0.5, 1.0, 2.0, 0.0];
+
+#[cfg(test)]
+fn get_opening_costs_delta(neighbor: &Neighbor) -> f64 {
+    return match neighbor {
+        Neighbor::Merge(vertex, _) => -OPENING_COSTS[vertex.layer],
+        Neighbor::Split(children) => OPENING_COSTS[children[0].layer + 1],
+        Neighbor::Reroute(vertex, target) => (vertex.layer + 1..target.layer)
+            .map(|layer| OPENING_COSTS[layer])
+            .sum(),
+        _ => 0.0,
+    };
+}
+
+#[test]
+fn test_opening_costs_in_objective() {
+    let mut instance =
+        create_random_flamecast_instance(5, 16, 2, None, InitialSolutionFunction::Random);
+    let edge_costs = instance.get_objective_function_value();
+
+    instance
+        .set_vertex_opening_costs(OPENING_COSTS.to_vec(), &EmbeddingOptions::default())
+        .unwrap();
+
+    // the opening costs do not change the embedding, only the objective
+    let graph = &instance.solution_state.current_solution.base_graph;
+    let expected_opening_costs: f64 = graph
+        .layers
+        .iter()
+        .enumerate()
+        .map(|(layer_index, layer)| layer.vertices.len() as f64 * OPENING_COSTS[layer_index])
+        .sum();
+    assert!((instance.get_opening_costs() - expected_opening_costs).abs() < EPSILON);
+    assert!(
+        (instance.get_objective_function_value() - edge_costs - expected_opening_costs).abs()
+            < EPSILON * edge_costs.max(1.0)
+    );
+
+    // the plain alpha costs stay the pure edge costs
+    let plain_costs = instance
+        .solution_state
+        .current_solution
+        .calculate_costs(instance.alpha);
+    assert!((plain_costs - edge_costs).abs() < EPSILON * edge_costs.max(1.0));
+
+    // the opening costs are kept when the instance is stored
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    assert_eq!(loaded.vertex_opening_costs, OPENING_COSTS.to_vec());
+
+    let mut invalid_file = instance_file.clone();
+    invalid_file.vertex_opening_costs.pop();
+    assert!(FlamecastInstanceFile::from_json(&invalid_file.to_json(), None).is_err());
+
+    // the setter rejects the same opening costs as the instance files
+    for invalid_opening_costs in [vec![0.0; 4], vec![0.0, -1.0, 0.0, 0.0, 0.0]] {
+        assert!(instance
+            .set_vertex_opening_costs(invalid_opening_costs, &EmbeddingOptions::default())
+            .is_err());
+    }
+    assert_eq!(instance.vertex_opening_costs, OPENING_COSTS.to_vec());
+}
+
+#[test]
+fn test_heuristical_opening_costs() {
+    let free_instance =
+        create_random_flamecast_instance(5, 16, 2, None, InitialSolutionFunction::Random);
+    let mut instance = free_instance.clone();
+    instance.vertex_opening_costs = OPENING_COSTS.to_vec();
+
+    let current_costs = free_instance.get_objective_function_value();
+    let current_vertex_flows = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_vertex_flows();
+    let vertices = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .get_sorted_random_vertices(20);
+    let options = EmbeddingOptions::default();
+
+    let get_neighbors = |instance: &FlamecastInstance| -> Vec<NeighborCost> {
+        let mut result = instance.get_heuristical_merges(
+            &vertices,
+            current_costs,
+            &current_vertex_flows,
+            &options,
+        );
+        result.append(&mut instance.get_heuristical_splits(
+            &vertices,
+            current_costs,
+            &current_vertex_flows,
+            &options,
+        ));
+        result.append(&mut instance.get_heuristical_reroutes(
+            &vertices,
+            current_costs,
+            &current_vertex_flows,
+        ));
+        result.append(&mut instance.get_heuristical_recablings(
+            &vertices,
+            current_costs,
+            &current_vertex_flows,
+        ));
+        return result;
+    };
+
+    // merges save and splits and reroutes pay the opening costs of the changed vertices,
+    // the children of a split are clustered randomly, so only equal splits can be compared
+    let free_neighbors = get_neighbors(&free_instance);
+    let neighbors = get_neighbors(&instance);
+    for neighbor in neighbors.iter() {
+        let free_costs = match &neighbor.neighbor {
+            // the best reroute of a drain can change with the opening costs
+            Neighbor::Reroute(vertex, target) => free_instance.estimate_reroute_costs(
+                vertex,
+                target,
+                &current_vertex_flows,
+                current_costs,
+                &free_instance.get_cost_model(),
+            ),
+            _ => match free_neighbors
+                .iter()
+                .find(|free_neighbor| free_neighbor.neighbor == neighbor.neighbor)
+            {
+                Some(free_neighbor) => free_neighbor.cost,
+                None if matches!(neighbor.neighbor, Neighbor::Split(_)) => continue,
+                None => panic!("The neighbor {:?} is missing", neighbor.neighbor),
+            },
+        };
+        let delta = neighbor.cost - free_costs;
+        let expected_delta = get_opening_costs_delta(&neighbor.neighbor);
+        assert!((delta - expected_delta).abs() < 1e-6 * current_costs.max(1.0));
+    }
+}
+
+#[test]
+fn test_solve_with_opening_costs() {
+    let mut instance =
+        create_random_flamecast_instance(5, 16, 2, None, InitialSolutionFunction::Random);
+    // opening costs that dominate the edge costs, no solution with more vertices can be better
+    let opening_costs = vec![0.0, 100.0, 100.0, 100.0, 0.0];
+    instance
+        .set_vertex_opening_costs(opening_costs, &EmbeddingOptions::default())
+        .unwrap();
+    let initial_number_of_vertices = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .get_number_of_vertices();
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 20;
+    options.verbose = false;
+    instance.solve(options);
+
+    // the logger traces the opening costs of the current solution in every iteration
+    let logger = &instance.logger;
+    assert_eq!(
+        logger.current_opening_costs.len(),
+        logger.current_costs.len()
+    );
+    logger
+        .current_opening_costs
+        .iter()
+        .zip(logger.current_costs.iter())
+        .for_each(|(opening_costs, costs)| assert!(*opening_costs <= *costs + EPSILON));
+
+    assert!(
+        instance
+            .solution_state
+            .current_solution
+            .base_graph
+            .get_number_of_vertices()
+            <= initial_number_of_vertices
+    );
+}
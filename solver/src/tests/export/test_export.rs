@@ -0,0 +1,142 @@
+#![cfg(test)]
+use std::{env::temp_dir, fs::read_to_string, fs::remove_file};
+
+use serde_json::Value;
+
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    ExportFormat, GraphEmbedding, InitialSolutionFunction,
+};
+
+#[cfg(test)]
+fn create_random_solution() -> (GraphEmbedding, f64) {
+    let flamecast_instance =
+        create_random_flamecast_instance(5, 20, 3, None, InitialSolutionFunction::Random);
+    return (
+        flamecast_instance.solution_state.current_solution,
+        flamecast_instance.alpha,
+    );
+}
+
+#[cfg(test)]
+fn count_vertices_edges(solution: &GraphEmbedding) -> (usize, usize) {
+    let layers = &solution.base_graph.layers;
+    let num_vertices = layers.iter().map(|layer| layer.vertices.len()).sum();
+    let num_edges = num_vertices - layers[layers.len() - 1].vertices.len();
+    return (num_vertices, num_edges);
+}
+
+#[test]
+fn test_edge_costs() {
+    let (solution, alpha) = create_random_solution();
+
+    let edge_costs = solution.calculate_edge_costs(alpha);
+    let edge_lengths = solution.calculate_edge_lengths();
+    let edge_flows = solution.base_graph.calculate_edge_flows();
+    for (layer_index, layer_costs) in edge_costs.iter().enumerate() {
+        for (vertex_index, cost) in layer_costs.iter().enumerate() {
+            let expected = edge_lengths[layer_index][vertex_index]
+                * (edge_flows[layer_index][vertex_index] as f64).powf(alpha);
+            assert!((cost - expected).abs() < EPSILON);
+        }
+    }
+
+    let total_cost: f64 = edge_costs.iter().flat_map(|layer| layer.iter()).sum();
+    assert!((total_cost - solution.calculate_costs(alpha)).abs() < EPSILON);
+}
+
+#[test]
+fn test_export_geojson() {
+    let (solution, alpha) = create_random_solution();
+    let (num_vertices, num_edges) = count_vertices_edges(&solution);
+
+    let geojson = serde_json::from_str::<Value>(&solution.to_geojson(alpha)).unwrap();
+    assert_eq!(geojson["type"], "FeatureCollection");
+
+    let features = geojson["features"].as_array().unwrap();
+    let points = features
+        .iter()
+        .filter(|feature| feature["geometry"]["type"] == "Point")
+        .count();
+    let lines = features
+        .iter()
+        .filter(|feature| feature["geometry"]["type"] == "LineString")
+        .collect::<Vec<_>>();
+    assert_eq!(points, num_vertices);
+    assert_eq!(lines.len(), num_edges);
+
+    let total_cost: f64 = lines
+        .iter()
+        .map(|line| line["properties"]["cost"].as_f64().unwrap())
+        .sum();
+    assert!((total_cost - solution.calculate_costs(alpha)).abs() < EPSILON);
+
+    // all sources send one unit of flow, so the drains receive all of it
+    let drain_flow: u64 = features
+        .iter()
+        .filter(|feature| feature["properties"]["type"] == "drain")
+        .map(|feature| feature["properties"]["flow"].as_u64().unwrap())
+        .sum();
+    assert_eq!(
+        drain_flow as usize,
+        solution.base_graph.layers[0].vertices.len()
+    );
+}
+
+#[test]
+fn test_export_dot() {
+    let (solution, alpha) = create_random_solution();
+    let (num_vertices, num_edges) = count_vertices_edges(&solution);
+
+    let dot = solution.to_dot(alpha);
+    assert!(dot.starts_with("digraph flamecast {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert_eq!(
+        dot.lines().filter(|line| line.contains("->")).count(),
+        num_edges
+    );
+    assert_eq!(
+        dot.lines().filter(|line| line.contains("pos=")).count(),
+        num_vertices
+    );
+}
+
+#[test]
+fn test_export_graphml() {
+    let (solution, alpha) = create_random_solution();
+    let (num_vertices, num_edges) = count_vertices_edges(&solution);
+
+    let graphml = solution.to_graphml(alpha);
+    assert_eq!(graphml.matches("<node ").count(), num_vertices);
+    assert_eq!(graphml.matches("<edge ").count(), num_edges);
+    assert_eq!(graphml.matches("</node>").count(), num_vertices);
+    assert_eq!(graphml.matches("</edge>").count(), num_edges);
+}
+
+#[test]
+fn test_export_to_file() {
+    let (solution, alpha) = create_random_solution();
+
+    for extension in ["graphml", "dot", "geojson"] {
+        let file_path = temp_dir().join(format!(
+            "test_export_{}.{}",
+            rand::random::<u64>(),
+            extension
+        ));
+        let file_path = file_path.to_str().unwrap();
+        let format = ExportFormat::from_file_path(file_path).unwrap();
+
+        solution.export(file_path, format, alpha).unwrap();
+        let content = read_to_string(file_path).unwrap();
+        let expected = match format {
+            ExportFormat::GraphML => solution.to_graphml(alpha),
+            ExportFormat::Dot => solution.to_dot(alpha),
+            ExportFormat::GeoJson => solution.to_geojson(alpha),
+        };
+        assert_eq!(content, expected);
+
+        remove_file(file_path).unwrap();
+    }
+
+    assert!(ExportFormat::from_file_path("solution.json").is_none());
+}
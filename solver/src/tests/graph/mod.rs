@@ -1 +1,5 @@
 mod test_random_vertices;
+mod test_robustness;
+mod test_solution_statistics;
+mod test_topology_distance;
+mod test_topology_hash;
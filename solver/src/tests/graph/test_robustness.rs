@@ -0,0 +1,150 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    CostModel, EmbeddingOptions, FlamecastInstanceFile, GraphEmbedding, InitialSolutionFunction,
+    Layer, LayeredGraph, MaxChildren, RobustnessReport, Vertex, VertexEmbeddings, VertexID,
+};
+
+#[test]
+fn test_robustness_report() {
+    let instance = create_random_flamecast_instance(
+        4,
+        12,
+        2,
+        Some(vec![1, 12, 12, 12]),
+        InitialSolutionFunction::Matching,
+    );
+    let solution = &instance.solution_state.current_solution;
+    let report = instance.analyze_robustness();
+    let vertex_flows = solution.base_graph.calculate_vertex_flows();
+
+    assert_eq!(
+        report.vertex_failures.len(),
+        solution.base_graph.get_number_of_inner_vertices()
+    );
+    for failure in report.vertex_failures.iter() {
+        assert_eq!(
+            failure.disconnected_sources,
+            vertex_flows[failure.vertex.layer][failure.vertex.index]
+        );
+
+        // the repaired solution serves every source without the failed vertex
+        let repaired = solution.repair_vertex_failure(
+            &failure.vertex,
+            &instance.capacities,
+            &instance.max_children,
+            &instance.get_cost_model(),
+        );
+        assert_eq!(repaired.is_some(), failure.repair_costs.is_some());
+        if let Some(repaired) = repaired {
+            assert!(repaired.base_graph.is_valid_flamecast_topology_check_all(
+                &instance.capacities,
+                instance.get_number_of_sources(),
+                instance.get_number_of_drains(),
+                instance.num_layers,
+            ));
+            // parents without other children are removed with the failed vertex
+            assert!(
+                repaired.base_graph.get_number_of_vertices()
+                    < solution.base_graph.get_number_of_vertices()
+            );
+            let repaired_costs =
+                repaired.calculate_costs_with_cost_model(&instance.get_cost_model());
+            assert!(
+                (repaired_costs - report.objective_value - failure.repair_costs.unwrap()).abs()
+                    < EPSILON
+            );
+        }
+    }
+
+    assert!(
+        (report.get_expected_disconnected_sources()
+            - solution
+                .base_graph
+                .calculate_expected_disconnected_sources())
+        .abs()
+            < EPSILON
+    );
+    let score = report.get_robustness_score();
+    assert!((0.0..=1.0).contains(&score));
+
+    // without sources no failure can disconnect one
+    let empty_report = RobustnessReport {
+        number_of_sources: 0,
+        objective_value: 0.0,
+        vertex_failures: Vec::new(),
+    };
+    assert_eq!(empty_report.get_robustness_score(), 1.0);
+}
+
+#[test]
+fn test_unrepairable_failure() {
+    // two sources behind a single inner vertex, nothing can take over its subtree
+    let graph = LayeredGraph::from(vec![
+        Layer::from(vec![Vertex::new(Some(0), None), Vertex::new(Some(0), None)]),
+        Layer::from(vec![Vertex::new(Some(0), Some(vec![0, 1]))]),
+        Layer::from(vec![Vertex::new(None, Some(vec![0]))]),
+    ]);
+    let embeddings = VertexEmbeddings::from(vec![
+        vec![(0.0, 0.0), (0.0, 1.0)],
+        vec![(0.5, 0.5)],
+        vec![(1.0, 0.5)],
+    ]);
+    let solution = GraphEmbedding::new(graph, embeddings);
+
+    let report = solution.analyze_robustness(
+        &vec![1, 2, 2],
+        &MaxChildren::default(),
+        &CostModel::from_alpha(0.5),
+    );
+    assert_eq!(
+        report.get_unrepairable_failures(),
+        vec![VertexID::new(1, 0)]
+    );
+    assert_eq!(report.get_max_repair_costs(), None);
+    assert_eq!(report.get_expected_disconnected_sources(), 2.0);
+    assert_eq!(report.get_robustness_score(), 0.0);
+}
+
+#[test]
+fn test_robustness_penalty() {
+    let mut instance = create_random_flamecast_instance(
+        4,
+        12,
+        2,
+        Some(vec![1, 12, 12, 12]),
+        InitialSolutionFunction::Matching,
+    );
+    let costs = instance.get_objective_function_value();
+    let expected_disconnected_sources = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_expected_disconnected_sources();
+
+    instance
+        .set_robustness_penalty(2.0, &EmbeddingOptions::default())
+        .unwrap();
+    assert!(
+        (instance.get_objective_function_value() - costs - 2.0 * expected_disconnected_sources)
+            .abs()
+            < EPSILON * costs.max(1.0)
+    );
+
+    // the penalty is kept when the instance is stored
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    assert_eq!(loaded.robustness_penalty, 2.0);
+
+    // the setter rejects the same penalties as the instance files
+    for invalid_penalty in [-1.0, f64::NAN] {
+        assert!(instance
+            .set_robustness_penalty(invalid_penalty, &EmbeddingOptions::default())
+            .is_err());
+    }
+    assert_eq!(instance.robustness_penalty, 2.0);
+
+    instance.robustness_penalty = -1.0;
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    assert!(instance_file.validate().is_err());
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    InitialSolutionFunction, SolutionStatistics, UTILIZATION_HISTOGRAM_BINS,
+};
+
+#[test]
+fn test_solution_statistics_layers() {
+    let instance = create_random_flamecast_instance(
+        4,
+        20,
+        2,
+        Some(vec![1, 4, 8, 20]),
+        InitialSolutionFunction::Random,
+    );
+    let solution = &instance.solution_state.current_solution;
+    let statistics = instance.get_solution_statistics();
+
+    assert_eq!(statistics.layers.len(), instance.num_layers);
+    assert_eq!(
+        statistics
+            .layers
+            .iter()
+            .map(|layer| layer.number_of_vertices)
+            .sum::<usize>(),
+        solution.base_graph.get_number_of_vertices()
+    );
+    for (layer_index, layer) in statistics.layers.iter().enumerate() {
+        assert_eq!(layer.capacity, instance.capacities[layer_index]);
+        assert!(layer.mean_utilization > 0.0 && layer.mean_utilization <= 1.0 + EPSILON);
+        if layer_index > 0 {
+            // every vertex of the layer below is the child of one vertex of the layer
+            let layer_branching_factors = &layer.branching_factors;
+            assert_eq!(
+                layer_branching_factors.iter().sum::<usize>(),
+                layer.number_of_vertices
+            );
+            assert_eq!(
+                layer_branching_factors
+                    .iter()
+                    .enumerate()
+                    .map(|(children, count)| children * count)
+                    .sum::<usize>(),
+                statistics.layers[layer_index - 1].number_of_vertices
+            );
+        }
+    }
+    // the drains have no outgoing edges
+    assert_eq!(statistics.layers.last().unwrap().edge_length, 0.0);
+}
+
+#[test]
+fn test_solution_statistics_totals() {
+    let instance = create_random_flamecast_instance(
+        4,
+        20,
+        2,
+        Some(vec![1, 4, 8, 20]),
+        InitialSolutionFunction::Random,
+    );
+    let solution = &instance.solution_state.current_solution;
+    let cost_model = instance.get_cost_model();
+    let statistics = instance.get_solution_statistics();
+
+    // the cost breakdown adds up to the objective value
+    assert!((statistics.objective_value - instance.get_objective_function_value()).abs() < EPSILON);
+    let total_edge_length = solution
+        .calculate_edge_lengths_with_cost_model(&cost_model)
+        .iter()
+        .flat_map(|layer| layer.iter())
+        .sum::<f64>();
+    assert!((statistics.total_edge_length - total_edge_length).abs() < EPSILON);
+    assert!(
+        (statistics.longest_source_path_length
+            - solution.calculate_max_path_length_with_cost_model(&cost_model))
+        .abs()
+            < EPSILON
+    );
+    assert!(statistics.longest_source_path < instance.get_number_of_sources());
+
+    // the histograms count every vertex that is not a source once
+    let number_of_vertices =
+        solution.base_graph.get_number_of_vertices() - instance.get_number_of_sources();
+    assert_eq!(
+        statistics.utilization_histogram.len(),
+        UTILIZATION_HISTOGRAM_BINS
+    );
+    assert_eq!(
+        statistics.utilization_histogram.iter().sum::<usize>(),
+        number_of_vertices
+    );
+    assert_eq!(
+        statistics.flow_histogram.iter().sum::<usize>(),
+        number_of_vertices
+    );
+    assert_eq!(
+        statistics.branching_factors.iter().sum::<usize>(),
+        number_of_vertices
+    );
+
+    // the statistics are written next to the solutions
+    let json = serde_json::to_string(&statistics).unwrap();
+    let loaded = serde_json::from_str::<SolutionStatistics>(&json).unwrap();
+    assert_eq!(loaded.layers.len(), statistics.layers.len());
+    assert_eq!(loaded.flow_histogram, statistics.flow_histogram);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+use rand::Rng;
+
+use crate::{
+    tests::{create_random_flamecast_graph, EPSILON},
+    VertexID,
+};
+
+#[test]
+fn test_topological_distance_of_same_topology() {
+    let graph = create_random_flamecast_graph(4, &vec![1, 4, 8, 20], 20, 2);
+    let distance = graph.calculate_topological_distance(&graph).unwrap();
+    assert!(distance.distance.abs() < EPSILON);
+    assert_eq!(distance.layer_rand_indices.len(), 3);
+    assert!(graph
+        .calculate_source_grouping_diff(&graph)
+        .unwrap()
+        .is_empty());
+
+    // the order of the inner vertices is not part of the topology
+    let mut permuted = graph.clone();
+    let mut rng = rand::thread_rng();
+    for layer_index in 1..3 {
+        let layer_size = permuted.layers[layer_index].vertices.len();
+        for _ in 0..layer_size {
+            let vertex1 = VertexID::new(layer_index, rng.gen_range(0..layer_size));
+            let vertex2 = VertexID::new(layer_index, rng.gen_range(0..layer_size));
+            permuted.swap_vertices_position(&vertex1, &vertex2);
+        }
+    }
+    let distance = graph.calculate_topological_distance(&permuted).unwrap();
+    assert!(distance.distance.abs() < EPSILON);
+    assert!(graph
+        .calculate_source_grouping_diff(&permuted)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_topological_distance_of_different_topologies() {
+    let graph = create_random_flamecast_graph(4, &vec![1, 4, 8, 20], 20, 2);
+
+    // swapping two sources of different vertices of the first layer changes only a few pairs
+    let mut changed = graph.clone();
+    // the first source has a sibling, so the swap cannot just exchange two single sources
+    let source1 = (0..changed.layers[0].vertices.len())
+        .map(|index| VertexID::new(0, index))
+        .find(|source| {
+            let parent = changed.get_parent(source).unwrap();
+            changed.get_vertex(&parent).get_number_of_children() > 1
+        })
+        .unwrap();
+    let parent1 = changed.get_parent(&source1).unwrap();
+    let source2 = changed.layers[0]
+        .vertices
+        .iter()
+        .position(|vertex| vertex.parent_index.unwrap() != parent1.index)
+        .unwrap();
+    changed.swap(&source1, &VertexID::new(0, source2));
+
+    let distance = graph.calculate_topological_distance(&changed).unwrap();
+    assert!(distance.distance > EPSILON);
+    assert!(distance.distance < 0.5);
+    assert!(distance.layer_rand_indices[0] < 1.0 - EPSILON);
+    assert!(distance
+        .layer_rand_indices
+        .iter()
+        .all(|rand_index| *rand_index >= 0.0 && *rand_index <= 1.0));
+
+    // the source is grouped differently in the first layer
+    let diffs = graph.calculate_source_grouping_diff(&changed).unwrap();
+    assert_eq!(diffs[0].layer, 1);
+    assert!(diffs[0]
+        .only_in_first
+        .iter()
+        .any(|group| group.contains(&source1.index)));
+    assert!(diffs[0]
+        .only_in_second
+        .iter()
+        .any(|group| group.contains(&source1.index)));
+
+    // the distance is symmetric
+    let reverse_distance = changed.calculate_topological_distance(&graph).unwrap();
+    assert!((distance.distance - reverse_distance.distance).abs() < EPSILON);
+}
+
+#[test]
+fn test_topological_distance_of_different_instances() {
+    let graph = create_random_flamecast_graph(4, &vec![1, 4, 8, 20], 20, 2);
+    let other_graph = create_random_flamecast_graph(4, &vec![1, 4, 8, 20], 19, 2);
+    assert!(graph.calculate_topological_distance(&other_graph).is_err());
+    assert!(graph.calculate_source_grouping_diff(&other_graph).is_err());
+}
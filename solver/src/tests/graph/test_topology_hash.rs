@@ -0,0 +1,94 @@
+#[cfg(test)]
+use rand::Rng;
+
+#[cfg(test)]
+use crate::{
+    tests::create_random_flamecast_graph, EmbeddingOptions, SearchDepth, TopologyCache, VertexID,
+};
+
+#[cfg(test)]
+fn process_test_topology_hash_permutation(
+    num_layers: usize,
+    capacities: &Vec<usize>,
+    num_sources: usize,
+    num_drains: usize,
+) {
+    let mut graph = create_random_flamecast_graph(num_layers, capacities, num_sources, num_drains);
+    let topology_hash = graph.calculate_topology_hash();
+
+    // permuting the vertices of the inner layers doesn't change the topology
+    let mut rng = rand::thread_rng();
+    for layer_index in 1..num_layers - 1 {
+        let layer_size = graph.layers[layer_index].vertices.len();
+        for _ in 0..layer_size {
+            let vertex1 = VertexID::new(layer_index, rng.gen_range(0..layer_size));
+            let vertex2 = VertexID::new(layer_index, rng.gen_range(0..layer_size));
+            graph.swap_vertices_position(&vertex1, &vertex2);
+        }
+    }
+
+    assert!(graph.is_valid_flamecast_topology(capacities));
+    assert!(graph.calculate_topology_hash() == topology_hash);
+
+    // swapping two sources with different parents changes the topology
+    let source1 = VertexID::new(0, 0);
+    let parent1 = graph.get_parent(&source1).unwrap();
+    let source2 = graph.layers[0]
+        .vertices
+        .iter()
+        .position(|vertex| vertex.parent_index.unwrap() != parent1.index)
+        .unwrap();
+    graph.swap(&source1, &VertexID::new(0, source2));
+
+    assert!(graph.calculate_topology_hash() != topology_hash);
+}
+
+#[test]
+fn test_topology_hash_permutation1() {
+    process_test_topology_hash_permutation(3, &vec![1, 2, 4], 20, 5);
+}
+
+#[test]
+fn test_topology_hash_permutation2() {
+    process_test_topology_hash_permutation(5, &vec![1, 4, 8, 10, 20], 400, 20);
+}
+
+#[test]
+fn test_topology_hash_permutation3() {
+    process_test_topology_hash_permutation(6, &vec![1, 2, 4, 8, 16, 50], 200, 4);
+}
+
+#[test]
+fn test_topology_cache() {
+    let options = EmbeddingOptions::default();
+    let mut cache = TopologyCache::new(2);
+    assert!(cache.is_empty());
+
+    assert!(cache.get(1, &options).is_none());
+    cache.insert(1, &options, 1.0);
+    cache.insert(2, &options, 2.0);
+    assert!(cache.get(1, &options) == Some(1.0));
+
+    // other embedding depths and time limits are cached separately
+    assert!(cache
+        .get(1, &EmbeddingOptions::from_depth(SearchDepth::Deep))
+        .is_none());
+    let mut limited_options = EmbeddingOptions::default();
+    limited_options.time_limit = 1.0;
+    assert!(cache.get(1, &limited_options).is_none());
+
+    // the oldest entry is removed when the capacity is exceeded
+    cache.insert(3, &options, 3.0);
+    assert!(cache.len() == 2);
+    assert!(cache.get(1, &options).is_none());
+    assert!(cache.get(3, &options) == Some(3.0));
+
+    assert!(cache.hits == 2);
+    assert!(cache.misses == 4);
+
+    let mut disabled_cache = TopologyCache::new(0);
+    disabled_cache.insert(1, &options, 1.0);
+    assert!(disabled_cache.is_empty());
+    assert!(disabled_cache.get(1, &options).is_none());
+    assert!(disabled_cache.misses == 0);
+}
@@ -0,0 +1,202 @@
+#![cfg(test)]
+use std::{
+    env::temp_dir,
+    fs::{remove_file, write},
+};
+
+use crate::{
+    import::{import_flamecast_instance, parse_flamecast_instance},
+    tests::{create_random_flamecast_instance, EPSILON},
+    FlamecastInstance, FlamecastTestInstance, ImportFormat, ImportOptions, InitialSolutionFunction,
+};
+
+#[cfg(test)]
+const CSV_WITH_HEADER: &str = "id;type;x;y
+0;source;0.1;0.2
+1;source;0.3;0.4
+2;sink;0.9;0.5
+3;source;0.2;0.8
+";
+
+#[cfg(test)]
+const CSV_WITHOUT_HEADER: &str = "# x y type
+0.1 0.2 source
+0.3 0.4 source
+
+0.9 0.5 drain
+0.2 0.8 source
+";
+
+#[cfg(test)]
+const TSPLIB_INSTANCE: &str = "NAME : test
+TYPE : CVRP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0.9 0.5
+2 0.1 0.2
+3 0.3 0.4
+4 0.2 0.8
+DEPOT_SECTION
+1
+-1
+EOF
+";
+
+#[cfg(test)]
+const GEOJSON_INSTANCE: &str = r#"{"type": "FeatureCollection", "features": [
+    {"type": "Feature", "geometry": {"type": "Point", "coordinates": [0.1, 0.2]}, "properties": {"type": "source"}},
+    {"type": "Feature", "geometry": {"type": "Point", "coordinates": [0.3, 0.4]}, "properties": {"role": "source"}},
+    {"type": "Feature", "geometry": {"type": "Point", "coordinates": [0.9, 0.5]}, "properties": {"type": "drain"}},
+    {"type": "Feature", "geometry": {"type": "LineString", "coordinates": [[0.1, 0.2], [0.9, 0.5]]}, "properties": {}},
+    {"type": "Feature", "geometry": {"type": "Point", "coordinates": [0.2, 0.8]}, "properties": {"type": "source"}}
+]}"#;
+
+#[cfg(test)]
+fn validate_imported_instance(instance: &FlamecastTestInstance, options: &ImportOptions) {
+    let embeddings = &instance.sources_drains_embeddings.embeddings;
+    assert_eq!(instance.num_layers, options.num_layers);
+    assert_eq!(embeddings.len(), options.num_layers);
+    assert_eq!(embeddings[0], vec![(0.1, 0.2), (0.3, 0.4), (0.2, 0.8)]);
+    assert_eq!(embeddings[options.num_layers - 1], vec![(0.9, 0.5)]);
+    assert!(embeddings[1..options.num_layers - 1]
+        .iter()
+        .all(|layer| layer.is_empty()));
+    assert_eq!(instance.alpha, options.alpha);
+    assert_eq!(instance.capacities.len(), options.num_layers);
+
+    // the imported instance must be solvable
+    let flamecast_instance = FlamecastInstance::new(
+        instance.alpha,
+        instance.num_layers,
+        instance.capacities.clone(),
+        instance.sources_drains_embeddings.clone(),
+        InitialSolutionFunction::Random,
+    );
+    assert!(flamecast_instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .is_valid_flamecast_topology_check_all(&instance.capacities, 3, 1, instance.num_layers));
+}
+
+#[cfg(test)]
+fn process_import_file(content: &str, extension: &str, expected_format: ImportFormat) {
+    let file_path = temp_dir().join(format!(
+        "test_import_{}.{}",
+        rand::random::<u64>(),
+        extension
+    ));
+    let file_path = file_path.to_str().unwrap();
+    write(file_path, content).unwrap();
+
+    assert_eq!(ImportFormat::detect(file_path, content), expected_format);
+
+    let options = ImportOptions::new(4, Some(vec![1, 2, 3, 3]), 0.7, None);
+    let instance = import_flamecast_instance(file_path, &options).unwrap();
+    validate_imported_instance(&instance, &options);
+
+    remove_file(file_path).unwrap();
+}
+
+#[test]
+fn test_import_csv() {
+    let options = ImportOptions::default();
+    let instance = parse_flamecast_instance(CSV_WITH_HEADER, ImportFormat::Csv, &options).unwrap();
+    validate_imported_instance(&instance, &options);
+    assert_eq!(instance.capacities, vec![1, 3, 3, 3, 3]);
+
+    let instance =
+        parse_flamecast_instance(CSV_WITHOUT_HEADER, ImportFormat::Csv, &options).unwrap();
+    validate_imported_instance(&instance, &options);
+}
+
+#[test]
+fn test_import_tsplib() {
+    let options = ImportOptions::default();
+    let instance =
+        parse_flamecast_instance(TSPLIB_INSTANCE, ImportFormat::Tsplib, &options).unwrap();
+    validate_imported_instance(&instance, &options);
+}
+
+#[test]
+fn test_import_geojson() {
+    let options = ImportOptions::default();
+    let instance =
+        parse_flamecast_instance(GEOJSON_INSTANCE, ImportFormat::GeoJson, &options).unwrap();
+    validate_imported_instance(&instance, &options);
+}
+
+#[test]
+fn test_import_exported_geojson() {
+    let flamecast_instance =
+        create_random_flamecast_instance(4, 12, 3, None, InitialSolutionFunction::Random);
+
+    // an exported solution can be imported again as instance
+    let geojson = flamecast_instance
+        .solution_state
+        .current_solution
+        .to_geojson(flamecast_instance.alpha);
+    let options = ImportOptions::new(
+        flamecast_instance.num_layers,
+        Some(flamecast_instance.capacities.clone()),
+        flamecast_instance.alpha,
+        None,
+    );
+    let instance = parse_flamecast_instance(&geojson, ImportFormat::GeoJson, &options).unwrap();
+
+    // json parsing may change the last digit of the coordinates
+    let imported_embeddings = &instance.sources_drains_embeddings.embeddings;
+    let original_embeddings = &flamecast_instance.sources_drains_embeddings.embeddings;
+    assert_eq!(imported_embeddings.len(), original_embeddings.len());
+    for (imported_layer, original_layer) in imported_embeddings.iter().zip(original_embeddings) {
+        assert_eq!(imported_layer.len(), original_layer.len());
+        for (imported, original) in imported_layer.iter().zip(original_layer) {
+            assert!((imported.0 - original.0).abs() < EPSILON);
+            assert!((imported.1 - original.1).abs() < EPSILON);
+        }
+    }
+    assert_eq!(instance.capacities, flamecast_instance.capacities);
+}
+
+#[test]
+fn test_import_detection() {
+    process_import_file(CSV_WITH_HEADER, "csv", ImportFormat::Csv);
+    process_import_file(CSV_WITHOUT_HEADER, "txt", ImportFormat::Csv);
+    process_import_file(TSPLIB_INSTANCE, "tsp", ImportFormat::Tsplib);
+    process_import_file(TSPLIB_INSTANCE, "txt", ImportFormat::Tsplib);
+    process_import_file(GEOJSON_INSTANCE, "json", ImportFormat::GeoJson);
+    process_import_file(GEOJSON_INSTANCE, "geojson", ImportFormat::GeoJson);
+}
+
+#[test]
+fn test_import_errors() {
+    let options = ImportOptions::default();
+
+    let no_drains = "0.1,0.2,source\n0.3,0.4,source\n";
+    assert!(parse_flamecast_instance(no_drains, ImportFormat::Csv, &options).is_err());
+
+    let unknown_type = "0.1,0.2,source\n0.3,0.4,hub\n";
+    assert!(parse_flamecast_instance(unknown_type, ImportFormat::Csv, &options).is_err());
+
+    let invalid_coordinate = "0.1,abc,source\n0.3,0.4,drain\n";
+    assert!(parse_flamecast_instance(invalid_coordinate, ImportFormat::Csv, &options).is_err());
+
+    let unknown_depot = TSPLIB_INSTANCE.replace("DEPOT_SECTION\n1", "DEPOT_SECTION\n7");
+    assert!(parse_flamecast_instance(&unknown_depot, ImportFormat::Tsplib, &options).is_err());
+
+    let wrong_capacities = ImportOptions::new(5, Some(vec![1, 2, 3]), 0.5, None);
+    assert!(
+        parse_flamecast_instance(CSV_WITH_HEADER, ImportFormat::Csv, &wrong_capacities).is_err()
+    );
+
+    let too_small_drains = ImportOptions::new(3, Some(vec![1, 2, 2]), 0.5, None);
+    assert!(
+        parse_flamecast_instance(CSV_WITH_HEADER, ImportFormat::Csv, &too_small_drains).is_err()
+    );
+
+    let invalid_alpha = ImportOptions::new(3, None, 1.5, None);
+    assert!(parse_flamecast_instance(CSV_WITH_HEADER, ImportFormat::Csv, &invalid_alpha).is_err());
+
+    assert!(import_flamecast_instance("./does_not_exist.csv", &options).is_err());
+}
@@ -3,7 +3,10 @@ use std::sync::LazyLock;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::{plotting::plot_flamecast_test_instance, VertexEmbeddings};
+use crate::{
+    plotting::plot_flamecast_test_instance, FlamecastInstance, InitialSolutionFunction,
+    MaxChildren, VertexEmbeddings,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FlamecastTestInstance {
@@ -11,6 +14,10 @@ pub struct FlamecastTestInstance {
     pub num_layers: usize,
     pub capacities: Vec<usize>,
     pub sources_drains_embeddings: VertexEmbeddings,
+    #[serde(default)]
+    pub layer_alphas: Vec<f64>, // one entry per edge layer, empty for the same alpha everywhere
+    #[serde(default)]
+    pub layer_cost_multipliers: Vec<f64>,
 }
 
 impl FlamecastTestInstance {
@@ -25,6 +32,8 @@ impl FlamecastTestInstance {
             num_layers,
             capacities,
             sources_drains_embeddings,
+            layer_alphas: Vec::new(),
+            layer_cost_multipliers: Vec::new(),
         }
     }
 
@@ -41,6 +50,8 @@ const FLAMECAST_TEST_INSTANCE1: LazyLock<FlamecastTestInstance> =
         sources_drains_embeddings: VertexEmbeddings {
             embeddings: vec![vec![(0.25, 0.25), (0.25, 0.75)], vec![], vec![(0.5, 0.5)]],
         },
+        layer_alphas: vec![],
+        layer_cost_multipliers: vec![],
     });
 
 const FLAMECAST_TEST_INSTANCE2: LazyLock<FlamecastTestInstance> =
@@ -57,6 +68,8 @@ const FLAMECAST_TEST_INSTANCE2: LazyLock<FlamecastTestInstance> =
                 vec![(0.75, 0.5)],
             ],
         },
+        layer_alphas: vec![],
+        layer_cost_multipliers: vec![],
     });
 
 const FLAMECAST_TEST_INSTANCE3: LazyLock<FlamecastTestInstance> =
@@ -83,6 +96,8 @@ const FLAMECAST_TEST_INSTANCE3: LazyLock<FlamecastTestInstance> =
                 vec![(0.75, 0.2), (0.75, 0.4), (0.75, 0.6), (0.75, 0.8)],
             ],
         },
+        layer_alphas: vec![],
+        layer_cost_multipliers: vec![],
     });
 
 pub const FLAMECAST_TEST_INSTANCES: [LazyLock<FlamecastTestInstance>; 3] = [
@@ -136,3 +151,67 @@ pub fn generate_random_flamecast_instance(
 
     FlamecastTestInstance::new(alpha, num_layers, capacities, sources_drains_embeddings)
 }
+
+pub fn create_random_flamecast_instance(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    capacities: Option<Vec<usize>>, // the random capacities are used if none are given
+    initial_solution_function: InitialSolutionFunction,
+) -> FlamecastInstance {
+    return create_random_flamecast_instance_with_max_children(
+        num_layers,
+        num_sources,
+        num_drains,
+        capacities,
+        initial_solution_function,
+        MaxChildren::default(),
+    )
+    .unwrap();
+}
+
+pub fn create_random_flamecast_instance_with_max_children(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    capacities: Option<Vec<usize>>,
+    initial_solution_function: InitialSolutionFunction,
+    max_children: MaxChildren,
+) -> Result<FlamecastInstance, String> {
+    let instance = generate_random_flamecast_instance(num_layers, num_sources, num_drains, false);
+    return FlamecastInstance::new_with_max_children(
+        instance.alpha,
+        instance.num_layers,
+        capacities.unwrap_or(instance.capacities),
+        instance.sources_drains_embeddings,
+        initial_solution_function,
+        max_children,
+    );
+}
+
+pub fn generate_random_flamecast_instance_with_layer_costs(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    clear_structure: bool,
+) -> FlamecastTestInstance {
+    let mut rng = rand::thread_rng();
+    let mut instance =
+        generate_random_flamecast_instance(num_layers, num_sources, num_drains, clear_structure);
+
+    // edges closer to the drains bundle more flow, so they get stronger economies of scale
+    // and higher unit costs, like backbone cables compared to access cables
+    let mut layer_alphas = (0..num_layers - 1)
+        .map(|_| rng.gen_range(0.0..=1.0))
+        .collect::<Vec<f64>>();
+    layer_alphas.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    let mut layer_cost_multipliers = (0..num_layers - 1)
+        .map(|_| rng.gen_range(1.0..=4.0))
+        .collect::<Vec<f64>>();
+    layer_cost_multipliers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    instance.layer_alphas = layer_alphas;
+    instance.layer_cost_multipliers = layer_cost_multipliers;
+    return instance;
+}
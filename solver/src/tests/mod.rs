@@ -1,7 +1,12 @@
 mod embedding;
+mod export;
 mod graph;
+mod import;
 mod initial_flamecast;
 mod neighborhood;
+mod plotting;
+mod schema;
+mod simulated_annealing;
 
 pub use embedding::*;
 pub use initial_flamecast::*;
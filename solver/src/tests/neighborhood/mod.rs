@@ -1,9 +1,12 @@
 mod neighbors_calculation;
 mod test_instances;
+mod test_max_children;
 mod test_merge_operation;
 mod test_neighbor;
 mod test_recable_operation;
+mod test_reroute_operation;
 mod test_split_operation;
+mod test_subtree_exchange_operation;
 mod test_swap_operation;
 mod util;
 
@@ -7,6 +7,8 @@ pub fn calculate_all_neighbors(graph: &LayeredGraph) -> Vec<Neighbor> {
     result.append(&mut &mut get_all_swaps(graph));
     result.append(&mut get_all_merges(graph));
     result.append(&mut get_all_splits(graph));
+    result.append(&mut get_all_subtree_exchanges(graph));
+    result.append(&mut get_all_reroutes(graph));
     return result;
 }
 
@@ -95,3 +97,50 @@ fn get_all_splits(graph: &LayeredGraph) -> Vec<Neighbor> {
 
     return possible_splits;
 }
+
+#[cfg(test)]
+pub fn get_all_subtree_exchanges(graph: &LayeredGraph) -> Vec<Neighbor> {
+    let mut possible_exchanges = Vec::new();
+
+    let num_layers = graph.layers.len();
+    for layer in 1..num_layers - 1 {
+        for node1_index in 0..graph.layers[layer].vertices.len() {
+            let node1_id = VertexID::new(layer, node1_index);
+
+            for node2_index in (node1_index + 1)..graph.layers[layer].vertices.len() {
+                let node2_id = VertexID::new(layer, node2_index);
+
+                if graph.get_parent(&node1_id).unwrap() != graph.get_parent(&node2_id).unwrap() {
+                    possible_exchanges.push(Neighbor::SubtreeExchange(node1_id.clone(), node2_id));
+                }
+            }
+        }
+    }
+
+    return possible_exchanges;
+}
+
+#[cfg(test)]
+pub fn get_all_reroutes(graph: &LayeredGraph) -> Vec<Neighbor> {
+    let mut possible_reroutes = Vec::new();
+
+    // only targets that belong to another drain are part of the neighborhood
+    let vertex_drains = graph.calculate_vertex_drains();
+    let num_layers = graph.layers.len();
+    for layer in 0..num_layers - 1 {
+        for node in 0..graph.layers[layer].vertices.len() {
+            let node_id = VertexID::new(layer, node);
+
+            for target_layer in (layer + 2)..num_layers {
+                for target_node in 0..graph.layers[target_layer].vertices.len() {
+                    if vertex_drains[target_layer][target_node] != vertex_drains[layer][node] {
+                        let target_node_id = VertexID::new(target_layer, target_node);
+                        possible_reroutes.push(Neighbor::Reroute(node_id.clone(), target_node_id));
+                    }
+                }
+            }
+        }
+    }
+
+    return possible_reroutes;
+}
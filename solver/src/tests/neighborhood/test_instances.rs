@@ -1,8 +1,9 @@
 use std::sync::LazyLock;
 
 use crate::{
-    FlamecastInstance, GraphEmbedding, Layer, LayeredGraph, SimulatedAnnealingLogger,
-    SolutionState, Vertex, VertexEmbeddings,
+    CoordinateSystem, DistanceMetric, EdgeCostFunction, FlamecastInstance, GraphEmbedding, Layer,
+    LayeredGraph, MaxChildren, MaxPathLength, SimulatedAnnealingLogger, SolutionState,
+    TopologyCache, Vertex, VertexEmbeddings,
 };
 
 const NEIGHBORHOOD_TEST_INSTANCE1: LazyLock<FlamecastInstance> =
@@ -19,6 +20,15 @@ const NEIGHBORHOOD_TEST_INSTANCE1: LazyLock<FlamecastInstance> =
                 vec![(0.75, 0.5)],
             ],
         },
+        coordinate_system: CoordinateSystem::Euclidean,
+        metric: DistanceMetric::Euclidean,
+        edge_cost_function: EdgeCostFunction::Power,
+        layer_alphas: vec![],
+        layer_cost_multipliers: vec![],
+        vertex_opening_costs: vec![],
+        max_path_length: MaxPathLength::Unbounded,
+        max_children: MaxChildren::default(),
+        robustness_penalty: 0.0,
         solution_state: SolutionState::new(GraphEmbedding::new(
             LayeredGraph::from(vec![
                 Layer::from(vec![
@@ -47,6 +57,8 @@ const NEIGHBORHOOD_TEST_INSTANCE1: LazyLock<FlamecastInstance> =
             VertexEmbeddings::new(),
         )),
         logger: SimulatedAnnealingLogger::new_empty(),
+        source_updates: Vec::new(),
+        topology_cache: TopologyCache::default(),
     });
 
 pub const NEIGHBORHOOD_TEST_INSTANCES: [LazyLock<FlamecastInstance>; 1] =
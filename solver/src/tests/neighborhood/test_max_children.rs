@@ -0,0 +1,166 @@
+#![cfg(test)]
+use crate::{
+    tests::create_random_flamecast_instance_with_max_children, FlamecastInstance,
+    FlamecastInstanceFile, FlamecastSolutionFile, InitialSolutionFunction, MaxChildren,
+    NeighborSearchOption, OptimizationOptions, VertexID,
+};
+
+#[cfg(test)]
+fn create_instance_with_max_children(
+    max_children: Vec<usize>,
+    initial_solution_function: InitialSolutionFunction,
+) -> FlamecastInstance {
+    return create_random_flamecast_instance_with_max_children(
+        5,
+        12,
+        2,
+        Some(vec![1, 12, 12, 12, 12]),
+        initial_solution_function,
+        MaxChildren::new(max_children),
+    )
+    .unwrap();
+}
+
+#[cfg(test)]
+fn assert_max_children_respected(instance: &FlamecastInstance) {
+    assert!(instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .is_valid_flamecast_topology_check_all(
+            &instance.capacities,
+            instance.get_number_of_sources(),
+            instance.get_number_of_drains(),
+            instance.num_layers,
+        ));
+    assert!(instance.get_max_children_violations().is_empty());
+}
+
+#[test]
+fn test_max_children_validation() {
+    let capacities = vec![1, 10, 10];
+    assert!(MaxChildren::default().validate(&capacities, 100, 1).is_ok());
+
+    let max_children = MaxChildren::new(vec![1, 2, 3]);
+    assert_eq!(
+        max_children.get_max_sources_per_vertex(&capacities),
+        vec![1, 2, 6]
+    );
+    assert!(max_children.validate(&capacities, 12, 2).is_ok());
+    // the drains cannot serve more than six sources each
+    assert!(max_children.validate(&capacities, 13, 2).is_err());
+    assert!(MaxChildren::new(vec![1, 2])
+        .validate(&capacities, 1, 1)
+        .is_err());
+    assert!(MaxChildren::new(vec![1, 0, 3])
+        .validate(&capacities, 1, 1)
+        .is_err());
+
+    // invalid max children are rejected when the instance is created
+    assert!(create_random_flamecast_instance_with_max_children(
+        3,
+        13,
+        2,
+        Some(capacities),
+        InitialSolutionFunction::Random,
+        max_children,
+    )
+    .is_err());
+
+    // the constraints are kept when an instance is stored and loaded
+    let instance =
+        create_instance_with_max_children(vec![1, 2, 3, 3, 3], InitialSolutionFunction::Matching);
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    assert_eq!(loaded.max_children, instance.max_children);
+}
+
+#[test]
+fn test_initial_solutions_with_max_children() {
+    for initial_solution_function in [
+        InitialSolutionFunction::Random,
+        InitialSolutionFunction::Matching,
+    ] {
+        let instance =
+            create_instance_with_max_children(vec![1, 2, 3, 3, 3], initial_solution_function);
+        assert_max_children_respected(&instance);
+    }
+
+    // every source has its own chain, so only the drains have more than one child
+    let instance = create_instance_with_max_children(
+        vec![1, 1, 1, 1, 10],
+        InitialSolutionFunction::LowConnectivity,
+    );
+    assert_max_children_respected(&instance);
+}
+
+#[test]
+fn test_moves_respect_max_children() {
+    let instance =
+        create_instance_with_max_children(vec![1, 2, 3, 3, 3], InitialSolutionFunction::Random);
+    let graph = &instance.solution_state.current_solution.base_graph;
+    let flows = graph.calculate_vertex_flows();
+
+    for (parent_index, parent) in graph.layers[1].vertices.iter().enumerate() {
+        let parent_id = VertexID::new(1, parent_index);
+        let number_of_children = parent.get_number_of_children();
+
+        // a full vertex cannot get another child
+        if number_of_children == 2 {
+            for node_index in 0..graph.layers[0].vertices.len() {
+                let node_id = VertexID::new(0, node_index);
+                assert!(!graph.check_recable_possible(
+                    &node_id,
+                    instance.num_layers,
+                    &parent_id,
+                    &instance.capacities,
+                    &instance.max_children,
+                    &flows,
+                ));
+            }
+        }
+
+        // merges must not exceed the max children of the merged vertex
+        for other_index in 0..graph.layers[1].vertices.len() {
+            let other_id = VertexID::new(1, other_index);
+            if number_of_children + graph.get_vertex(&other_id).get_number_of_children() > 2 {
+                assert!(!graph.check_merge_possible(
+                    &parent_id,
+                    &other_id,
+                    &instance.capacities,
+                    &instance.max_children,
+                    &flows,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_solve_with_max_children() {
+    for neighbor_search_option in [
+        NeighborSearchOption::CompleteHeuristical,
+        NeighborSearchOption::CompleteEmbedding,
+    ] {
+        let mut instance = create_instance_with_max_children(
+            vec![1, 2, 3, 3, 3],
+            InitialSolutionFunction::Matching,
+        );
+
+        let mut options = OptimizationOptions::default();
+        options.neighbor_search_option = neighbor_search_option;
+        options.max_iterations = 10;
+        options.verbose = false;
+        instance.solve(options);
+
+        assert_max_children_respected(&instance);
+        let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+        assert!(solution_file.validate().is_ok());
+    }
+
+    // solutions with too many children are rejected when they are loaded
+    let instance = create_instance_with_max_children(Vec::new(), InitialSolutionFunction::Matching);
+    let mut solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    solution_file.instance.max_children = MaxChildren::new(vec![1, 1, 1, 1, 10]);
+    assert!(solution_file.validate().is_err());
+}
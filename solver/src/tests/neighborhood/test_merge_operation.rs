@@ -4,7 +4,7 @@ use rand::seq::SliceRandom;
 #[cfg(test)]
 use crate::{
     graph_generation::generate_random_flamecast_graph, tests::vertices_equal, LayeredGraph,
-    VertexID,
+    MaxChildren, VertexID,
 };
 
 #[cfg(test)]
@@ -21,6 +21,7 @@ fn process_merge_operation(
     let mut graph = generate_random_flamecast_graph(
         num_layers,
         capacities,
+        &MaxChildren::default(),
         num_sources,
         num_drains,
         &sources_embeddings,
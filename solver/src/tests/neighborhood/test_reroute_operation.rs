@@ -0,0 +1,235 @@
+#[cfg(test)]
+use rand::{seq::SliceRandom, Rng};
+
+#[cfg(test)]
+use crate::{
+    tests::{
+        create_random_flamecast_graph, create_random_flamecast_instance,
+        generate_random_flamecast_instance, vertices_equal, EPSILON,
+    },
+    EmbeddingOptions, FlamecastInstance, InitialSolutionFunction, LayeredGraph, Neighbor,
+    NeighborLoader, VertexID,
+};
+
+#[cfg(test)]
+fn process_reroute_operation(
+    num_layers: usize,
+    capacities: &Vec<usize>,
+    num_sources: usize,
+    num_drains: usize,
+) {
+    let mut graph = create_random_flamecast_graph(num_layers, capacities, num_sources, num_drains);
+    let graph_copy = graph.clone();
+
+    let (node_id, target_node_id) = calculate_random_reroute_pair(&graph);
+
+    let old_parent = graph.get_parent(&node_id).unwrap();
+
+    graph.reroute(&node_id, &target_node_id);
+
+    // the node is connected to the target by a chain of new vertices with only one child
+    let mut current_vertex = graph.get_parent(&node_id).unwrap();
+    while current_vertex != target_node_id {
+        assert!(current_vertex.index == graph_copy.layers[current_vertex.layer].vertices.len());
+        assert!(graph.get_children(&current_vertex).unwrap().len() == 1);
+        current_vertex = graph.get_parent(&current_vertex).unwrap();
+    }
+    assert!(!graph.get_children(&old_parent).unwrap().contains(&node_id));
+    assert!(graph.is_valid_flamecast_topology(&vec![num_sources; num_layers]));
+
+    graph.undo_reroute(&node_id, &target_node_id, &old_parent);
+
+    assert!(graph.get_layer_structure() == graph_copy.get_layer_structure());
+
+    assert!(vertices_equal(
+        graph.get_vertex(&node_id),
+        graph_copy.get_vertex(&node_id)
+    ));
+
+    assert!(vertices_equal(
+        graph.get_vertex(&old_parent),
+        graph_copy.get_vertex(&old_parent)
+    ));
+
+    assert!(vertices_equal(
+        graph.get_vertex(&target_node_id),
+        graph_copy.get_vertex(&target_node_id)
+    ));
+}
+
+#[cfg(test)]
+fn calculate_random_reroute_pair(graph: &LayeredGraph) -> (VertexID, VertexID) {
+    let num_layers = graph.layers.len();
+    let vertex_drains = graph.calculate_vertex_drains();
+
+    let mut rng = rand::thread_rng();
+    let node_layer = rng.gen_range(0..num_layers - 2);
+
+    let mut candidate_nodes = (0..graph.layers[node_layer].vertices.len()).collect::<Vec<usize>>();
+    candidate_nodes.shuffle(&mut rng);
+
+    let node_index = *candidate_nodes
+        .iter()
+        .find(|node| {
+            let parent = graph
+                .get_parent(&VertexID::new(node_layer, **node))
+                .unwrap();
+            graph.get_children(&parent).unwrap().len() > 1
+        })
+        .unwrap();
+
+    let target_layer = rng.gen_range(node_layer + 2..num_layers);
+    let target_index = graph.layers[target_layer]
+        .vertices
+        .iter()
+        .enumerate()
+        .position(|(index, _)| {
+            vertex_drains[target_layer][index] != vertex_drains[node_layer][node_index]
+        })
+        .unwrap();
+
+    return (
+        VertexID::new(node_layer, node_index),
+        VertexID::new(target_layer, target_index),
+    );
+}
+
+#[cfg(test)]
+fn process_reroute_neighbors(
+    num_layers: usize,
+    num_sources: usize,
+    num_drains: usize,
+    heuristical: bool,
+) {
+    let test_instance =
+        generate_random_flamecast_instance(num_layers, num_sources, num_drains, true);
+    let mut instance = FlamecastInstance::new(
+        test_instance.alpha,
+        test_instance.num_layers,
+        test_instance.capacities,
+        test_instance.sources_drains_embeddings,
+        InitialSolutionFunction::Random,
+    );
+
+    let current_vertex_flows = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_vertex_flows();
+    let reroutes = if heuristical {
+        let current_costs = instance.get_objective_function_value();
+        let vertices = instance
+            .solution_state
+            .current_solution
+            .base_graph
+            .get_sorted_random_vertices(20);
+        instance
+            .get_heuristical_reroutes(&vertices, current_costs, &current_vertex_flows)
+            .into_iter()
+            .map(|neighbor_cost| neighbor_cost.neighbor)
+            .collect()
+    } else {
+        instance.get_all_possible_reroutes(&current_vertex_flows)
+    };
+
+    let graph = &mut instance.solution_state.current_solution.base_graph;
+    let graph_copy = graph.clone();
+    let mut neighbor_loader = NeighborLoader::new();
+
+    reroutes.iter().for_each(|neighbor| {
+        assert!(neighbor.is_reroute());
+
+        neighbor_loader.load_neighbor(graph, neighbor);
+        assert!(graph.is_valid_flamecast_topology(&instance.capacities));
+        neighbor_loader.unload_neighbor(graph, neighbor);
+
+        assert!(graph.get_layer_structure() == graph_copy.get_layer_structure());
+    });
+
+    instance.embed_current_solution(&EmbeddingOptions::default());
+}
+
+#[test]
+fn test_reroute_costs_with_layer_costs() {
+    // capacities that leave room for reroutes to every other drain
+    let mut instance = create_random_flamecast_instance(
+        5,
+        60,
+        3,
+        Some(vec![1, 60, 60, 60, 60]),
+        InitialSolutionFunction::Random,
+    );
+    instance
+        .set_layer_costs(
+            vec![0.2, 0.4, 0.6, 0.8],
+            vec![1.0, 2.0, 3.0, 4.0],
+            &EmbeddingOptions::default(),
+        )
+        .unwrap();
+    let cost_model = instance.get_cost_model();
+    let current_costs = instance.get_objective_function_value();
+    let current_vertex_flows = instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .calculate_vertex_flows();
+
+    let reroutes = instance.get_all_possible_reroutes(&current_vertex_flows);
+    assert!(!reroutes.is_empty());
+    for neighbor in reroutes {
+        let (node, target_node) = match &neighbor {
+            Neighbor::Reroute(node, target_node) => (node, target_node),
+            _ => unreachable!(),
+        };
+        let estimated_costs = instance.estimate_reroute_costs(
+            node,
+            target_node,
+            &current_vertex_flows,
+            current_costs,
+            &cost_model,
+        );
+
+        // the estimation is exact if the intermediate vertices lie evenly on the straight line
+        let mut solution = instance.solution_state.current_solution.clone();
+        solution.base_graph.reroute(node, target_node);
+        let embeddings = &mut solution.vertices_embeddings.embeddings;
+        let start = embeddings[node.layer][node.index];
+        let end = embeddings[target_node.layer][target_node.index];
+        let number_of_edges = (target_node.layer - node.layer) as f64;
+        let intermediate_layers = embeddings[node.layer + 1..target_node.layer].iter_mut();
+        for (edge_index, layer_embeddings) in intermediate_layers.enumerate() {
+            let fraction = (edge_index + 1) as f64 / number_of_edges;
+            layer_embeddings.push((
+                start.0 + fraction * (end.0 - start.0),
+                start.1 + fraction * (end.1 - start.1),
+            ));
+        }
+        let costs = solution.calculate_costs_with_cost_model(&cost_model);
+        assert!((estimated_costs - costs).abs() < EPSILON * costs.max(1.0));
+    }
+}
+
+#[test]
+fn test_reroute_operation1() {
+    process_reroute_operation(4, &vec![1, 2, 4, 8], 40, 5);
+}
+
+#[test]
+fn test_reroute_operation2() {
+    process_reroute_operation(5, &vec![1, 4, 8, 10, 20], 400, 20);
+}
+
+#[test]
+fn test_reroute_operation3() {
+    process_reroute_operation(6, &vec![1, 2, 4, 8, 16, 50], 200, 4);
+}
+
+#[test]
+fn test_reroute_neighbors1() {
+    process_reroute_neighbors(5, 60, 3, false);
+}
+
+#[test]
+fn test_reroute_neighbors2() {
+    process_reroute_neighbors(6, 200, 5, true);
+}
@@ -2,7 +2,9 @@
 use rand::{seq::SliceRandom, Rng};
 
 #[cfg(test)]
-use crate::{graph_generation::generate_random_flamecast_graph, LayeredGraph, VertexID};
+use crate::{
+    graph_generation::generate_random_flamecast_graph, LayeredGraph, MaxChildren, VertexID,
+};
 
 #[cfg(test)]
 fn process_split_operation(
@@ -18,6 +20,7 @@ fn process_split_operation(
     let mut graph = generate_random_flamecast_graph(
         num_layers,
         capacities,
+        &MaxChildren::default(),
         num_sources,
         num_drains,
         &sources_embeddings,
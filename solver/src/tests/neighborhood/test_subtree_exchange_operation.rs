@@ -0,0 +1,172 @@
+#[cfg(test)]
+use rand::Rng;
+
+#[cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_graph, vertices_equal},
+    MaxChildren, VertexID,
+};
+
+#[cfg(test)]
+fn process_subtree_exchange_operation(
+    num_layers: usize,
+    capacities: &Vec<usize>,
+    num_sources: usize,
+    num_drains: usize,
+) {
+    let mut graph = create_random_flamecast_graph(num_layers, capacities, num_sources, num_drains);
+    let graph_copy = graph.clone();
+
+    let mut rng = rand::thread_rng();
+    let random_layer = rng.gen_range(1..num_layers - 1);
+    let random_node1 = rng.gen_range(0..graph.layers[random_layer].vertices.len());
+    let node1_id = VertexID::new(random_layer, random_node1);
+
+    let parent1 = graph.get_parent(&node1_id).unwrap();
+
+    let random_node2 = graph.layers[random_layer]
+        .vertices
+        .iter()
+        .position(|x| x.parent_index.unwrap() != parent1.index)
+        .unwrap();
+
+    let node2_id = VertexID::new(random_layer, random_node2);
+    assert!(graph.check_subtree_exchange_possible(
+        &node1_id,
+        &node2_id,
+        &vec![usize::MAX; num_layers],
+        &MaxChildren::default(),
+        &graph.calculate_vertex_flows(),
+    ));
+
+    let parent2 = graph.get_parent(&node2_id).unwrap();
+
+    let children1 = graph.get_children(&node1_id).unwrap();
+    let children2 = graph.get_children(&node2_id).unwrap();
+
+    graph.exchange_subtrees(&node1_id, &node2_id);
+
+    assert!(graph.get_parent(&node1_id).unwrap() == parent1);
+    assert!(graph.get_parent(&node2_id).unwrap() == parent2);
+
+    let new_children1 = graph.get_children(&node1_id).unwrap();
+    let new_children2 = graph.get_children(&node2_id).unwrap();
+
+    assert!(new_children1.len() == children2.len());
+    assert!(new_children2.len() == children1.len());
+
+    children1.iter().for_each(|child| {
+        assert!(graph.get_parent(child).unwrap() == node2_id);
+        assert!(new_children2.contains(child));
+    });
+    children2.iter().for_each(|child| {
+        assert!(graph.get_parent(child).unwrap() == node1_id);
+        assert!(new_children1.contains(child));
+    });
+
+    graph.undo_exchange_subtrees(&node1_id, &node2_id);
+
+    assert!(vertices_equal(
+        graph.get_vertex(&node1_id),
+        graph_copy.get_vertex(&node1_id)
+    ));
+
+    assert!(vertices_equal(
+        graph.get_vertex(&node2_id),
+        graph_copy.get_vertex(&node2_id)
+    ));
+
+    children1.iter().chain(children2.iter()).for_each(|child| {
+        assert!(vertices_equal(
+            graph.get_vertex(child),
+            graph_copy.get_vertex(child)
+        ));
+    });
+}
+
+#[test]
+fn test_subtree_exchange_possible() {
+    let num_layers = 4;
+    let capacities = vec![1, 8, 10, 20];
+    let graph = create_random_flamecast_graph(num_layers, &capacities, 200, 20);
+    let vertex_flows = graph.calculate_vertex_flows();
+
+    let node1_id = VertexID::new(2, 0);
+    let parent1 = graph.get_parent(&node1_id).unwrap();
+    let sibling_index = graph.layers[2]
+        .vertices
+        .iter()
+        .enumerate()
+        .position(|(index, vertex)| index != 0 && vertex.parent_index.unwrap() == parent1.index);
+    let node2_index = graph.layers[2]
+        .vertices
+        .iter()
+        .position(|vertex| vertex.parent_index.unwrap() != parent1.index)
+        .unwrap();
+    let node2_id = VertexID::new(2, node2_index);
+
+    // the exchanged flows change the paths up to the common ancestor like a swap
+    assert_eq!(
+        graph.check_subtree_exchange_possible(
+            &node1_id,
+            &node2_id,
+            &capacities,
+            &MaxChildren::default(),
+            &vertex_flows,
+        ),
+        graph.check_swap_possible(&node1_id, &node2_id, &capacities, &vertex_flows)
+    );
+
+    // the vertices need different parents and have to be inner vertices
+    if let Some(sibling_index) = sibling_index {
+        assert!(!graph.check_subtree_exchange_possible(
+            &node1_id,
+            &VertexID::new(2, sibling_index),
+            &capacities,
+            &MaxChildren::default(),
+            &vertex_flows,
+        ));
+    }
+    assert!(!graph.check_subtree_exchange_possible(
+        &VertexID::new(3, 0),
+        &VertexID::new(3, 1),
+        &capacities,
+        &MaxChildren::default(),
+        &vertex_flows,
+    ));
+
+    // a vertex cannot take more children than the max children of its layer allow
+    let number_of_children = graph
+        .get_vertex(&node1_id)
+        .get_number_of_children()
+        .max(graph.get_vertex(&node2_id).get_number_of_children());
+    let mut layer_max_children = vec![usize::MAX; num_layers];
+    layer_max_children[2] = number_of_children - 1;
+    assert!(!graph.check_subtree_exchange_possible(
+        &node1_id,
+        &node2_id,
+        &vec![usize::MAX; num_layers],
+        &MaxChildren::new(layer_max_children),
+        &vertex_flows,
+    ));
+}
+
+#[test]
+fn test_subtree_exchange_operation1() {
+    process_subtree_exchange_operation(3, &vec![1, 2, 4], 20, 5);
+}
+
+#[test]
+fn test_subtree_exchange_operation2() {
+    process_subtree_exchange_operation(3, &vec![1, 2, 2], 4, 2);
+}
+
+#[test]
+fn test_subtree_exchange_operation3() {
+    process_subtree_exchange_operation(5, &vec![1, 4, 8, 10, 20], 400, 20);
+}
+
+#[test]
+fn test_subtree_exchange_operation4() {
+    process_subtree_exchange_operation(4, &vec![1, 8, 10, 20], 200, 20);
+}
@@ -2,7 +2,9 @@
 use rand::Rng;
 
 #[cfg(test)]
-use crate::VertexEmbedding;
+use crate::{
+    graph_generation::generate_random_flamecast_graph, LayeredGraph, MaxChildren, VertexEmbedding,
+};
 
 #[cfg(test)]
 pub fn create_random_source_embeddings(sources_size: usize) -> Vec<VertexEmbedding> {
@@ -18,3 +20,22 @@ pub fn create_random_source_embeddings(sources_size: usize) -> Vec<VertexEmbeddi
 
     return sources_embeddings;
 }
+
+#[cfg(test)]
+pub fn create_random_flamecast_graph(
+    num_layers: usize,
+    capacities: &Vec<usize>,
+    num_sources: usize,
+    num_drains: usize,
+) -> LayeredGraph {
+    let sources_embeddings = create_random_source_embeddings(num_sources);
+
+    return generate_random_flamecast_graph(
+        num_layers,
+        capacities,
+        &MaxChildren::default(),
+        num_sources,
+        num_drains,
+        &sources_embeddings,
+    );
+}
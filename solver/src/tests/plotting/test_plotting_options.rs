@@ -0,0 +1,119 @@
+#![cfg(test)]
+use std::{
+    env::temp_dir,
+    fs::{read_to_string, remove_file},
+    path::PathBuf,
+};
+
+use crate::{
+    plotting::calculate_layer_colors, tests::create_random_flamecast_instance,
+    InitialSolutionFunction, PlotFormat, PlottingOptions, PlottingVertices,
+};
+
+#[cfg(test)]
+fn get_temp_file_path(name: &str, format: PlotFormat) -> PathBuf {
+    let extension = match format {
+        PlotFormat::Png => "png",
+        PlotFormat::Svg => "svg",
+    };
+    return temp_dir().join(format!("{}_{}.{}", name, rand::random::<u64>(), extension));
+}
+
+#[cfg(test)]
+fn process_plot_current_solution(num_layers: usize, options: PlottingOptions) -> String {
+    let instance =
+        create_random_flamecast_instance(num_layers, 15, 3, None, InitialSolutionFunction::Random);
+    let file_path = get_temp_file_path("test_plotting_options", options.format);
+
+    instance.plot_current_solution_with_options(file_path.to_str().unwrap(), &options);
+
+    assert!(file_path.metadata().unwrap().len() > 0);
+    let content = if options.format == PlotFormat::Svg {
+        read_to_string(&file_path).unwrap()
+    } else {
+        String::new()
+    };
+    remove_file(&file_path).unwrap();
+
+    return content;
+}
+
+#[test]
+fn test_plot_png() {
+    process_plot_current_solution(5, PlottingOptions::default());
+}
+
+#[test]
+fn test_plot_png_resolution() {
+    let mut options = PlottingOptions::default();
+    options.width = 640;
+    options.height = 480;
+    options.show_scale_bar = true;
+    options.show_indices = true;
+    process_plot_current_solution(4, options);
+}
+
+#[test]
+fn test_plot_svg() {
+    let mut options = PlottingOptions::default();
+    options.format = PlotFormat::Svg;
+    let content = process_plot_current_solution(5, options);
+
+    assert!(content.contains("<svg"));
+    for layer_index in 0..5 {
+        assert!(content.contains(&format!("Layer {}", layer_index)));
+    }
+}
+
+#[test]
+fn test_plot_svg_plain() {
+    let mut options = PlottingOptions::default();
+    options.format = PlotFormat::Svg;
+    options.width = 800;
+    options.height = 800;
+    options.show_layers = false;
+    options.scale_edges_by_flow = false;
+    options.scale_nodes_by_flow = false;
+    options.show_legend = false;
+    options.show_axes = false;
+    let content = process_plot_current_solution(4, options);
+
+    assert!(content.contains("<svg"));
+    assert!(!content.contains("Layer 0"));
+}
+
+#[test]
+fn test_plot_vertices() {
+    let instance =
+        create_random_flamecast_instance(3, 15, 3, None, InitialSolutionFunction::Random);
+    let embeddings = &instance.sources_drains_embeddings.embeddings;
+    let plotting_vertices = vec![
+        PlottingVertices::new(embeddings[0].clone(), plotters::style::RED),
+        PlottingVertices::new(embeddings[2].clone(), plotters::style::BLUE),
+    ];
+
+    let mut options = PlottingOptions::default();
+    options.format = PlotFormat::Svg;
+    let file_path = get_temp_file_path("test_plot_vertices", options.format);
+    crate::plot_vertices_with_options(file_path.to_str().unwrap(), plotting_vertices, &options);
+
+    let content = read_to_string(&file_path).unwrap();
+    assert!(content.contains("Group 1"));
+    remove_file(&file_path).unwrap();
+}
+
+#[test]
+fn test_layer_colors() {
+    for num_layers in [3, 40, 41, 100] {
+        let colors = calculate_layer_colors(num_layers, true);
+        assert_eq!(colors.len(), num_layers);
+        for i in 0..num_layers {
+            for j in (i + 1)..num_layers {
+                assert!(colors[i] != colors[j]);
+            }
+        }
+    }
+
+    let colors = calculate_layer_colors(50, false);
+    assert!(colors.iter().all(|color| *color == plotters::style::BLACK));
+}
@@ -0,0 +1,2 @@
+mod test_schema;
+mod test_verification;
@@ -0,0 +1,233 @@
+#![cfg(test)]
+use serde_json::json;
+
+use crate::{
+    tests::{create_random_flamecast_instance, generate_random_flamecast_instance, EPSILON},
+    CostModel, FlamecastInstanceFile, FlamecastSolutionFile, InitialSolutionFunction,
+    OptimizationOptions, VertexEmbedding, INSTANCE_SCHEMA_VERSION, SOLUTION_SCHEMA_VERSION,
+};
+
+#[cfg(test)]
+fn assert_embeddings_close(left: &Vec<VertexEmbedding>, right: &Vec<VertexEmbedding>) {
+    // json parsing may change the last digit of the coordinates
+    assert_eq!(left.len(), right.len());
+    for (left, right) in left.iter().zip(right) {
+        assert!((left.0 - right.0).abs() < EPSILON);
+        assert!((left.1 - right.1).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn test_instance_file_roundtrip() {
+    let test_instance = generate_random_flamecast_instance(5, 12, 3, false);
+    let instance_file = FlamecastInstanceFile::from_test_instance(&test_instance);
+    assert_eq!(instance_file.version, INSTANCE_SCHEMA_VERSION);
+
+    let loaded = FlamecastInstanceFile::from_json(&instance_file.to_json(), None).unwrap();
+    let loaded_instance = loaded.to_test_instance();
+    assert!((loaded_instance.alpha - test_instance.alpha).abs() < EPSILON);
+    assert_eq!(loaded_instance.num_layers, test_instance.num_layers);
+    assert_eq!(loaded_instance.capacities, test_instance.capacities);
+    let loaded_embeddings = &loaded_instance.sources_drains_embeddings.embeddings;
+    let original_embeddings = &test_instance.sources_drains_embeddings.embeddings;
+    assert_eq!(loaded_embeddings.len(), original_embeddings.len());
+    for (loaded_layer, original_layer) in loaded_embeddings.iter().zip(original_embeddings) {
+        assert_embeddings_close(loaded_layer, original_layer);
+    }
+}
+
+#[test]
+fn test_migrate_test_instance() {
+    let test_instance = generate_random_flamecast_instance(4, 8, 2, false);
+    let content = serde_json::to_string(&test_instance).unwrap();
+
+    let migrated = FlamecastInstanceFile::from_json(&content, None).unwrap();
+    assert!((migrated.alpha - test_instance.alpha).abs() < EPSILON);
+    assert_eq!(migrated.capacities, test_instance.capacities);
+    assert_embeddings_close(
+        &migrated.sources,
+        &test_instance.sources_drains_embeddings.embeddings[0],
+    );
+    assert_embeddings_close(
+        &migrated.drains,
+        &test_instance.sources_drains_embeddings.embeddings[3],
+    );
+}
+
+#[test]
+fn test_migrate_base_instance() {
+    let content = json!({
+        "sources": [[0.1, 0.2], [0.3, 0.4], [0.5, 0.6]],
+        "drains": [[0.9, 0.9]],
+        "layers": 3,
+        "capacities": [1, 2, 3],
+    })
+    .to_string();
+
+    // base instances have no alpha
+    assert!(FlamecastInstanceFile::from_json(&content, None).is_err());
+
+    let migrated = FlamecastInstanceFile::from_json(&content, Some(0.3)).unwrap();
+    assert_eq!(migrated.alpha, 0.3);
+    assert_eq!(migrated.num_layers, 3);
+    assert_eq!(migrated.capacities, vec![1, 2, 3]);
+    assert_eq!(migrated.sources, vec![(0.1, 0.2), (0.3, 0.4), (0.5, 0.6)]);
+    assert_eq!(migrated.drains, vec![(0.9, 0.9)]);
+}
+
+#[test]
+fn test_migrate_circle_instance() {
+    let content = json!({
+        "sources": [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]],
+        "sinks": [[0.0, 0.0]],
+        "layers": 4,
+        "capacities": [2, null, null],
+    })
+    .to_string();
+
+    let migrated = FlamecastInstanceFile::from_json(&content, Some(0.5)).unwrap();
+    assert_eq!(migrated.capacities, vec![1, 2, 4, 4]);
+    assert_eq!(migrated.sources.len(), 4);
+    assert_eq!(migrated.drains, vec![(0.0, 0.0)]);
+
+    let instance = migrated
+        .to_flamecast_instance(InitialSolutionFunction::Random)
+        .unwrap();
+    assert_eq!(instance.get_number_of_sources(), 4);
+    assert_eq!(instance.get_number_of_drains(), 1);
+}
+
+#[test]
+fn test_migrate_flamecast_instance() {
+    let mut instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+    let mut options = OptimizationOptions::default();
+    options.max_iterations = 10;
+    options.verbose = false;
+    instance.solve(options);
+    let content = serde_json::to_string(&instance).unwrap();
+
+    let migrated_instance = FlamecastInstanceFile::from_json(&content, None).unwrap();
+    assert!((migrated_instance.alpha - instance.alpha).abs() < EPSILON);
+    assert_eq!(migrated_instance.capacities, instance.capacities);
+
+    let migrated_solution = FlamecastSolutionFile::from_json(&content).unwrap();
+    assert_eq!(
+        migrated_solution.solution.base_graph,
+        instance.solution_state.current_solution.base_graph
+    );
+    assert_eq!(
+        migrated_solution.logger.unwrap().accepted_neighbors.len(),
+        instance.logger.accepted_neighbors.len()
+    );
+
+    // the logger of an unsolved instance can not be stored and is dropped
+    let unsolved_instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+    let content = serde_json::to_string(&unsolved_instance).unwrap();
+    let migrated_solution = FlamecastSolutionFile::from_json(&content).unwrap();
+    assert!(migrated_solution.logger.is_none());
+    // fields that cannot be read are reported instead of being replaced by their default
+    let mut value = serde_json::to_value(&unsolved_instance).unwrap();
+    value["robustness_penalty"] = json!("high");
+    let result = FlamecastInstanceFile::from_json(&value.to_string(), None);
+    assert!(result.unwrap_err().contains("robustness_penalty"));
+}
+
+#[test]
+fn test_migrate_version_1_instance() {
+    // version 1 files only contain the fields of the single alpha model
+    let content = json!({
+        "schema": "flamecast-instance",
+        "version": 1,
+        "alpha": 0.5,
+        "num_layers": 3,
+        "capacities": [1, 2, 2],
+        "sources": [[0.0, 0.0], [1.0, 0.0]],
+        "drains": [[0.5, 1.0]],
+    })
+    .to_string();
+
+    let migrated = FlamecastInstanceFile::from_json(&content, None).unwrap();
+    assert_eq!(migrated.version, INSTANCE_SCHEMA_VERSION);
+    assert_eq!(migrated.get_cost_model(), CostModel::from_alpha(0.5));
+    assert!(migrated.max_children.is_unlimited());
+
+    // the instance of a version 1 solution is migrated as well
+    let instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+    let mut solution_value = serde_json::to_value(FlamecastSolutionFile::from_flamecast_instance(
+        &instance, false,
+    ))
+    .unwrap();
+    solution_value["version"] = json!(1);
+    solution_value["instance"]["version"] = json!(1);
+    let migrated = FlamecastSolutionFile::from_json(&solution_value.to_string()).unwrap();
+    assert_eq!(migrated.version, SOLUTION_SCHEMA_VERSION);
+    assert_eq!(migrated.instance.version, INSTANCE_SCHEMA_VERSION);
+
+    // unknown fields are rejected instead of being dropped
+    let mut instance_value = serde_json::from_str::<serde_json::Value>(&content).unwrap();
+    instance_value["version"] = json!(INSTANCE_SCHEMA_VERSION);
+    instance_value["unknown_cost"] = json!(1.0);
+    assert!(FlamecastInstanceFile::from_json(&instance_value.to_string(), None).is_err());
+}
+
+#[test]
+fn test_solution_file_roundtrip() {
+    let instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    assert_eq!(
+        solution_file.objective_value,
+        instance.get_objective_function_value()
+    );
+
+    let loaded = FlamecastSolutionFile::from_json(&solution_file.to_json()).unwrap();
+    assert!(loaded.logger.is_none());
+
+    let loaded_instance = loaded.to_flamecast_instance();
+    assert_eq!(
+        loaded_instance.solution_state.current_solution.base_graph,
+        instance.solution_state.current_solution.base_graph
+    );
+    assert_eq!(loaded_instance.capacities, instance.capacities);
+    let loaded_embeddings = &loaded_instance.sources_drains_embeddings.embeddings;
+    let original_embeddings = &instance.sources_drains_embeddings.embeddings;
+    assert_eq!(loaded_embeddings.len(), original_embeddings.len());
+    for (loaded_layer, original_layer) in loaded_embeddings.iter().zip(original_embeddings) {
+        assert_embeddings_close(loaded_layer, original_layer);
+    }
+}
+
+#[test]
+fn test_schema_errors() {
+    let instance_file =
+        FlamecastInstanceFile::new(0.5, 3, vec![1, 2, 2], vec![(0.0, 0.0)], vec![(1.0, 1.0)]);
+
+    let mut newer_version = instance_file.clone();
+    newer_version.version = INSTANCE_SCHEMA_VERSION + 1;
+    assert!(FlamecastInstanceFile::from_json(&newer_version.to_json(), None).is_err());
+
+    let mut wrong_schema = instance_file.clone();
+    wrong_schema.schema = String::from("flamecast-solution");
+    assert!(FlamecastInstanceFile::from_json(&wrong_schema.to_json(), None).is_err());
+
+    let mut wrong_capacities = instance_file.clone();
+    wrong_capacities.capacities = vec![1, 2];
+    assert!(FlamecastInstanceFile::from_json(&wrong_capacities.to_json(), None).is_err());
+
+    let mut wrong_alpha = instance_file.clone();
+    wrong_alpha.alpha = 1.5;
+    assert!(FlamecastInstanceFile::from_json(&wrong_alpha.to_json(), None).is_err());
+
+    assert!(FlamecastInstanceFile::from_json("{\"foo\": 1}", Some(0.5)).is_err());
+    assert!(FlamecastSolutionFile::from_json(&instance_file.to_json()).is_err());
+
+    // a solution that does not fit the instance is rejected
+    let instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+    let mut solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    solution_file.instance.drains.push((0.5, 0.5));
+    assert!(FlamecastSolutionFile::from_json(&solution_file.to_json()).is_err());
+}
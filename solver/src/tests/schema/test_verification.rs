@@ -0,0 +1,185 @@
+#![cfg(test)]
+use rand::Rng;
+
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    DistanceMetric, EdgeCostFunction, FixedVariableEdgeCost, FlamecastInstance,
+    FlamecastInstanceFile, FlamecastSolutionFile, InitialSolutionFunction, LogarithmicEdgeCost,
+    OptimizationOptions, PiecewiseLinearEdgeCost, SearchDepth, VertexEmbeddings,
+};
+
+#[cfg(test)]
+fn create_solved_instance() -> FlamecastInstance {
+    let mut instance = create_random_flamecast_instance(
+        4,
+        10,
+        2,
+        Some(vec![1, 4, 8, 10]),
+        InitialSolutionFunction::Random,
+    );
+    let mut options = OptimizationOptions::default();
+    options.max_iterations = 3;
+    options.verbose = false;
+    instance.solve(options);
+    return instance;
+}
+
+#[test]
+fn test_verify_valid_solution() {
+    let instance = create_solved_instance();
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, true);
+
+    let report = solution_file.verify(&instance_file, None);
+    assert!(report.instance_matches);
+    assert!(report.is_verified());
+    assert!(
+        (report.recomputed_objective_value.unwrap() - instance.get_objective_function_value())
+            .abs()
+            < EPSILON
+    );
+    assert!(report.reembedded_objective_value.is_none());
+
+    // the stored embedding cannot be improved by a deeper search
+    let report = solution_file.verify(&instance_file, Some(SearchDepth::Deep));
+    assert!(report.is_verified());
+    assert!(report.reembedded_objective_value.is_some());
+
+    // storing and loading the solution does not change the verification
+    let loaded = FlamecastSolutionFile::from_json(&solution_file.to_json()).unwrap();
+    assert!(loaded.verify(&instance_file, None).is_verified());
+}
+
+#[test]
+fn test_verify_cost_mismatches() {
+    let instance = create_solved_instance();
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let mut solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+    solution_file.objective_value += 1.0;
+
+    let report = solution_file.verify(&instance_file, None);
+    assert!(report.feasibility_errors.is_empty());
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].quantity, "stored objective value");
+    assert!(
+        (report.mismatches[0].reported_value - report.mismatches[0].recomputed_value - 1.0).abs()
+            < EPSILON
+    );
+
+    // the solution is scored with the cost model of the given instance
+    let mut other_instance_file = instance_file.clone();
+    other_instance_file.alpha += 0.5;
+    solution_file.objective_value -= 1.0;
+    let report = solution_file.verify(&other_instance_file, None);
+    assert!(!report.instance_matches);
+    assert!(report
+        .mismatches
+        .iter()
+        .any(|mismatch| mismatch.quantity == "stored objective value"));
+
+    // a solution of another instance is not verified, even with the same costs
+    let mut larger_instance_file = instance_file.clone();
+    larger_instance_file.capacities = vec![1, 10, 10, 10];
+    let report = solution_file.verify(&larger_instance_file, None);
+    assert!(report.feasibility_errors.is_empty());
+    assert!(report.mismatches.is_empty());
+    assert!(!report.is_verified());
+}
+
+#[test]
+fn test_verify_cost_models() {
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&create_solved_instance());
+    let mut instance_files = Vec::new();
+    for metric in [DistanceMetric::Manhattan, DistanceMetric::Lp(3.0)] {
+        let mut other_instance_file = instance_file.clone();
+        other_instance_file.metric = metric;
+        instance_files.push(other_instance_file);
+    }
+    for edge_cost_function in [
+        EdgeCostFunction::PiecewiseLinear(
+            PiecewiseLinearEdgeCost::new(vec![(2.0, 3.0), (5.0, 5.0)]).unwrap(),
+        ),
+        EdgeCostFunction::FixedVariable(FixedVariableEdgeCost::new(1.0, 0.5)),
+        EdgeCostFunction::Logarithmic(LogarithmicEdgeCost::new(2.0)),
+    ] {
+        let mut other_instance_file = instance_file.clone();
+        other_instance_file.edge_cost_function = edge_cost_function;
+        instance_files.push(other_instance_file);
+    }
+    let mut other_instance_file = instance_file.clone();
+    other_instance_file.layer_alphas = vec![0.2, 0.5, 0.8];
+    other_instance_file.layer_cost_multipliers = vec![1.0, 2.0, 3.0];
+    other_instance_file.vertex_opening_costs = vec![0.0, 0.5, 0.2, 1.0];
+    other_instance_file.robustness_penalty = 0.1;
+    instance_files.push(other_instance_file);
+
+    // random points in a region of roughly 70 km around berlin
+    let mut rng = rand::thread_rng();
+    let mut random_point = || (rng.gen_range(12.9..13.9), rng.gen_range(52.2..52.8));
+    let sources = (0..10).map(|_| random_point()).collect();
+    let drains = (0..2).map(|_| random_point()).collect();
+    instance_files.push(FlamecastInstanceFile::from_flamecast_instance(
+        &FlamecastInstance::new_geographic(
+            0.5,
+            4,
+            vec![1, 4, 8, 10],
+            VertexEmbeddings::from(vec![sources, vec![], vec![], drains]),
+            InitialSolutionFunction::Random,
+        ),
+    ));
+
+    // the independently recomputed costs agree with the costs of the library
+    for instance_file in instance_files {
+        let instance = instance_file
+            .to_flamecast_instance(InitialSolutionFunction::Random)
+            .unwrap();
+        let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+        let report = solution_file.verify(&instance_file, None);
+        assert!(report.is_verified());
+        assert!(
+            (report.recomputed_objective_value.unwrap() - instance.get_objective_function_value())
+                .abs()
+                < EPSILON * instance.get_objective_function_value().max(1.0)
+        );
+    }
+}
+
+#[test]
+fn test_verify_infeasible_solutions() {
+    let instance = create_solved_instance();
+    let instance_file = FlamecastInstanceFile::from_flamecast_instance(&instance);
+    let solution_file = FlamecastSolutionFile::from_flamecast_instance(&instance, false);
+
+    // every vertex above the capacity of the given instance is reported
+    let mut smaller_instance_file = instance_file.clone();
+    smaller_instance_file.capacities = vec![1, 1, 8, 10];
+    let report = solution_file.verify(&smaller_instance_file, None);
+    assert!(!report.is_verified());
+    let number_of_full_vertices = solution_file.solution.base_graph.calculate_vertex_flows()[1]
+        .iter()
+        .filter(|flow| **flow > 1)
+        .count();
+    assert_eq!(report.feasibility_errors.len(), number_of_full_vertices);
+
+    // moved sources are reported, the costs are recomputed with the sources of the instance
+    let mut moved_solution_file = solution_file.clone();
+    moved_solution_file.solution.vertices_embeddings.embeddings[0][0].0 += 1.0;
+    let report = moved_solution_file.verify(&instance_file, None);
+    assert_eq!(report.feasibility_errors.len(), 1);
+    assert!(
+        (report.recomputed_objective_value.unwrap() - instance.get_objective_function_value())
+            .abs()
+            < EPSILON
+    );
+    assert!(report
+        .mismatches
+        .iter()
+        .any(|mismatch| mismatch.quantity == "library objective value"));
+
+    // a broken parent pointer stops the verification before the costs are recomputed
+    let mut broken_solution_file = solution_file.clone();
+    broken_solution_file.solution.base_graph.layers[0].vertices[0].parent_index = Some(100);
+    let report = broken_solution_file.verify(&instance_file, None);
+    assert_eq!(report.feasibility_errors.len(), 1);
+    assert!(report.recomputed_objective_value.is_none());
+}
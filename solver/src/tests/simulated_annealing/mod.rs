@@ -0,0 +1,7 @@
+mod test_alpha_sweep;
+mod test_annealing_run_plot;
+mod test_layer_count_search;
+mod test_pareto_archive;
+mod test_progress;
+mod test_solution_history;
+mod test_source_updates;
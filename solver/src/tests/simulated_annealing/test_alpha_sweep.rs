@@ -0,0 +1,89 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    EmbeddingOptions, InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+fn get_sweep_options(_init_objective_value: f64, _num_vertices: usize) -> OptimizationOptions {
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 10;
+    options.verbose = false;
+    return options;
+}
+
+#[test]
+fn test_alpha_sweep() {
+    let mut instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Matching);
+    let alphas = vec![0.9, 0.5, 0.1];
+    let alpha_sweep = instance
+        .solve_alpha_sweep(&alphas, get_sweep_options)
+        .unwrap();
+
+    assert_eq!(alpha_sweep.get_alphas(), alphas);
+    assert_eq!(alpha_sweep.get_cost_curve().len(), alphas.len());
+    assert_eq!(instance.alpha, 0.1);
+
+    for (index, point) in alpha_sweep.points.iter().enumerate() {
+        // every point is the best solution of its alpha
+        let costs = point.solution.calculate_costs(point.alpha);
+        assert!((costs - point.objective_value).abs() < EPSILON * costs.max(1.0));
+        assert!(point.objective_value <= point.logger.initial_objective_value + EPSILON);
+        assert!(
+            (point.statistics.objective_value - point.objective_value).abs()
+                < EPSILON * costs.max(1.0)
+        );
+        assert!(point
+            .solution
+            .base_graph
+            .is_valid_flamecast_topology_check_all(
+                &instance.capacities,
+                instance.get_number_of_sources(),
+                instance.get_number_of_drains(),
+                instance.num_layers,
+            ));
+
+        // every solve is warm started from the best topology of the previous alpha
+        if index > 0 {
+            assert_eq!(
+                point.logger.initial_solution.base_graph,
+                alpha_sweep.points[index - 1].solution.base_graph
+            );
+        }
+    }
+
+    // for smaller alphas the bundling is cheaper, so the costs can only decrease
+    assert!(alpha_sweep
+        .get_objective_values()
+        .windows(2)
+        .all(|pair| pair[1] <= pair[0] + EPSILON * pair[0].max(1.0)));
+}
+
+#[test]
+fn test_alpha_sweep_invalid_input() {
+    let mut instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Matching);
+    let alpha = instance.alpha;
+    assert!(instance
+        .solve_alpha_sweep(&Vec::new(), get_sweep_options)
+        .is_err());
+
+    // no alpha outside of [0, 1] is solved
+    for invalid_alpha in [1.5, -0.1, f64::NAN] {
+        assert!(instance
+            .solve_alpha_sweep(&vec![0.5, invalid_alpha], get_sweep_options)
+            .is_err());
+        assert!(instance
+            .set_alpha(invalid_alpha, &EmbeddingOptions::default())
+            .is_err());
+    }
+    assert_eq!(instance.alpha, alpha);
+
+    // a sweep cannot change the alpha of single layers
+    instance.layer_alphas = vec![0.5; instance.num_layers];
+    assert!(instance
+        .solve_alpha_sweep(&vec![0.5], get_sweep_options)
+        .is_err());
+}
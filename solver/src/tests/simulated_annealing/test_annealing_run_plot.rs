@@ -0,0 +1,62 @@
+#![cfg(test)]
+use std::{env::temp_dir, fs::remove_dir_all, path::Path};
+
+use crate::{
+    tests::create_random_flamecast_instance, AnimationFormat, AnimationOptions, FlamecastInstance,
+    InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+fn solve_random_instance() -> FlamecastInstance {
+    let mut flamecast_instance =
+        create_random_flamecast_instance(4, 8, 2, None, InitialSolutionFunction::Random);
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 10;
+    options.verbose = false;
+    flamecast_instance.solve(options);
+
+    return flamecast_instance;
+}
+
+#[test]
+fn test_plot_annealing_run_frames() {
+    let instance = solve_random_instance();
+
+    let directory = temp_dir().join(format!(
+        "test_annealing_run_frames_{}",
+        rand::random::<u64>()
+    ));
+    let mut options = AnimationOptions::default();
+    options.format = AnimationFormat::FrameSequence;
+    instance.plot_solving_run(directory.to_str().unwrap(), &options);
+
+    // one frame for the initial solution and one for every accepted neighbor
+    let number_frames = instance.logger.accepted_neighbors.len() + 1;
+    for frame_index in 0..number_frames {
+        assert!(directory
+            .join(format!("frame_{:05}.png", frame_index))
+            .exists());
+    }
+    assert!(!directory
+        .join(format!("frame_{:05}.png", number_frames))
+        .exists());
+
+    remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_plot_annealing_run_gif() {
+    let instance = solve_random_instance();
+
+    let file_path = temp_dir().join(format!("test_annealing_run_{}.gif", rand::random::<u64>()));
+    let mut options = AnimationOptions::default();
+    options.format = AnimationFormat::Gif(200);
+    options.show_indices = true;
+    instance.plot_solving_run(file_path.to_str().unwrap(), &options);
+
+    assert!(Path::new(&file_path).metadata().unwrap().len() > 0);
+
+    std::fs::remove_file(&file_path).unwrap();
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+fn get_search_options(_init_objective_value: f64, _num_vertices: usize) -> OptimizationOptions {
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 10;
+    options.verbose = false;
+    return options;
+}
+
+#[test]
+fn test_layer_count_search() {
+    let instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Matching);
+    let drain_capacity = instance.capacities[instance.num_layers - 1];
+    let candidate_capacities = (3..=5)
+        .map(|num_layers| {
+            let mut capacities = vec![drain_capacity; num_layers];
+            capacities[0] = 1;
+            capacities
+        })
+        .collect::<Vec<Vec<usize>>>();
+
+    let search = instance
+        .solve_layer_counts(
+            &candidate_capacities,
+            InitialSolutionFunction::Matching,
+            get_search_options,
+        )
+        .unwrap();
+    assert_eq!(search.get_layer_counts(), vec![3, 4, 5]);
+
+    for (candidate, capacities) in search.candidates.iter().zip(candidate_capacities.iter()) {
+        assert!(candidate
+            .solution
+            .base_graph
+            .is_valid_flamecast_topology_check_all(
+                capacities,
+                instance.get_number_of_sources(),
+                instance.get_number_of_drains(),
+                candidate.num_layers,
+            ));
+        let costs = candidate.solution.calculate_costs(instance.alpha);
+        assert!((costs - candidate.objective_value).abs() < EPSILON * costs.max(1.0));
+    }
+
+    // the best instance is the cheapest candidate
+    let best_objective_value = search
+        .get_objective_values()
+        .into_iter()
+        .fold(f64::INFINITY, f64::min);
+    assert!(
+        (search.best_instance.get_objective_function_value() - best_objective_value).abs()
+            < EPSILON * best_objective_value.max(1.0)
+    );
+    let best_candidate = search
+        .candidates
+        .iter()
+        .find(|candidate| candidate.objective_value == best_objective_value)
+        .unwrap();
+    assert_eq!(search.get_best_num_layers(), best_candidate.num_layers);
+}
+
+#[test]
+fn test_layer_count_search_invalid() {
+    let mut instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Matching);
+    assert!(instance
+        .solve_layer_counts(
+            &Vec::new(),
+            InitialSolutionFunction::Matching,
+            get_search_options
+        )
+        .is_err());
+
+    // an instance needs at least two layers
+    assert!(instance
+        .solve_layer_counts(
+            &vec![vec![1]],
+            InitialSolutionFunction::Matching,
+            get_search_options
+        )
+        .is_err());
+
+    instance.layer_alphas = vec![instance.alpha; instance.num_layers - 1];
+    assert!(instance
+        .solve_layer_counts(
+            &vec![instance.capacities.clone()],
+            InitialSolutionFunction::Matching,
+            get_search_options
+        )
+        .is_err());
+}
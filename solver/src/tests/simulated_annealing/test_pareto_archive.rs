@@ -0,0 +1,140 @@
+#![cfg(test)]
+use std::{env::temp_dir, fs::remove_file};
+
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    CostModel, FlamecastInstance, InitialSolutionFunction, MultiObjectiveOption,
+    NeighborSearchOption, OptimizationOptions, ParetoArchive, ParetoPoint,
+};
+
+#[cfg(test)]
+fn solve_random_instance(multi_objective_option: MultiObjectiveOption) -> FlamecastInstance {
+    let mut flamecast_instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 20;
+    options.verbose = false;
+    options.multi_objective_option = multi_objective_option;
+    flamecast_instance.solve(options);
+
+    return flamecast_instance;
+}
+
+#[cfg(test)]
+fn assert_no_point_dominated(pareto_archive: &ParetoArchive) {
+    for point in pareto_archive.points.iter() {
+        assert!(pareto_archive
+            .points
+            .iter()
+            .all(|other| !other.dominates(point)));
+    }
+}
+
+#[test]
+fn test_pareto_archive_insert() {
+    let mut pareto_archive = ParetoArchive::new();
+    assert!(pareto_archive.insert(ParetoPoint::new(10.0, 2, None, 0)));
+    assert!(pareto_archive.insert(ParetoPoint::new(8.0, 4, None, 1)));
+    assert!(pareto_archive.insert(ParetoPoint::new(5.0, 8, None, 2)));
+
+    // dominated and duplicated points are not archived
+    assert!(!pareto_archive.insert(ParetoPoint::new(9.0, 4, None, 3)));
+    assert!(!pareto_archive.insert(ParetoPoint::new(8.0, 4, None, 4)));
+
+    // a point that dominates archived points replaces them
+    assert!(pareto_archive.insert(ParetoPoint::new(7.0, 3, None, 5)));
+    assert_eq!(pareto_archive.points.len(), 3);
+    assert_no_point_dominated(&pareto_archive);
+
+    let front = pareto_archive.get_front();
+    assert_eq!(
+        front
+            .iter()
+            .map(|point| point.number_inner_vertices)
+            .collect::<Vec<usize>>(),
+        vec![2, 3, 8]
+    );
+
+    // costs saved per additional inner vertex along the front
+    let marginal_savings = pareto_archive.get_marginal_savings();
+    assert_eq!(marginal_savings.len(), 2);
+    assert_eq!(marginal_savings[0].0, 3);
+    assert!((marginal_savings[0].1 - 3.0).abs() < EPSILON);
+    assert!((marginal_savings[1].1 - 0.4).abs() < EPSILON);
+
+    // with the path length as third objective a more expensive point can be archived
+    let mut pareto_archive = ParetoArchive::new();
+    assert!(pareto_archive.insert(ParetoPoint::new(5.0, 8, Some(2.0), 0)));
+    assert!(pareto_archive.insert(ParetoPoint::new(6.0, 8, Some(1.0), 1)));
+    assert!(!pareto_archive.insert(ParetoPoint::new(6.0, 9, Some(1.5), 2)));
+    assert_eq!(pareto_archive.points.len(), 2);
+    assert_eq!(pareto_archive.get_cost_vertex_front().len(), 1);
+}
+
+#[test]
+fn test_pareto_archive_disabled() {
+    let instance = solve_random_instance(MultiObjectiveOption::Disabled);
+    assert!(instance.logger.pareto_archive.points.is_empty());
+}
+
+#[test]
+fn test_pareto_archive_annealing() {
+    let instance = solve_random_instance(MultiObjectiveOption::InnerVertices);
+    let pareto_archive = &instance.logger.pareto_archive;
+    assert!(!pareto_archive.points.is_empty());
+    assert_no_point_dominated(pareto_archive);
+
+    // every archived point belongs to a solution of the run
+    for point in pareto_archive.points.iter() {
+        assert!(point.max_path_length.is_none());
+        let solution = instance.solution_state.get_pareto_solution(point);
+        assert_eq!(
+            solution.get_number_of_inner_vertices(),
+            point.number_inner_vertices
+        );
+    }
+
+    // the cheapest archived point is the best solution of the run
+    let best_value = instance
+        .solution_state
+        .best_iteration
+        .best_value
+        .min(instance.logger.initial_objective_value);
+    let cheapest_value = pareto_archive
+        .points
+        .iter()
+        .map(|point| point.objective_value)
+        .fold(f64::INFINITY, f64::min);
+    assert!((cheapest_value - best_value).abs() < EPSILON * best_value.max(1.0));
+
+    let file_path = temp_dir().join(format!("test_pareto_front_{}.png", rand::random::<u64>()));
+    instance.plot_pareto_front(file_path.to_str().unwrap());
+    assert!(file_path.exists());
+    remove_file(&file_path).unwrap();
+}
+
+#[test]
+fn test_pareto_archive_path_length() {
+    let instance = solve_random_instance(MultiObjectiveOption::InnerVerticesAndPathLength);
+    let pareto_archive = &instance.logger.pareto_archive;
+    assert_no_point_dominated(pareto_archive);
+
+    for point in pareto_archive.points.iter() {
+        assert!(point.max_path_length.unwrap() > 0.0);
+    }
+
+    // the max path length is the longest of the source to drain paths
+    let solution = &instance.solution_state.current_solution;
+    let path_lengths =
+        solution.calculate_source_path_lengths_with_cost_model(&CostModel::from_alpha(1.0));
+    assert_eq!(path_lengths.len(), instance.get_number_of_sources());
+    let max_path_length = solution.calculate_max_path_length();
+    assert!(path_lengths
+        .iter()
+        .all(|path_length| *path_length <= max_path_length));
+    assert!(path_lengths
+        .iter()
+        .any(|path_length| (*path_length - max_path_length).abs() < EPSILON));
+}
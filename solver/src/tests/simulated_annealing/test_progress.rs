@@ -0,0 +1,124 @@
+#![cfg(test)]
+use crate::{
+    tests::create_random_flamecast_instance, CancellationToken, FlamecastInstance,
+    InitialSolutionFunction, IterationProgress, NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+fn create_random_instance() -> (FlamecastInstance, OptimizationOptions) {
+    let flamecast_instance =
+        create_random_flamecast_instance(4, 12, 2, None, InitialSolutionFunction::Random);
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 20;
+    options.verbose = false;
+
+    return (flamecast_instance, options);
+}
+
+#[cfg(test)]
+fn validate_solution(instance: &FlamecastInstance) {
+    assert!(instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .is_valid_flamecast_topology_check_all(
+            &instance.capacities,
+            instance.get_number_of_sources(),
+            instance.get_number_of_drains(),
+            instance.num_layers,
+        ));
+}
+
+#[cfg(test)]
+fn process_observer() {
+    let (mut instance, options) = create_random_instance();
+
+    let mut progresses: Vec<IterationProgress> = Vec::new();
+    let mut observer = |progress: &IterationProgress| progresses.push(progress.clone());
+    instance.solve_with_observer(options, &mut observer, &CancellationToken::new());
+
+    assert!(!instance.logger.cancelled);
+    assert_eq!(progresses.len(), instance.logger.current_costs.len());
+
+    let mut last_iteration = None;
+    for (progress, current_cost) in progresses.iter().zip(instance.logger.current_costs.iter()) {
+        assert!(progress.current_cost == *current_cost);
+        assert!(progress.best_cost <= progress.current_cost);
+        assert!(progress.temperature > 0.0);
+        if let Some(last_iteration) = last_iteration {
+            assert!(progress.iteration > last_iteration);
+        }
+        last_iteration = Some(progress.iteration);
+    }
+
+    let accepted = progresses
+        .iter()
+        .filter(|progress| progress.accepted)
+        .count();
+    assert_eq!(accepted, instance.solution_state.accepted_neighbors.len());
+
+    validate_solution(&instance);
+}
+
+#[cfg(test)]
+fn process_cancellation(cancel_after: usize) {
+    let (mut instance, options) = create_random_instance();
+
+    let cancellation_token = CancellationToken::new();
+    let observer_token = cancellation_token.clone();
+    let mut observed_iterations = 0;
+    let mut best_cost = f64::INFINITY;
+    let mut observer = |progress: &IterationProgress| {
+        observed_iterations += 1;
+        best_cost = progress.best_cost;
+        if observed_iterations == cancel_after {
+            observer_token.cancel();
+        }
+    };
+    instance.solve_with_observer(options, &mut observer, &cancellation_token);
+
+    assert!(cancellation_token.is_cancelled());
+    assert!(instance.logger.cancelled);
+    assert_eq!(observed_iterations, cancel_after);
+    assert_eq!(instance.logger.current_costs.len(), cancel_after);
+    assert!(instance.logger.final_objective_value.is_finite());
+    assert!(instance.solution_state.best_iteration.best_value <= best_cost);
+
+    validate_solution(&instance);
+}
+
+#[test]
+fn test_observer1() {
+    process_observer();
+}
+
+#[test]
+fn test_cancellation1() {
+    process_cancellation(1);
+}
+
+#[test]
+fn test_cancellation2() {
+    process_cancellation(5);
+}
+
+#[test]
+fn test_cancellation_before_start() {
+    let (mut instance, options) = create_random_instance();
+
+    let cancellation_token = CancellationToken::new();
+    cancellation_token.cancel();
+    instance.solve_with_observer(
+        options,
+        &mut |_: &IterationProgress| panic!("no iteration should run"),
+        &cancellation_token,
+    );
+
+    assert!(instance.logger.cancelled);
+    assert!(instance.logger.current_costs.is_empty());
+    assert!(instance.solution_state.accepted_neighbors.is_empty());
+
+    validate_solution(&instance);
+}
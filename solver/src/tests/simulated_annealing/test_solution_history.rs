@@ -0,0 +1,119 @@
+#![cfg(test)]
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    FlamecastInstance, InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
+};
+
+#[cfg(test)]
+fn solve_random_instance(neighbor_search_option: NeighborSearchOption) -> FlamecastInstance {
+    let mut flamecast_instance =
+        create_random_flamecast_instance(4, 10, 2, None, InitialSolutionFunction::Random);
+
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = neighbor_search_option;
+    options.max_iterations = 15;
+    options.verbose = false;
+    flamecast_instance.solve(options);
+
+    return flamecast_instance;
+}
+
+#[cfg(test)]
+fn process_intermediate_solutions(neighbor_search_option: NeighborSearchOption) {
+    let instance = solve_random_instance(neighbor_search_option);
+    let history = instance.logger.get_solution_history();
+
+    let intermediate_solutions = history.iter_intermediate_solutions().collect::<Vec<_>>();
+    assert_eq!(
+        intermediate_solutions.len(),
+        instance.logger.accepted_neighbors.len() + 1
+    );
+    assert!(intermediate_solutions[0].accepted_neighbor.is_none());
+    assert_eq!(
+        intermediate_solutions[0].solution,
+        instance.logger.initial_solution.base_graph
+    );
+
+    for intermediate_solution in intermediate_solutions.iter().skip(1) {
+        let neighbor_change = intermediate_solution.accepted_neighbor.as_ref().unwrap();
+        let solution = history.get_solution_at_iteration(neighbor_change.iteration);
+        assert_eq!(solution, intermediate_solution.solution);
+        assert!(solution.is_valid_flamecast_topology_check_all(
+            &instance.capacities,
+            instance.get_number_of_sources(),
+            instance.get_number_of_drains(),
+            instance.num_layers,
+        ));
+
+        // the logged cost was calculated with the same embedding options
+        let embedded_solution = history.embed_solution(
+            solution,
+            &instance.get_cost_model(),
+            &OptimizationOptions::default().neighbor_cost_options,
+        );
+        let costs = embedded_solution.calculate_costs(instance.alpha);
+        assert!((costs - neighbor_change.neighbor_cost).abs() < EPSILON * costs.max(1.0));
+    }
+}
+
+#[cfg(test)]
+fn process_best_solution(neighbor_search_option: NeighborSearchOption) {
+    let instance = solve_random_instance(neighbor_search_option);
+    let solution_state = &instance.solution_state;
+
+    let history = solution_state.get_solution_history();
+
+    // the best solution has the best logged cost, the initial solution is taken if it was the best one
+    let best_solution = solution_state.get_best_solution();
+    if solution_state.best_iteration.iteration == usize::MAX {
+        assert_eq!(best_solution, solution_state.initial_solution.base_graph);
+    } else if !solution_state.accepted_neighbors.is_empty() {
+        let best_costs = history
+            .embed_solution(
+                best_solution,
+                &instance.get_cost_model(),
+                &OptimizationOptions::default().neighbor_cost_options,
+            )
+            .calculate_costs(instance.alpha);
+        let best_value = solution_state.best_iteration.best_value;
+        assert!((best_costs - best_value).abs() < EPSILON * best_value.max(1.0));
+    }
+
+    // before the first accepted neighbor the history returns the initial solution
+    if let Some(first_neighbor) = solution_state.accepted_neighbors.first() {
+        if first_neighbor.iteration > 0 {
+            assert_eq!(
+                history.get_solution_at_iteration(first_neighbor.iteration - 1),
+                solution_state.initial_solution.base_graph
+            );
+        }
+    }
+    assert_eq!(
+        history.get_solution_at_iteration(usize::MAX),
+        history
+            .iter_intermediate_solutions()
+            .last()
+            .unwrap()
+            .solution
+    );
+}
+
+#[test]
+fn test_intermediate_solutions1() {
+    process_intermediate_solutions(NeighborSearchOption::CompleteHeuristical);
+}
+
+#[test]
+fn test_intermediate_solutions2() {
+    process_intermediate_solutions(NeighborSearchOption::CompleteEmbedding);
+}
+
+#[test]
+fn test_best_solution1() {
+    process_best_solution(NeighborSearchOption::CompleteHeuristical);
+}
+
+#[test]
+fn test_best_solution2() {
+    process_best_solution(NeighborSearchOption::CompleteEmbedding);
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+use rand::Rng;
+
+use crate::{
+    tests::{create_random_flamecast_instance, EPSILON},
+    FlamecastInstance, InitialSolutionFunction, NeighborSearchOption, OptimizationOptions,
+    SourceUpdateKind, VertexEmbeddings,
+};
+
+#[cfg(test)]
+fn get_reoptimization_options() -> OptimizationOptions {
+    let mut options = OptimizationOptions::default();
+    options.neighbor_search_option = NeighborSearchOption::CompleteHeuristical;
+    options.max_iterations = 5;
+    options.verbose = false;
+    return options;
+}
+
+#[cfg(test)]
+fn assert_valid_solution(instance: &FlamecastInstance) {
+    assert!(instance
+        .solution_state
+        .current_solution
+        .base_graph
+        .is_valid_flamecast_topology_check_all(
+            &instance.capacities,
+            instance.get_number_of_sources(),
+            instance.get_number_of_drains(),
+            instance.num_layers,
+        ));
+}
+
+#[test]
+fn test_add_source() {
+    let mut instance = create_random_flamecast_instance(
+        4,
+        12,
+        2,
+        Some(vec![1, 13, 13, 13]),
+        InitialSolutionFunction::Matching,
+    );
+    let source_index = instance
+        .add_source((0.5, 0.5), Some(get_reoptimization_options()))
+        .unwrap();
+
+    assert_eq!(source_index, 12);
+    assert_eq!(instance.get_number_of_sources(), 13);
+    assert_eq!(
+        instance
+            .solution_state
+            .current_solution
+            .vertices_embeddings
+            .embeddings[0][source_index],
+        (0.5, 0.5)
+    );
+    assert_valid_solution(&instance);
+
+    let source_update = &instance.source_updates[0];
+    assert_eq!(source_update.kind, SourceUpdateKind::Added);
+    assert!(source_update.attached_to.is_some());
+    // the re-optimization starts from the updated solution and keeps the best one
+    assert!(
+        source_update.objective_value_after_reoptimization
+            <= source_update.objective_value_after_update + EPSILON
+    );
+    assert!(
+        (instance.get_objective_function_value()
+            - source_update.objective_value_after_reoptimization)
+            .abs()
+            < EPSILON
+    );
+}
+
+#[test]
+fn test_remove_source() {
+    let mut instance = create_random_flamecast_instance(
+        4,
+        12,
+        2,
+        Some(vec![1, 12, 12, 12]),
+        InitialSolutionFunction::Matching,
+    );
+    let last_source_embedding = instance.sources_drains_embeddings.embeddings[0][11];
+    let removed_source_embedding = instance.sources_drains_embeddings.embeddings[0][3];
+    instance.remove_source(3, None).unwrap();
+
+    // the last source takes over the index of the removed one
+    assert_eq!(instance.get_number_of_sources(), 11);
+    assert_eq!(
+        instance.sources_drains_embeddings.embeddings[0][3],
+        last_source_embedding
+    );
+    // no inner vertex without children is left
+    assert_valid_solution(&instance);
+
+    let source_update = &instance.source_updates[0];
+    assert_eq!(source_update.kind, SourceUpdateKind::Removed);
+    assert_eq!(source_update.source_embedding, removed_source_embedding);
+    assert_eq!(
+        source_update.objective_value_after_update,
+        source_update.objective_value_after_reoptimization
+    );
+    // fewer sources never cost more
+    assert!(
+        source_update.objective_value_after_update
+            <= source_update.objective_value_before * (1.0 + EPSILON)
+    );
+}
+
+#[test]
+fn test_geographic_source_updates() {
+    // random points in a region of roughly 70 km around berlin
+    let mut rng = rand::thread_rng();
+    let mut random_point = || (rng.gen_range(12.9..13.9), rng.gen_range(52.2..52.8));
+    let sources = (0..12).map(|_| random_point()).collect();
+    let drains = (0..2).map(|_| random_point()).collect();
+    let mut instance = FlamecastInstance::new_geographic(
+        0.5,
+        4,
+        vec![1, 13, 13, 13],
+        VertexEmbeddings::from(vec![sources, vec![], vec![], drains]),
+        InitialSolutionFunction::Matching,
+    );
+    let projection = *instance.coordinate_system.get_projection().unwrap();
+
+    // the source is given as (longitude, latitude) and projected like the other sources
+    let point = random_point();
+    let source_index = instance.add_source(point, None).unwrap();
+    let projected = projection.project(&point);
+    let embedding = instance.sources_drains_embeddings.embeddings[0][source_index];
+    assert!((embedding.0 - projected.0).abs() < EPSILON);
+    assert!((embedding.1 - projected.1).abs() < EPSILON);
+    assert_eq!(instance.source_updates[0].source_embedding, point);
+    assert_valid_solution(&instance);
+
+    // the removed source is reported in the coordinates it was given in
+    instance.remove_source(source_index, None).unwrap();
+    let removed_embedding = instance.source_updates[1].source_embedding;
+    assert!((removed_embedding.0 - point.0).abs() < EPSILON);
+    assert!((removed_embedding.1 - point.1).abs() < EPSILON);
+}
+
+#[test]
+fn test_source_updates_invalid() {
+    // the drains are already full
+    let mut instance = create_random_flamecast_instance(
+        4,
+        12,
+        2,
+        Some(vec![1, 6, 6, 6]),
+        InitialSolutionFunction::Matching,
+    );
+    assert!(instance.add_source((0.5, 0.5), None).is_err());
+    assert!(instance.remove_source(12, None).is_err());
+    assert!(instance.source_updates.is_empty());
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DistanceMetric, VertexEmbedding, VertexEmbeddings};
+
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+// geographic embeddings are stored as (longitude, latitude) in degrees, like in geojson
+pub fn haversine_distance(from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+    let (from_lon, from_lat) = (from.0.to_radians(), from.1.to_radians());
+    let (to_lon, to_lat) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((to_lat - from_lat) / 2.0).sin().powi(2)
+        + from_lat.cos() * to_lat.cos() * ((to_lon - from_lon) / 2.0).sin().powi(2);
+    return 2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeographicProjection {
+    pub reference: VertexEmbedding, // (longitude, latitude) that is mapped to (0, 0)
+}
+
+impl GeographicProjection {
+    pub fn new(reference: VertexEmbedding) -> Self {
+        Self { reference }
+    }
+
+    pub fn from_embeddings(embeddings: &VertexEmbeddings) -> Self {
+        // use the center of the bounding box as reference to keep the distortion small
+        let points = embeddings.embeddings.iter().flat_map(|layer| layer.iter());
+        let (min_lon, min_lat, max_lon, max_lat) = points.fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_lon, min_lat, max_lon, max_lat), point| {
+                (
+                    min_lon.min(point.0),
+                    min_lat.min(point.1),
+                    max_lon.max(point.0),
+                    max_lat.max(point.1),
+                )
+            },
+        );
+
+        if min_lon > max_lon {
+            return Self::new((0.0, 0.0));
+        }
+        return Self::new(((min_lon + max_lon) / 2.0, (min_lat + max_lat) / 2.0));
+    }
+
+    pub fn project(&self, point: &VertexEmbedding) -> VertexEmbedding {
+        // local equirectangular projection, the result is in kilometers
+        let x = (point.0 - self.reference.0).to_radians()
+            * self.reference.1.to_radians().cos()
+            * EARTH_RADIUS_KM;
+        let y = (point.1 - self.reference.1).to_radians() * EARTH_RADIUS_KM;
+        return (x, y);
+    }
+
+    pub fn unproject(&self, point: &VertexEmbedding) -> VertexEmbedding {
+        return (self.unproject_x(point.0), self.unproject_y(point.1));
+    }
+
+    pub fn unproject_x(&self, x: f64) -> f64 {
+        return self.reference.0
+            + (x / (EARTH_RADIUS_KM * self.reference.1.to_radians().cos())).to_degrees();
+    }
+
+    pub fn unproject_y(&self, y: f64) -> f64 {
+        return self.reference.1 + (y / EARTH_RADIUS_KM).to_degrees();
+    }
+
+    pub fn project_embeddings(&self, embeddings: &VertexEmbeddings) -> VertexEmbeddings {
+        return VertexEmbeddings::from(
+            embeddings
+                .embeddings
+                .iter()
+                .map(|layer| layer.iter().map(|point| self.project(point)).collect())
+                .collect(),
+        );
+    }
+
+    pub fn unproject_embeddings(&self, embeddings: &VertexEmbeddings) -> VertexEmbeddings {
+        return VertexEmbeddings::from(
+            embeddings
+                .embeddings
+                .iter()
+                .map(|layer| layer.iter().map(|point| self.unproject(point)).collect())
+                .collect(),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CoordinateSystem {
+    #[default]
+    Euclidean,
+    // embeddings are projected to kilometers, costs are measured along great circles
+    Geographic(GeographicProjection),
+}
+
+impl CoordinateSystem {
+    pub fn is_geographic(&self) -> bool {
+        return matches!(self, CoordinateSystem::Geographic(_));
+    }
+
+    pub fn get_projection(&self) -> Option<&GeographicProjection> {
+        return match self {
+            CoordinateSystem::Euclidean => None,
+            CoordinateSystem::Geographic(projection) => Some(projection),
+        };
+    }
+
+    pub fn project(&self, point: &VertexEmbedding) -> VertexEmbedding {
+        // maps a point given like the sources and drains of the instance to the coordinates
+        // the instance works with
+        return match self {
+            CoordinateSystem::Euclidean => *point,
+            CoordinateSystem::Geographic(projection) => projection.project(point),
+        };
+    }
+
+    pub fn unproject(&self, point: &VertexEmbedding) -> VertexEmbedding {
+        return match self {
+            CoordinateSystem::Euclidean => *point,
+            CoordinateSystem::Geographic(projection) => projection.unproject(point),
+        };
+    }
+
+    pub fn get_distance(&self, from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+        return self.get_distance_with_metric(from, to, &DistanceMetric::Euclidean);
+    }
+
+    pub fn get_distance_with_metric(
+        &self,
+        from: &VertexEmbedding,
+        to: &VertexEmbedding,
+        metric: &DistanceMetric,
+    ) -> f64 {
+        // both points are given in the (projected) coordinates the instance works with,
+        // other metrics than the euclidean one are measured on the projection
+        return match (self, metric) {
+            (CoordinateSystem::Geographic(projection), DistanceMetric::Euclidean) => {
+                haversine_distance(&projection.unproject(from), &projection.unproject(to))
+            }
+            _ => metric.get_distance(from, to),
+        };
+    }
+}
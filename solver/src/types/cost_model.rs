@@ -0,0 +1,220 @@
+use super::{
+    CoordinateSystem, DistanceMetric, EdgeCost, EdgeCostFunction, MaxPathLength, VertexEmbedding,
+};
+
+// everything that is needed to evaluate the costs of an embedded solution,
+// the embedding, the exact evaluation and the heuristics all use the same model
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostModel {
+    pub alpha: f64,
+    pub metric: DistanceMetric,
+    pub coordinate_system: CoordinateSystem,
+    pub edge_cost_function: EdgeCostFunction,
+    // one entry per edge layer, the edges from layer i to layer i + 1 use entry i,
+    // without entries the alpha of the model and no multiplier is used for every layer
+    pub layer_alphas: Vec<f64>,
+    pub layer_cost_multipliers: Vec<f64>,
+    // one entry per layer, every vertex of the layer costs the given amount
+    pub vertex_opening_costs: Vec<f64>,
+    // constraint of the embedding on the summed edge lengths from every source to its drain
+    pub max_path_length: MaxPathLength,
+    // costs per source that is expected to be disconnected by the failure of an inner vertex
+    pub robustness_penalty: f64,
+}
+
+impl CostModel {
+    pub fn new(
+        alpha: f64,
+        metric: DistanceMetric,
+        coordinate_system: CoordinateSystem,
+        edge_cost_function: EdgeCostFunction,
+    ) -> Self {
+        Self {
+            alpha,
+            metric,
+            coordinate_system,
+            edge_cost_function,
+            layer_alphas: Vec::new(),
+            layer_cost_multipliers: Vec::new(),
+            vertex_opening_costs: Vec::new(),
+            max_path_length: MaxPathLength::Unbounded,
+            robustness_penalty: 0.0,
+        }
+    }
+
+    // the classic model: euclidean length * flow^alpha
+    pub fn from_alpha(alpha: f64) -> Self {
+        return Self::new(
+            alpha,
+            DistanceMetric::Euclidean,
+            CoordinateSystem::Euclidean,
+            EdgeCostFunction::Power,
+        );
+    }
+
+    pub fn with_layer_costs(
+        mut self,
+        layer_alphas: Vec<f64>,
+        layer_cost_multipliers: Vec<f64>,
+    ) -> Self {
+        self.layer_alphas = layer_alphas;
+        self.layer_cost_multipliers = layer_cost_multipliers;
+        return self;
+    }
+
+    pub fn validate_alpha(alpha: f64) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(format!("The alpha has to be in [0, 1], got {}.", alpha));
+        }
+        return Ok(());
+    }
+
+    pub fn validate_layer_costs(
+        layer_alphas: &Vec<f64>,
+        layer_cost_multipliers: &Vec<f64>,
+        num_layers: usize,
+    ) -> Result<(), String> {
+        for (name, values) in [
+            ("alphas", layer_alphas),
+            ("cost multipliers", layer_cost_multipliers),
+        ] {
+            if !values.is_empty() && values.len() != num_layers - 1 {
+                return Err(format!(
+                    "Expected {} layer {} but got {}.",
+                    num_layers - 1,
+                    name,
+                    values.len()
+                ));
+            }
+        }
+        if layer_alphas
+            .iter()
+            .any(|alpha| !(0.0..=1.0).contains(alpha))
+        {
+            return Err(String::from("Every layer alpha has to be in [0, 1]."));
+        }
+        if layer_cost_multipliers
+            .iter()
+            .any(|multiplier| *multiplier < 0.0)
+        {
+            return Err(String::from(
+                "Layer cost multipliers have to be non negative.",
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn validate_vertex_opening_costs(
+        vertex_opening_costs: &Vec<f64>,
+        num_layers: usize,
+    ) -> Result<(), String> {
+        if !vertex_opening_costs.is_empty() && vertex_opening_costs.len() != num_layers {
+            return Err(format!(
+                "Expected {} vertex opening costs but got {}.",
+                num_layers,
+                vertex_opening_costs.len()
+            ));
+        }
+        if vertex_opening_costs
+            .iter()
+            .any(|costs| *costs < 0.0 || costs.is_nan())
+        {
+            return Err(String::from(
+                "Vertex opening costs have to be non negative.",
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn validate_robustness_penalty(robustness_penalty: f64) -> Result<(), String> {
+        if robustness_penalty < 0.0 || robustness_penalty.is_nan() {
+            return Err(String::from(
+                "The robustness penalty has to be non negative.",
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn with_vertex_opening_costs(mut self, vertex_opening_costs: Vec<f64>) -> Self {
+        self.vertex_opening_costs = vertex_opening_costs;
+        return self;
+    }
+
+    pub fn with_max_path_length(mut self, max_path_length: MaxPathLength) -> Self {
+        self.max_path_length = max_path_length;
+        return self;
+    }
+
+    pub fn with_robustness_penalty(mut self, robustness_penalty: f64) -> Self {
+        self.robustness_penalty = robustness_penalty;
+        return self;
+    }
+
+    pub fn with_layer_offset(&self, first_layer: usize) -> Self {
+        // cost model for a subgraph whose layer 0 is the given layer of the whole graph
+        let mut cost_model = self.clone();
+        cost_model.layer_alphas = self
+            .layer_alphas
+            .iter()
+            .skip(first_layer)
+            .cloned()
+            .collect();
+        cost_model.layer_cost_multipliers = self
+            .layer_cost_multipliers
+            .iter()
+            .skip(first_layer)
+            .cloned()
+            .collect();
+        cost_model.vertex_opening_costs = self
+            .vertex_opening_costs
+            .iter()
+            .skip(first_layer)
+            .cloned()
+            .collect();
+        // the sources of a subgraph are not the sources of the instance
+        cost_model.max_path_length = MaxPathLength::Unbounded;
+        cost_model.robustness_penalty = 0.0;
+        return cost_model;
+    }
+
+    pub fn get_alpha(&self, layer_index: usize) -> f64 {
+        return *self.layer_alphas.get(layer_index).unwrap_or(&self.alpha);
+    }
+
+    pub fn get_cost_multiplier(&self, layer_index: usize) -> f64 {
+        return *self.layer_cost_multipliers.get(layer_index).unwrap_or(&1.0);
+    }
+
+    pub fn get_opening_costs(&self, layer_index: usize) -> f64 {
+        return *self.vertex_opening_costs.get(layer_index).unwrap_or(&0.0);
+    }
+
+    pub fn get_flow_weight(&self, layer_index: usize, flow: usize) -> f64 {
+        return self.get_cost_multiplier(layer_index)
+            * self
+                .edge_cost_function
+                .get_flow_costs(flow as f64, self.get_alpha(layer_index));
+    }
+
+    pub fn get_distance(&self, from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+        return self
+            .coordinate_system
+            .get_distance_with_metric(from, to, &self.metric);
+    }
+
+    pub fn get_projected_distance(&self, from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+        // distance on the (projected) coordinates the embedding works with, the path length
+        // constraints of the embedding bound it instead of the great circle distance
+        return self.metric.get_distance(from, to);
+    }
+
+    pub fn get_edge_costs(
+        &self,
+        layer_index: usize,
+        from: &VertexEmbedding,
+        to: &VertexEmbedding,
+        flow: usize,
+    ) -> f64 {
+        return self.get_distance(from, to) * self.get_flow_weight(layer_index, flow);
+    }
+}
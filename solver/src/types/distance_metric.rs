@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use super::VertexEmbedding;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    #[default]
+    Euclidean,
+    Manhattan,
+    Lp(f64), // general p-norm with p > 1, use from_p to get the specialized variants for p = 1 and p = 2
+}
+
+impl DistanceMetric {
+    pub fn from_p(p: f64) -> Result<Self, String> {
+        if p == 1.0 {
+            return Ok(DistanceMetric::Manhattan);
+        }
+        if p == 2.0 {
+            return Ok(DistanceMetric::Euclidean);
+        }
+        let metric = DistanceMetric::Lp(p);
+        metric.validate()?;
+        return Ok(metric);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // the embedding models the p-norm with power cones of exponent 1 / p in (0, 1)
+        if let DistanceMetric::Lp(p) = self {
+            if !(*p > 1.0 && p.is_finite()) {
+                return Err(format!(
+                    "The p-norm is only defined for finite p > 1, got {}.",
+                    p
+                ));
+            }
+        }
+        return Ok(());
+    }
+
+    pub fn get_p(&self) -> f64 {
+        return match self {
+            DistanceMetric::Euclidean => 2.0,
+            DistanceMetric::Manhattan => 1.0,
+            DistanceMetric::Lp(p) => *p,
+        };
+    }
+
+    pub fn get_norm(&self, dx: f64, dy: f64) -> f64 {
+        return match self {
+            DistanceMetric::Euclidean => (dx.powi(2) + dy.powi(2)).sqrt(),
+            DistanceMetric::Manhattan => dx.abs() + dy.abs(),
+            DistanceMetric::Lp(p) => (dx.abs().powf(*p) + dy.abs().powf(*p)).powf(1.0 / p),
+        };
+    }
+
+    pub fn get_distance(&self, from: &VertexEmbedding, to: &VertexEmbedding) -> f64 {
+        return self.get_norm(to.0 - from.0, to.1 - from.1);
+    }
+}
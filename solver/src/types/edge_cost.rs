@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+// costs per unit length of an edge depending on the flow it carries,
+// the function should be concave and non decreasing to model economies of scale
+pub trait EdgeCost {
+    fn get_flow_costs(&self, flow: f64, alpha: f64) -> f64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PowerEdgeCost;
+
+impl EdgeCost for PowerEdgeCost {
+    fn get_flow_costs(&self, flow: f64, alpha: f64) -> f64 {
+        return flow.powf(alpha);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PiecewiseLinearEdgeCost {
+    // (flow, costs) pairs with increasing flow, the costs are interpolated linearly in between,
+    // below the first breakpoint towards (0, 0) and above the last one with the last slope
+    pub breakpoints: Vec<(f64, f64)>,
+}
+
+impl PiecewiseLinearEdgeCost {
+    pub fn new(breakpoints: Vec<(f64, f64)>) -> Result<Self, String> {
+        Self::check_breakpoints(&breakpoints)?;
+        return Ok(Self { breakpoints });
+    }
+
+    pub fn check_breakpoints(breakpoints: &Vec<(f64, f64)>) -> Result<(), String> {
+        if breakpoints.is_empty() {
+            return Err(String::from(
+                "A piecewise linear edge cost needs at least one breakpoint.",
+            ));
+        }
+        if breakpoints
+            .iter()
+            .any(|(flow, costs)| *flow <= 0.0 || *costs < 0.0)
+        {
+            return Err(String::from(
+                "The breakpoints of a piecewise linear edge cost need positive flows and non negative costs.",
+            ));
+        }
+
+        let mut previous = (0.0, 0.0);
+        let mut previous_slope = f64::INFINITY;
+        for breakpoint in breakpoints.iter() {
+            if breakpoint.0 <= previous.0 {
+                return Err(String::from(
+                    "The breakpoints of a piecewise linear edge cost have to be sorted by flow.",
+                ));
+            }
+            let slope = (breakpoint.1 - previous.1) / (breakpoint.0 - previous.0);
+            if slope > previous_slope + 1e-9 {
+                return Err(String::from(
+                    "A piecewise linear edge cost has to be concave.",
+                ));
+            }
+            previous = *breakpoint;
+            previous_slope = slope;
+        }
+
+        return Ok(());
+    }
+
+    // every cable type of the catalogue is given as (capacity, costs per unit length)
+    pub fn from_cable_catalogue(cable_types: &Vec<(f64, f64)>) -> Result<Self, String> {
+        let mut breakpoints = cable_types.clone();
+        breakpoints.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        return Self::new(breakpoints);
+    }
+}
+
+impl EdgeCost for PiecewiseLinearEdgeCost {
+    fn get_flow_costs(&self, flow: f64, _alpha: f64) -> f64 {
+        let mut previous = (0.0, 0.0);
+        for breakpoint in self.breakpoints.iter() {
+            if flow <= breakpoint.0 {
+                let slope = (breakpoint.1 - previous.1) / (breakpoint.0 - previous.0);
+                return previous.1 + slope * (flow - previous.0);
+            }
+            previous = *breakpoint;
+        }
+
+        // extrapolate with the slope of the last segment
+        let num_breakpoints = self.breakpoints.len();
+        let before_last = if num_breakpoints > 1 {
+            self.breakpoints[num_breakpoints - 2]
+        } else {
+            (0.0, 0.0)
+        };
+        let slope = (previous.1 - before_last.1) / (previous.0 - before_last.0);
+        return previous.1 + slope * (flow - previous.0);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FixedVariableEdgeCost {
+    pub fixed: f64,    // costs per unit length of every used edge
+    pub variable: f64, // additional costs per unit length and unit of flow
+}
+
+impl FixedVariableEdgeCost {
+    pub fn new(fixed: f64, variable: f64) -> Self {
+        Self { fixed, variable }
+    }
+}
+
+impl EdgeCost for FixedVariableEdgeCost {
+    fn get_flow_costs(&self, flow: f64, _alpha: f64) -> f64 {
+        if flow <= 0.0 {
+            return 0.0;
+        }
+        return self.fixed + self.variable * flow;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LogarithmicEdgeCost {
+    pub scale: f64,
+}
+
+impl LogarithmicEdgeCost {
+    pub fn new(scale: f64) -> Self {
+        Self { scale }
+    }
+}
+
+impl EdgeCost for LogarithmicEdgeCost {
+    fn get_flow_costs(&self, flow: f64, _alpha: f64) -> f64 {
+        return self.scale * flow.ln_1p();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum EdgeCostFunction {
+    #[default]
+    Power, // flow^alpha with the alpha of the instance
+    PiecewiseLinear(PiecewiseLinearEdgeCost),
+    FixedVariable(FixedVariableEdgeCost),
+    Logarithmic(LogarithmicEdgeCost),
+}
+
+impl EdgeCostFunction {
+    pub fn is_power(&self) -> bool {
+        return matches!(self, EdgeCostFunction::Power);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        // loaded edge cost functions do not pass through the constructors
+        return match self {
+            EdgeCostFunction::Power => Ok(()),
+            EdgeCostFunction::PiecewiseLinear(edge_cost) => {
+                PiecewiseLinearEdgeCost::check_breakpoints(&edge_cost.breakpoints)
+            }
+            EdgeCostFunction::FixedVariable(edge_cost) => {
+                if edge_cost.fixed < 0.0 || edge_cost.variable < 0.0 {
+                    return Err(String::from(
+                        "A fixed plus variable edge cost needs non negative coefficients.",
+                    ));
+                }
+                Ok(())
+            }
+            EdgeCostFunction::Logarithmic(edge_cost) => {
+                if edge_cost.scale <= 0.0 {
+                    return Err(String::from(
+                        "A logarithmic edge cost needs a positive scale.",
+                    ));
+                }
+                Ok(())
+            }
+        };
+    }
+}
+
+impl EdgeCost for EdgeCostFunction {
+    fn get_flow_costs(&self, flow: f64, alpha: f64) -> f64 {
+        return match self {
+            EdgeCostFunction::Power => PowerEdgeCost.get_flow_costs(flow, alpha),
+            EdgeCostFunction::PiecewiseLinear(edge_cost) => edge_cost.get_flow_costs(flow, alpha),
+            EdgeCostFunction::FixedVariable(edge_cost) => edge_cost.get_flow_costs(flow, alpha),
+            EdgeCostFunction::Logarithmic(edge_cost) => edge_cost.get_flow_costs(flow, alpha),
+        };
+    }
+}
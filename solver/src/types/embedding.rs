@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::LayeredGraph;
+use super::{CoordinateSystem, CostModel, LayeredGraph};
 
 pub type VertexEmbedding = (f64, f64); // (x-coordinate, y-coordinate) of a vertex
 
@@ -57,33 +57,156 @@ impl GraphEmbedding {
     }
 
     pub fn calculate_costs(&self, alpha: f64) -> f64 {
+        return self.calculate_costs_with_cost_model(&CostModel::from_alpha(alpha));
+    }
+
+    pub fn calculate_costs_with_cost_model(&self, cost_model: &CostModel) -> f64 {
         // calculate the cost of the embedding, assumes a valid flamecast graph
-        let edge_flows = self.base_graph.calculate_edge_flows();
-        let mut cost = 0.0;
+        let edge_costs: f64 = self
+            .calculate_edge_costs_with_cost_model(cost_model)
+            .iter()
+            .flat_map(|layer| layer.iter())
+            .sum();
+        return edge_costs
+            + self.calculate_opening_costs_with_cost_model(cost_model)
+            + self.calculate_robustness_penalty_with_cost_model(cost_model);
+    }
+
+    pub fn calculate_opening_costs_with_cost_model(&self, cost_model: &CostModel) -> f64 {
+        // the opening costs only depend on the number of vertices, not on their positions
+        return self
+            .base_graph
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(layer_index, layer)| {
+                layer.vertices.len() as f64 * cost_model.get_opening_costs(layer_index)
+            })
+            .sum();
+    }
+
+    pub fn calculate_edge_lengths(&self) -> Vec<Vec<f64>> {
+        return self.calculate_edge_lengths_with_cost_model(&CostModel::from_alpha(1.0));
+    }
+
+    pub fn calculate_edge_lengths_with_cost_model(&self, cost_model: &CostModel) -> Vec<Vec<f64>> {
+        return self
+            .calculate_edge_lengths_with_distance(|from, to| cost_model.get_distance(from, to));
+    }
+
+    fn calculate_edge_lengths_with_distance(
+        &self,
+        distance: impl Fn(&VertexEmbedding, &VertexEmbedding) -> f64,
+    ) -> Vec<Vec<f64>> {
+        // calculate the length of the outgoing edge of every vertex that is not a drain
+        let mut edge_lengths = Vec::new();
 
         for (layer_index, layer) in self.base_graph.layers.iter().enumerate() {
             if layer_index == self.base_graph.layers.len() - 1 {
                 break;
             }
 
-            layer
+            let layer_lengths = layer
                 .vertices
                 .iter()
                 .enumerate()
-                .for_each(|(vertex_index, vertex)| {
+                .map(|(vertex_index, vertex)| {
                     let source_embedding =
                         self.vertices_embeddings.embeddings[layer_index][vertex_index];
                     let target_embedding = self.vertices_embeddings.embeddings[layer_index + 1]
                         [vertex.parent_index.unwrap()];
 
-                    let edge_len = ((source_embedding.0 - target_embedding.0).powi(2)
-                        + (source_embedding.1 - target_embedding.1).powi(2))
-                    .sqrt();
+                    return distance(&source_embedding, &target_embedding);
+                })
+                .collect();
+            edge_lengths.push(layer_lengths);
+        }
+
+        return edge_lengths;
+    }
+
+    pub fn calculate_source_path_lengths_with_cost_model(
+        &self,
+        cost_model: &CostModel,
+    ) -> Vec<f64> {
+        return self
+            .sum_source_path_lengths(&self.calculate_edge_lengths_with_cost_model(cost_model));
+    }
+
+    pub fn calculate_projected_source_path_lengths(&self, cost_model: &CostModel) -> Vec<f64> {
+        // path lengths on the projection, which are bounded by the max path lengths
+        let edge_lengths = self.calculate_edge_lengths_with_distance(|from, to| {
+            cost_model.get_projected_distance(from, to)
+        });
+        return self.sum_source_path_lengths(&edge_lengths);
+    }
+
+    fn sum_source_path_lengths(&self, edge_lengths: &Vec<Vec<f64>>) -> Vec<f64> {
+        // length of the path from every source to its drain, summed up from the drains backwards
+        let number_of_layers = self.base_graph.layers.len();
 
-                    cost += edge_len * (edge_flows[layer_index][vertex_index] as f64).powf(alpha);
-                });
+        let mut path_lengths =
+            vec![0.0; self.base_graph.layers[number_of_layers - 1].vertices.len()];
+        for layer_index in (0..number_of_layers - 1).rev() {
+            path_lengths = self.base_graph.layers[layer_index]
+                .vertices
+                .iter()
+                .enumerate()
+                .map(|(vertex_index, vertex)| {
+                    edge_lengths[layer_index][vertex_index]
+                        + path_lengths[vertex.parent_index.unwrap()]
+                })
+                .collect();
         }
 
-        return cost;
+        return path_lengths;
+    }
+
+    pub fn calculate_max_path_length(&self) -> f64 {
+        return self.calculate_max_path_length_with_cost_model(&CostModel::from_alpha(1.0));
+    }
+
+    pub fn calculate_max_path_length_with_cost_model(&self, cost_model: &CostModel) -> f64 {
+        return self
+            .calculate_source_path_lengths_with_cost_model(cost_model)
+            .into_iter()
+            .fold(0.0, f64::max);
+    }
+
+    pub fn calculate_edge_costs(&self, alpha: f64) -> Vec<Vec<f64>> {
+        return self.calculate_edge_costs_with_cost_model(&CostModel::from_alpha(alpha));
+    }
+
+    pub fn calculate_edge_costs_with_cost_model(&self, cost_model: &CostModel) -> Vec<Vec<f64>> {
+        // calculate the cost of the outgoing edge of every vertex that is not a drain
+        let edge_flows = self.base_graph.calculate_edge_flows();
+
+        return self
+            .calculate_edge_lengths_with_cost_model(cost_model)
+            .iter()
+            .enumerate()
+            .map(|(layer_index, layer_lengths)| {
+                layer_lengths
+                    .iter()
+                    .enumerate()
+                    .map(|(vertex_index, edge_len)| {
+                        edge_len
+                            * cost_model
+                                .get_flow_weight(layer_index, edge_flows[layer_index][vertex_index])
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    pub fn to_geographic_coordinates(&self, coordinate_system: &CoordinateSystem) -> Self {
+        // convert a projected embedding back to (longitude, latitude), e.g. for exporting
+        return match coordinate_system.get_projection() {
+            Some(projection) => Self::new(
+                self.base_graph.clone(),
+                projection.unproject_embeddings(&self.vertices_embeddings),
+            ),
+            None => self.clone(),
+        };
     }
 }
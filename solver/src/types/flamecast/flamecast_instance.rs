@@ -1,3 +1,5 @@
+use std::io;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -6,13 +8,18 @@ use crate::{
         generate_low_connectivity_flamecast_graph, generate_matching_flamecast_graph,
         generate_random_flamecast_graph,
     },
-    plotting::plot_embedded_graph,
+    plotting::{
+        plot_annealing_run, plot_embedded_graph, plot_embedded_graph_with_options,
+        plot_pareto_front, AnimationOptions, PlottingOptions,
+    },
     simulated_annealing::{OptimizationOptions, SimulatedAnnealing, SimulatedAnnealingLogger},
-    EmbeddingOptions, GraphEmbedding, InitialSolutionFunction, Stopwatch, TimeDeltaSave,
-    VertexEmbeddings,
+    CancellationToken, CoordinateSystem, CostModel, DistanceMetric, EdgeCostFunction,
+    EmbeddingOptions, ExportFormat, GeographicProjection, GraphEmbedding, InitialSolutionFunction,
+    MaxChildren, MaxPathLength, RobustnessReport, SolutionStatistics, SolveObserver, Stopwatch,
+    TimeDeltaSave, TopologyCache, VertexEmbeddings, VertexID,
 };
 
-use super::SolutionState;
+use super::{SolutionState, SourceUpdate};
 
 pub const FLAMECAST_BASE_FILE_PATH: &str = "./solutions/";
 
@@ -22,8 +29,30 @@ pub struct FlamecastInstance {
     pub num_layers: usize,
     pub capacities: Vec<usize>,
     pub sources_drains_embeddings: VertexEmbeddings,
+    #[serde(default)]
+    pub coordinate_system: CoordinateSystem,
+    #[serde(default)]
+    pub metric: DistanceMetric,
+    #[serde(default)]
+    pub edge_cost_function: EdgeCostFunction,
+    #[serde(default)]
+    pub layer_alphas: Vec<f64>, // one entry per edge layer, empty for the same alpha everywhere
+    #[serde(default)]
+    pub layer_cost_multipliers: Vec<f64>,
+    #[serde(default)]
+    pub vertex_opening_costs: Vec<f64>, // one entry per layer, empty if vertices are free
+    #[serde(default)]
+    pub max_path_length: MaxPathLength,
+    #[serde(default)]
+    pub max_children: MaxChildren,
+    #[serde(default)]
+    pub robustness_penalty: f64, // costs per expected disconnected source, 0 to ignore failures
     pub solution_state: SolutionState,
     pub logger: SimulatedAnnealingLogger,
+    #[serde(default)]
+    pub source_updates: Vec<SourceUpdate>, // sources added to or removed from the solved instance
+    #[serde(skip)]
+    pub topology_cache: TopologyCache,
 }
 
 impl FlamecastInstance {
@@ -33,12 +62,56 @@ impl FlamecastInstance {
         capacities: Vec<usize>,
         sources_drains_embeddings: VertexEmbeddings,
         initial_solution_function: InitialSolutionFunction,
+    ) -> Self {
+        return Self::generate(
+            alpha,
+            num_layers,
+            capacities,
+            sources_drains_embeddings,
+            initial_solution_function,
+            MaxChildren::default(),
+        );
+    }
+
+    pub fn new_with_max_children(
+        alpha: f64,
+        num_layers: usize,
+        capacities: Vec<usize>,
+        sources_drains_embeddings: VertexEmbeddings,
+        initial_solution_function: InitialSolutionFunction,
+        max_children: MaxChildren,
+    ) -> Result<Self, String> {
+        // the initial topology already has to respect the max children, so they cannot be set
+        // after the instance is created like the cost model
+        max_children.validate(
+            &capacities,
+            sources_drains_embeddings.embeddings[0].len(),
+            sources_drains_embeddings.embeddings[num_layers - 1].len(),
+        )?;
+        return Ok(Self::generate(
+            alpha,
+            num_layers,
+            capacities,
+            sources_drains_embeddings,
+            initial_solution_function,
+            max_children,
+        ));
+    }
+
+    fn generate(
+        alpha: f64,
+        num_layers: usize,
+        capacities: Vec<usize>,
+        sources_drains_embeddings: VertexEmbeddings,
+        initial_solution_function: InitialSolutionFunction,
+        max_children: MaxChildren,
     ) -> Self {
         let init_timer = Stopwatch::new();
         let initial_topology = match initial_solution_function {
             InitialSolutionFunction::Random => generate_random_flamecast_graph(
                 num_layers,
                 &capacities,
+                &max_children,
                 sources_drains_embeddings.embeddings[0].len(),
                 sources_drains_embeddings.embeddings[num_layers - 1].len(),
                 &sources_drains_embeddings.embeddings[0],
@@ -46,6 +119,7 @@ impl FlamecastInstance {
             InitialSolutionFunction::Matching => generate_matching_flamecast_graph(
                 num_layers,
                 &capacities,
+                &max_children,
                 sources_drains_embeddings.embeddings[0].len(),
                 sources_drains_embeddings.embeddings[num_layers - 1].len(),
                 &sources_drains_embeddings,
@@ -53,6 +127,7 @@ impl FlamecastInstance {
             InitialSolutionFunction::LowConnectivity => generate_low_connectivity_flamecast_graph(
                 num_layers,
                 &capacities,
+                &max_children,
                 sources_drains_embeddings.embeddings[0].len(),
                 sources_drains_embeddings.embeddings[num_layers - 1].len(),
                 &sources_drains_embeddings,
@@ -62,7 +137,7 @@ impl FlamecastInstance {
             &initial_topology,
             &sources_drains_embeddings,
             &initial_topology.calculate_edge_flows(),
-            alpha,
+            &CostModel::from_alpha(alpha),
             &EmbeddingOptions::default(),
         );
         let init_time = init_timer.elapsed();
@@ -76,13 +151,77 @@ impl FlamecastInstance {
             num_layers,
             capacities,
             sources_drains_embeddings,
+            coordinate_system: CoordinateSystem::Euclidean,
+            metric: DistanceMetric::Euclidean,
+            edge_cost_function: EdgeCostFunction::Power,
+            layer_alphas: Vec::new(),
+            layer_cost_multipliers: Vec::new(),
+            vertex_opening_costs: Vec::new(),
+            max_path_length: MaxPathLength::Unbounded,
+            max_children,
+            robustness_penalty: 0.0,
             solution_state: initial_solution_state,
             logger: SimulatedAnnealingLogger::from_init_time(TimeDeltaSave::from_time_delta(
                 &init_time,
             )),
+            source_updates: Vec::new(),
+            topology_cache: TopologyCache::default(),
         }
     }
 
+    pub fn new_geographic(
+        alpha: f64,
+        num_layers: usize,
+        capacities: Vec<usize>,
+        sources_drains_coordinates: VertexEmbeddings,
+        initial_solution_function: InitialSolutionFunction,
+    ) -> Self {
+        // the coordinates are (longitude, latitude), the instance works on a projection in kilometers
+        let projection = GeographicProjection::from_embeddings(&sources_drains_coordinates);
+        let mut instance = Self::new(
+            alpha,
+            num_layers,
+            capacities,
+            projection.project_embeddings(&sources_drains_coordinates),
+            initial_solution_function,
+        );
+        instance.coordinate_system = CoordinateSystem::Geographic(projection);
+        return instance;
+    }
+
+    pub fn get_current_solution_in_geographic_coordinates(&self) -> GraphEmbedding {
+        return self
+            .solution_state
+            .current_solution
+            .to_geographic_coordinates(&self.coordinate_system);
+    }
+
+    pub fn plot_current_solution_with_options(&self, file_path: &str, options: &PlottingOptions) {
+        let mut options = options.clone();
+        options.coordinate_system = self.coordinate_system;
+        plot_embedded_graph_with_options(
+            file_path,
+            &self.solution_state.current_solution,
+            &options,
+        );
+    }
+
+    pub fn plot_solving_run(&self, file_path: &str, options: &AnimationOptions) {
+        plot_annealing_run(file_path, &self.logger, &self.get_cost_model(), options);
+    }
+
+    pub fn plot_pareto_front(&self, file_path: &str) {
+        plot_pareto_front(file_path, &self.logger.pareto_archive);
+    }
+
+    pub fn export_current_solution(&self, file_path: &str, format: ExportFormat) -> io::Result<()> {
+        return self.solution_state.current_solution.export_with_cost_model(
+            file_path,
+            format,
+            &self.get_cost_model(),
+        );
+    }
+
     pub fn get_number_of_sources(&self) -> usize {
         self.sources_drains_embeddings.embeddings[0].len()
     }
@@ -100,10 +239,27 @@ impl FlamecastInstance {
         );
     }
 
+    pub fn get_cost_model(&self) -> CostModel {
+        return CostModel::new(
+            self.alpha,
+            self.metric,
+            self.coordinate_system,
+            self.edge_cost_function.clone(),
+        )
+        .with_layer_costs(
+            self.layer_alphas.clone(),
+            self.layer_cost_multipliers.clone(),
+        )
+        .with_vertex_opening_costs(self.vertex_opening_costs.clone())
+        .with_max_path_length(self.max_path_length.clone())
+        .with_robustness_penalty(self.robustness_penalty);
+    }
+
     pub fn get_objective_function_value(&self) -> f64 {
+        // geographic instances are optimized on the projection but report great circle costs
         self.solution_state
             .current_solution
-            .calculate_costs(self.alpha)
+            .calculate_costs_with_cost_model(&self.get_cost_model())
     }
 
     pub fn embed_current_solution(&mut self, options: &EmbeddingOptions) {
@@ -115,12 +271,139 @@ impl FlamecastInstance {
                 .current_solution
                 .base_graph
                 .calculate_edge_flows(),
-            self.alpha,
+            &self.get_cost_model(),
             options,
         );
         self.solution_state.current_solution.vertices_embeddings = current_embedding;
     }
 
+    pub fn set_alpha(&mut self, alpha: f64, options: &EmbeddingOptions) -> Result<(), String> {
+        CostModel::validate_alpha(alpha)?;
+        self.alpha = alpha;
+        self.restart_from_current_solution(options);
+        return Ok(());
+    }
+
+    pub fn set_distance_metric(
+        &mut self,
+        metric: DistanceMetric,
+        options: &EmbeddingOptions,
+    ) -> Result<(), String> {
+        metric.validate()?;
+        self.metric = metric;
+        self.restart_from_current_solution(options);
+        return Ok(());
+    }
+
+    pub fn set_edge_cost_function(
+        &mut self,
+        edge_cost_function: EdgeCostFunction,
+        options: &EmbeddingOptions,
+    ) {
+        self.edge_cost_function = edge_cost_function;
+        self.restart_from_current_solution(options);
+    }
+
+    pub fn set_layer_costs(
+        &mut self,
+        layer_alphas: Vec<f64>,
+        layer_cost_multipliers: Vec<f64>,
+        options: &EmbeddingOptions,
+    ) -> Result<(), String> {
+        CostModel::validate_layer_costs(&layer_alphas, &layer_cost_multipliers, self.num_layers)?;
+        self.layer_alphas = layer_alphas;
+        self.layer_cost_multipliers = layer_cost_multipliers;
+        self.restart_from_current_solution(options);
+        return Ok(());
+    }
+
+    pub fn set_vertex_opening_costs(
+        &mut self,
+        vertex_opening_costs: Vec<f64>,
+        options: &EmbeddingOptions,
+    ) -> Result<(), String> {
+        CostModel::validate_vertex_opening_costs(&vertex_opening_costs, self.num_layers)?;
+        self.vertex_opening_costs = vertex_opening_costs;
+        self.restart_from_current_solution(options);
+        return Ok(());
+    }
+
+    pub fn set_max_path_length(
+        &mut self,
+        max_path_length: MaxPathLength,
+        options: &EmbeddingOptions,
+    ) {
+        self.max_path_length = max_path_length;
+        self.restart_from_current_solution(options);
+    }
+
+    pub fn set_robustness_penalty(
+        &mut self,
+        robustness_penalty: f64,
+        options: &EmbeddingOptions,
+    ) -> Result<(), String> {
+        CostModel::validate_robustness_penalty(robustness_penalty)?;
+        self.robustness_penalty = robustness_penalty;
+        self.restart_from_current_solution(options);
+        return Ok(());
+    }
+
+    pub fn analyze_robustness(&self) -> RobustnessReport {
+        return self.solution_state.current_solution.analyze_robustness(
+            &self.capacities,
+            &self.max_children,
+            &self.get_cost_model(),
+        );
+    }
+
+    pub fn get_solution_statistics(&self) -> SolutionStatistics {
+        return self
+            .solution_state
+            .current_solution
+            .calculate_statistics(&self.capacities, &self.get_cost_model());
+    }
+
+    pub fn get_path_length_violations(&self) -> Vec<usize> {
+        // sources whose path in the current solution is longer than allowed, measured on the
+        // projection like in the embedding
+        if self.max_path_length.is_unbounded() {
+            return Vec::new();
+        }
+        let source_path_lengths = self
+            .solution_state
+            .current_solution
+            .calculate_projected_source_path_lengths(&self.get_cost_model());
+        return self
+            .max_path_length
+            .get_violated_sources(&source_path_lengths);
+    }
+
+    pub fn is_path_length_feasible(&self) -> bool {
+        return self.get_path_length_violations().is_empty();
+    }
+
+    pub fn get_max_children_violations(&self) -> Vec<VertexID> {
+        // vertices of the current solution with more children than allowed in their layer
+        return self
+            .max_children
+            .get_violating_vertices(&self.solution_state.current_solution.base_graph);
+    }
+
+    pub fn restart_from_current_solution(&mut self, options: &EmbeddingOptions) {
+        // after the cost model changed the current solution is embedded again and becomes the
+        // new initial solution, cached objective values of the old cost model are dropped
+        self.embed_current_solution(options);
+        self.solution_state = SolutionState::new(self.solution_state.current_solution.clone());
+        self.topology_cache.clear();
+    }
+
+    pub fn get_opening_costs(&self) -> f64 {
+        return self
+            .solution_state
+            .current_solution
+            .calculate_opening_costs_with_cost_model(&self.get_cost_model());
+    }
+
     pub fn calculate_objective_function_value(&mut self, options: &EmbeddingOptions) -> f64 {
         self.embed_current_solution(options);
         self.get_objective_function_value()
@@ -131,4 +414,16 @@ impl FlamecastInstance {
 
         optimization_instance.solve();
     }
+
+    pub fn solve_with_observer(
+        &mut self,
+        options: OptimizationOptions,
+        observer: &mut dyn SolveObserver,
+        cancellation_token: &CancellationToken,
+    ) {
+        // the best solution found until a cancellation is stored as current solution
+        let mut optimization_instance = SimulatedAnnealing::from_flamecast_instance(self, options);
+
+        optimization_instance.solve_with_observer(observer, cancellation_token);
+    }
 }
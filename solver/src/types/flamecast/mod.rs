@@ -1,6 +1,8 @@
 mod flamecast_instance;
 mod neighborhood;
+mod source_updates;
 mod state;
 
 pub use flamecast_instance::*;
+pub use source_updates::*;
 pub use state::*;
@@ -9,22 +9,48 @@ impl FlamecastInstance {
         // calculates the objective function value of the neighbor
         let mut neighbor_loader = NeighborLoader::new();
 
+        neighbor_loader.load_neighbor(
+            &mut self.solution_state.current_solution.base_graph,
+            neighbor,
+        );
+
+        // topologies which were already embedded don't have to be embedded again
+        let topology_hash = self
+            .solution_state
+            .current_solution
+            .base_graph
+            .calculate_topology_hash();
+        if let Some(cached_value) = self
+            .topology_cache
+            .get(topology_hash, neighbor_cost_options)
+        {
+            neighbor_loader.unload_neighbor(
+                &mut self.solution_state.current_solution.base_graph,
+                neighbor,
+            );
+            return cached_value;
+        }
+
         let current_embedding = self
             .solution_state
             .current_solution
             .vertices_embeddings
             .clone();
 
-        neighbor_loader.load_neighbor(
-            &mut self.solution_state.current_solution.base_graph,
-            neighbor,
-        );
-        let new_objective_value = self.calculate_objective_function_value(neighbor_cost_options);
+        let mut new_objective_value =
+            self.calculate_objective_function_value(neighbor_cost_options);
+        if !self.is_path_length_feasible() {
+            // neighbors that violate the path length bounds are never accepted
+            new_objective_value = f64::INFINITY;
+        }
         neighbor_loader.unload_neighbor(
             &mut self.solution_state.current_solution.base_graph,
             neighbor,
         );
 
+        self.topology_cache
+            .insert(topology_hash, neighbor_cost_options, new_objective_value);
+
         // saving of the current embedding because the actual embedding was overwritten by the calculation of the neighbors cost
         self.solution_state.current_solution.vertices_embeddings = current_embedding;
 
@@ -19,12 +19,34 @@ impl FlamecastInstance {
                 &mut self.solution_state.current_solution.base_graph,
                 neighbor,
             );
-            let cost = self.calculate_objective_function_value(neighbor_test_options);
+            let topology_hash = self
+                .solution_state
+                .current_solution
+                .base_graph
+                .calculate_topology_hash();
+            let cost = match self
+                .topology_cache
+                .get(topology_hash, neighbor_test_options)
+            {
+                Some(cached_value) => cached_value,
+                None => {
+                    let mut cost = self.calculate_objective_function_value(neighbor_test_options);
+                    if !self.is_path_length_feasible() {
+                        cost = f64::INFINITY;
+                    }
+                    self.topology_cache
+                        .insert(topology_hash, neighbor_test_options, cost);
+                    cost
+                }
+            };
             neighbor_loader.unload_neighbor(
                 &mut self.solution_state.current_solution.base_graph,
                 neighbor,
             );
-            result.push(NeighborCost::new(neighbor.clone(), cost));
+            // neighbors that violate the path length bounds are no candidates
+            if cost.is_finite() {
+                result.push(NeighborCost::new(neighbor.clone(), cost));
+            }
         });
 
         return result;
@@ -41,6 +63,8 @@ impl FlamecastInstance {
         result.append(&mut self.get_all_possible_swaps(&current_vertex_flows));
         result.append(&mut self.get_all_possible_merges(&current_vertex_flows));
         result.append(&mut self.get_all_possible_splits());
+        result.append(&mut self.get_all_possible_subtree_exchanges(&current_vertex_flows));
+        result.append(&mut self.get_all_possible_reroutes(&current_vertex_flows));
         return result;
     }
 
@@ -65,6 +89,7 @@ impl FlamecastInstance {
                         num_layers,
                         &target_node_id,
                         &self.capacities,
+                        &self.max_children,
                         current_vertex_flows,
                     ) {
                         possible_recablings
@@ -122,6 +147,7 @@ impl FlamecastInstance {
                         &node1_id,
                         &node2_id,
                         &self.capacities,
+                        &self.max_children,
                         current_vertex_flows,
                     ) {
                         possible_merges.push(Neighbor::Merge(node1_id.clone(), node2_id));
@@ -149,7 +175,7 @@ impl FlamecastInstance {
                         &children,
                         &self.solution_state.current_solution.vertices_embeddings,
                     );
-                    if base_graph.check_split_possible(&cluster2) {
+                    if base_graph.check_split_possible(&cluster2, &self.max_children) {
                         possible_splits.push(Neighbor::Split(cluster2));
                     }
                 }
@@ -158,4 +184,78 @@ impl FlamecastInstance {
 
         return possible_splits;
     }
+
+    pub fn get_all_possible_subtree_exchanges(
+        &self,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> Vec<Neighbor> {
+        let mut possible_exchanges = Vec::new();
+
+        let base_graph = &self.solution_state.current_solution.base_graph;
+
+        let num_layers = base_graph.layers.len();
+        for layer in 1..num_layers - 1 {
+            for node1_index in 0..base_graph.layers[layer].vertices.len() {
+                let node1_id = VertexID::new(layer, node1_index);
+
+                for node2_index in (node1_index + 1)..base_graph.layers[layer].vertices.len() {
+                    let node2_id = VertexID::new(layer, node2_index);
+
+                    if base_graph.check_subtree_exchange_possible(
+                        &node1_id,
+                        &node2_id,
+                        &self.capacities,
+                        &self.max_children,
+                        current_vertex_flows,
+                    ) {
+                        possible_exchanges
+                            .push(Neighbor::SubtreeExchange(node1_id.clone(), node2_id));
+                    }
+                }
+            }
+        }
+
+        return possible_exchanges;
+    }
+
+    pub fn get_all_possible_reroutes(
+        &self,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> Vec<Neighbor> {
+        let mut possible_reroutes = Vec::new();
+
+        let base_graph = &self.solution_state.current_solution.base_graph;
+        let vertex_drains = base_graph.calculate_vertex_drains();
+
+        // targets in the following layer are already covered by recablings
+        let num_layers = base_graph.layers.len();
+        for layer in 0..num_layers - 1 {
+            for node in 0..base_graph.layers[layer].vertices.len() {
+                let node_id = VertexID::new(layer, node);
+
+                for target_layer in (layer + 2)..num_layers {
+                    for target_node in 0..base_graph.layers[target_layer].vertices.len() {
+                        if vertex_drains[target_layer][target_node] == vertex_drains[layer][node] {
+                            continue;
+                        }
+
+                        let target_node_id = VertexID::new(target_layer, target_node);
+
+                        if base_graph.check_reroute_possible(
+                            &node_id,
+                            &target_node_id,
+                            &self.capacities,
+                            &self.max_children,
+                            current_vertex_flows,
+                        ) {
+                            possible_reroutes
+                                .push(Neighbor::Reroute(node_id.clone(), target_node_id));
+                        }
+                    }
+                }
+            }
+        }
+
+        return possible_reroutes;
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    graph_embedding::embed_directed_graph, neighborhood::NeighborCost, EmbeddingOptions,
+    graph_embedding::embed_directed_graph, neighborhood::NeighborCost, CostModel, EmbeddingOptions,
     FlamecastInstance, LayeredGraph, Neighbor, Vertex, VertexEmbedding, VertexEmbeddings, VertexID,
 };
 
@@ -16,10 +16,8 @@ impl FlamecastInstance {
         // generate a set of random vertices which are used to search for neighbors
         let random_vertices = base_graph.get_sorted_random_vertices(number_random_vertices);
 
-        let current_costs = self
-            .solution_state
-            .current_solution
-            .calculate_costs(self.alpha);
+        // the heuristical estimates are relative to the exact objective value of the current solution
+        let current_costs = self.get_objective_function_value();
         let current_vertex_flows = base_graph.calculate_vertex_flows();
 
         let mut result =
@@ -41,6 +39,16 @@ impl FlamecastInstance {
             &current_vertex_flows,
             neighbor_test_options,
         ));
+        result.append(&mut self.get_heuristical_subtree_exchanges(
+            &random_vertices,
+            current_costs,
+            &current_vertex_flows,
+        ));
+        result.append(&mut self.get_heuristical_reroutes(
+            &random_vertices,
+            current_costs,
+            &current_vertex_flows,
+        ));
 
         return result;
     }
@@ -59,6 +67,7 @@ impl FlamecastInstance {
             .current_solution
             .vertices_embeddings
             .embeddings;
+        let cost_model = self.get_cost_model();
 
         let mut result = Vec::new();
 
@@ -69,7 +78,7 @@ impl FlamecastInstance {
                 &old_parent,
                 current_vertex_flows[vertex.layer][vertex.index],
                 embeddings,
-                self.alpha,
+                &cost_model,
             );
             let costs_without_edge = current_costs - old_parent_edge_costs;
 
@@ -81,6 +90,7 @@ impl FlamecastInstance {
                     num_layers,
                     &target_node_id,
                     &self.capacities,
+                    &self.max_children,
                     current_vertex_flows,
                 ) {
                     let new_costs = costs_without_edge
@@ -89,7 +99,7 @@ impl FlamecastInstance {
                             &target_node_id,
                             current_vertex_flows[vertex.layer][vertex.index],
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         );
                     let delta_flow = -(current_vertex_flows[vertex.layer][vertex.index] as i32);
                     let new_costs = self.update_path_costs(
@@ -98,6 +108,7 @@ impl FlamecastInstance {
                         &old_parent,
                         &target_node_id,
                         new_costs,
+                        &cost_model,
                     );
 
                     let neighbor = Neighbor::Recable(vertex.clone(), target_node_id);
@@ -121,6 +132,7 @@ impl FlamecastInstance {
             .current_solution
             .vertices_embeddings
             .embeddings;
+        let cost_model = self.get_cost_model();
 
         let mut current_layer = 0;
         let mut processed_vertices = vec![];
@@ -138,7 +150,7 @@ impl FlamecastInstance {
                 &parent1,
                 current_vertex_flows[vertex.layer][vertex.index],
                 embeddings,
-                self.alpha,
+                &cost_model,
             );
             let costs_without_edge1 = current_costs - edge_costs1;
 
@@ -163,21 +175,21 @@ impl FlamecastInstance {
                             &parent2,
                             current_vertex_flows[node2_id.layer][node2_id.index],
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         )
                         + get_edge_costs(
                             vertex,
                             &parent2,
                             current_vertex_flows[vertex.layer][vertex.index],
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         )
                         + get_edge_costs(
                             &node2_id,
                             &parent1,
                             current_vertex_flows[node2_id.layer][node2_id.index],
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         );
                     let delta_flow = current_vertex_flows[node2_id.layer][node2_id.index] as i32
                         - current_vertex_flows[vertex.layer][vertex.index] as i32;
@@ -187,6 +199,7 @@ impl FlamecastInstance {
                         &parent1,
                         &parent2,
                         new_costs,
+                        &cost_model,
                     );
 
                     let neighbor = Neighbor::Swap(vertex.clone(), node2_id);
@@ -213,6 +226,7 @@ impl FlamecastInstance {
             .current_solution
             .vertices_embeddings
             .embeddings;
+        let cost_model = self.get_cost_model();
 
         let mut current_layer = 0;
         let mut processed_vertices = vec![];
@@ -233,7 +247,7 @@ impl FlamecastInstance {
                 current_vertex_flows,
                 base_graph,
                 embeddings,
-                self.alpha,
+                &cost_model,
             );
             let costs_without_vertex_surrounding = current_costs - vertex_surrounding_costs;
 
@@ -244,6 +258,8 @@ impl FlamecastInstance {
             let mut merged_graph = create_merged_base_graph(children1_size);
             let mut sources_drains_embeddings =
                 create_sources_drains_embeddings(&children1, &parent, embeddings);
+            // the merged graph consists of the layers around the vertex
+            let local_cost_model = cost_model.with_layer_offset(vertex.layer - 1);
 
             for node2_index in 0..base_graph.layers[vertex.layer].vertices.len() {
                 let node2_id = VertexID::new(vertex.layer, node2_index);
@@ -256,6 +272,7 @@ impl FlamecastInstance {
                     vertex,
                     &node2_id,
                     &self.capacities,
+                    &self.max_children,
                     current_vertex_flows,
                 ) {
                     let children2 = base_graph.get_children(&node2_id).unwrap();
@@ -278,24 +295,26 @@ impl FlamecastInstance {
                         &merged_graph,
                         &sources_drains_embeddings,
                         &get_edge_flows(&graph_vertex_flows),
-                        self.alpha,
+                        &local_cost_model,
                         neighbor_test_options,
                     );
 
+                    // the merge closes one vertex of the layer
                     let new_costs = costs_without_vertex_surrounding
+                        - cost_model.get_opening_costs(vertex.layer)
                         - get_surrounding_costs(
                             &node2_id,
                             current_vertex_flows,
                             base_graph,
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         )
                         + get_surrounding_costs(
                             &VertexID::new(1, 0),
                             &graph_vertex_flows,
                             &merged_graph,
                             &merged_embeddings.embeddings,
-                            self.alpha,
+                            &local_cost_model,
                         );
 
                     reset_merged_base_graph(&mut merged_graph, children1_size);
@@ -325,6 +344,7 @@ impl FlamecastInstance {
             .current_solution
             .vertices_embeddings
             .embeddings;
+        let cost_model = self.get_cost_model();
 
         let mut result = Vec::new();
 
@@ -335,13 +355,15 @@ impl FlamecastInstance {
 
             let children = base_graph.get_children(vertex).unwrap();
             let parent = base_graph.get_parent(vertex).unwrap();
+            // the split graph consists of the layers around the vertex
+            let local_cost_model = cost_model.with_layer_offset(vertex.layer - 1);
 
             if children.len() > 1 {
                 let (cluster1, cluster2) = cluster_children(
                     &children,
                     &self.solution_state.current_solution.vertices_embeddings,
                 );
-                if base_graph.check_split_possible(&cluster2) {
+                if base_graph.check_split_possible(&cluster2, &self.max_children) {
                     let graph = create_split_base_graph(cluster1.len(), cluster2.len());
                     let mut children_sorted = cluster1;
                     children_sorted.append(&mut cluster2.clone());
@@ -356,31 +378,32 @@ impl FlamecastInstance {
                         &graph,
                         &sources_drains_embeddings,
                         &get_edge_flows(&graph_vertex_flows),
-                        self.alpha,
+                        &local_cost_model,
                         neighbor_test_options,
                     );
 
-                    let new_costs = current_costs
+                    // the split opens one vertex in the layer
+                    let new_costs = current_costs + cost_model.get_opening_costs(vertex.layer)
                         - get_surrounding_costs(
                             vertex,
                             current_vertex_flows,
                             base_graph,
                             embeddings,
-                            self.alpha,
+                            &cost_model,
                         )
                         + get_surrounding_costs(
                             &VertexID::new(1, 0),
                             &graph_vertex_flows,
                             &graph,
                             &split_embeddings.embeddings,
-                            self.alpha,
+                            &local_cost_model,
                         )
                         + get_surrounding_costs(
                             &VertexID::new(1, 1),
                             &graph_vertex_flows,
                             &graph,
                             &split_embeddings.embeddings,
-                            self.alpha,
+                            &local_cost_model,
                         );
 
                     let neighbor = Neighbor::Split(cluster2);
@@ -392,6 +415,239 @@ impl FlamecastInstance {
         return result;
     }
 
+    pub fn get_heuristical_subtree_exchanges(
+        &self,
+        vertices: &Vec<VertexID>,
+        current_costs: f64,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> Vec<NeighborCost> {
+        let base_graph = &self.solution_state.current_solution.base_graph;
+        let embeddings = &self
+            .solution_state
+            .current_solution
+            .vertices_embeddings
+            .embeddings;
+        let cost_model = self.get_cost_model();
+
+        let mut current_layer = 0;
+        let mut processed_vertices = vec![];
+        let mut result = Vec::new();
+
+        for vertex in vertices {
+            if vertex.layer == 0 {
+                continue;
+            }
+
+            if vertex.layer != current_layer {
+                current_layer = vertex.layer;
+                processed_vertices.clear();
+            }
+
+            let children1 = base_graph.get_children(vertex).unwrap();
+            let children1_costs = get_children_costs(
+                &children1,
+                vertex,
+                current_vertex_flows,
+                embeddings,
+                &cost_model,
+            );
+
+            for node2_index in 0..base_graph.layers[vertex.layer].vertices.len() {
+                let node2_id = VertexID::new(vertex.layer, node2_index);
+
+                if processed_vertices.binary_search(&node2_index).is_ok() {
+                    continue;
+                }
+
+                if base_graph.check_subtree_exchange_possible(
+                    vertex,
+                    &node2_id,
+                    &self.capacities,
+                    &self.max_children,
+                    current_vertex_flows,
+                ) {
+                    let children2 = base_graph.get_children(&node2_id).unwrap();
+
+                    // the vertices stay at their positions, only the edges to the children change
+                    let new_costs = current_costs
+                        - children1_costs
+                        - get_children_costs(
+                            &children2,
+                            &node2_id,
+                            current_vertex_flows,
+                            embeddings,
+                            &cost_model,
+                        )
+                        + get_children_costs(
+                            &children1,
+                            &node2_id,
+                            current_vertex_flows,
+                            embeddings,
+                            &cost_model,
+                        )
+                        + get_children_costs(
+                            &children2,
+                            vertex,
+                            current_vertex_flows,
+                            embeddings,
+                            &cost_model,
+                        );
+                    let delta_flow = current_vertex_flows[node2_id.layer][node2_id.index] as i32
+                        - current_vertex_flows[vertex.layer][vertex.index] as i32;
+                    let new_costs = self.update_path_costs(
+                        current_vertex_flows,
+                        delta_flow,
+                        vertex,
+                        &node2_id,
+                        new_costs,
+                        &cost_model,
+                    );
+
+                    let neighbor = Neighbor::SubtreeExchange(vertex.clone(), node2_id);
+                    result.push(NeighborCost::new(neighbor, new_costs));
+                }
+            }
+
+            processed_vertices.push(vertex.index);
+        }
+
+        return result;
+    }
+
+    pub fn get_heuristical_reroutes(
+        &self,
+        vertices: &Vec<VertexID>,
+        current_costs: f64,
+        current_vertex_flows: &Vec<Vec<usize>>,
+    ) -> Vec<NeighborCost> {
+        let base_graph = &self.solution_state.current_solution.base_graph;
+        let num_layers = base_graph.layers.len();
+        let num_drains = base_graph.layers[num_layers - 1].vertices.len();
+
+        let vertex_drains = base_graph.calculate_vertex_drains();
+        let cost_model = self.get_cost_model();
+
+        let mut result = Vec::new();
+
+        for vertex in vertices {
+            // only the best branch of every other drain is regarded
+            let mut best_reroutes: Vec<Option<NeighborCost>> = vec![None; num_drains];
+
+            for target_layer in (vertex.layer + 2)..num_layers {
+                for target_index in 0..base_graph.layers[target_layer].vertices.len() {
+                    let drain = vertex_drains[target_layer][target_index];
+                    if drain == vertex_drains[vertex.layer][vertex.index] {
+                        continue;
+                    }
+
+                    let target_node_id = VertexID::new(target_layer, target_index);
+
+                    if base_graph.check_reroute_possible(
+                        vertex,
+                        &target_node_id,
+                        &self.capacities,
+                        &self.max_children,
+                        current_vertex_flows,
+                    ) {
+                        let new_costs = self.estimate_reroute_costs(
+                            vertex,
+                            &target_node_id,
+                            current_vertex_flows,
+                            current_costs,
+                            &cost_model,
+                        );
+
+                        let neighbor = Neighbor::Reroute(vertex.clone(), target_node_id);
+                        let neighbor_cost = NeighborCost::new(neighbor, new_costs);
+                        best_reroutes[drain] = match &best_reroutes[drain] {
+                            Some(best) => Some(best.min(&neighbor_cost).clone()),
+                            None => Some(neighbor_cost),
+                        };
+                    }
+                }
+            }
+
+            result.extend(best_reroutes.into_iter().flatten());
+        }
+
+        return result;
+    }
+
+    pub fn estimate_reroute_costs(
+        &self,
+        vertex: &VertexID,
+        target_node: &VertexID,
+        current_vertex_flows: &Vec<Vec<usize>>,
+        current_costs: f64,
+        cost_model: &CostModel,
+    ) -> f64 {
+        let graph = &self.solution_state.current_solution.base_graph;
+        let embeddings = &self
+            .solution_state
+            .current_solution
+            .vertices_embeddings
+            .embeddings;
+
+        let flow = current_vertex_flows[vertex.layer][vertex.index];
+        let old_parent = graph.get_parent(vertex).unwrap();
+
+        // the intermediate vertices are assumed to lie evenly spaced on the straight line to the
+        // target, every edge of the new path is priced with the costs of its layer
+        let start = embeddings[vertex.layer][vertex.index];
+        let end = embeddings[target_node.layer][target_node.index];
+        let number_of_edges = (target_node.layer - vertex.layer) as f64;
+        let get_path_point = |layer: usize| {
+            let fraction = (layer - vertex.layer) as f64 / number_of_edges;
+            (
+                start.0 + fraction * (end.0 - start.0),
+                start.1 + fraction * (end.1 - start.1),
+            )
+        };
+        let mut new_costs = current_costs
+            - get_edge_costs(vertex, &old_parent, flow, embeddings, cost_model)
+            + (vertex.layer..target_node.layer)
+                .map(|layer| {
+                    cost_model.get_edge_costs(
+                        layer,
+                        &get_path_point(layer),
+                        &get_path_point(layer + 1),
+                        flow,
+                    )
+                })
+                .sum::<f64>();
+
+        // an intermediate vertex is opened in every skipped layer
+        new_costs += (vertex.layer + 1..target_node.layer)
+            .map(|layer| cost_model.get_opening_costs(layer))
+            .sum::<f64>();
+
+        // the old path loses the flow until it reaches the layer of the target
+        let mut current = old_parent;
+        while current.layer < target_node.layer {
+            let parent = graph.get_parent(&current).unwrap();
+            let current_flow = current_vertex_flows[current.layer][current.index];
+            new_costs = new_costs
+                - get_edge_costs(&current, &parent, current_flow, embeddings, cost_model)
+                + get_edge_costs(
+                    &current,
+                    &parent,
+                    current_flow - flow,
+                    embeddings,
+                    cost_model,
+                );
+            current = parent;
+        }
+
+        return self.update_path_costs(
+            current_vertex_flows,
+            -(flow as i32),
+            &current,
+            target_node,
+            new_costs,
+            cost_model,
+        );
+    }
+
     pub fn update_path_costs(
         &self,
         current_vertex_flows: &Vec<Vec<usize>>,
@@ -399,6 +655,7 @@ impl FlamecastInstance {
         start1: &VertexID,
         start2: &VertexID,
         current_costs: f64,
+        cost_model: &CostModel,
     ) -> f64 {
         let graph = &self.solution_state.current_solution.base_graph;
         let embeddings = &self
@@ -423,13 +680,13 @@ impl FlamecastInstance {
                 &par1,
                 current_vertex_flows[current1.layer][current1.index],
                 embeddings,
-                self.alpha,
+                cost_model,
             ) + get_edge_costs(
                 &current2,
                 &par2,
                 current_vertex_flows[current2.layer][current2.index],
                 embeddings,
-                self.alpha,
+                cost_model,
             );
 
             current_costs += get_edge_costs(
@@ -437,13 +694,13 @@ impl FlamecastInstance {
                 &par1,
                 (current_vertex_flows[current1.layer][current1.index] as i32 + delta_flow) as usize,
                 embeddings,
-                self.alpha,
+                cost_model,
             ) + get_edge_costs(
                 &current2,
                 &par2,
                 (current_vertex_flows[current2.layer][current2.index] as i32 - delta_flow) as usize,
                 embeddings,
-                self.alpha,
+                cost_model,
             );
 
             current1 = par1;
@@ -562,7 +819,7 @@ fn get_surrounding_costs(
     current_vertex_flows: &Vec<Vec<usize>>,
     graph: &LayeredGraph,
     embeddings: &Vec<Vec<VertexEmbedding>>,
-    alpha: f64,
+    cost_model: &CostModel,
 ) -> f64 {
     // assumes that the vertex has a parent and children
     let mut costs = 0.0;
@@ -573,31 +830,50 @@ fn get_surrounding_costs(
     let vertex_embedding = embeddings[vertex.layer][vertex.index];
     children.iter().for_each(|child| {
         let child_embedding = embeddings[child.layer][child.index];
-        let len = ((vertex_embedding.0 - child_embedding.0).powi(2)
-            + (vertex_embedding.1 - child_embedding.1).powi(2))
-        .sqrt();
-        costs += len * (current_vertex_flows[child.layer][child.index] as f64).powf(alpha);
+        costs += cost_model.get_edge_costs(
+            child.layer,
+            &vertex_embedding,
+            &child_embedding,
+            current_vertex_flows[child.layer][child.index],
+        );
     });
     let parent_embedding = embeddings[parent.layer][parent.index];
-    let len = ((parent_embedding.0 - vertex_embedding.0).powi(2)
-        + (parent_embedding.1 - vertex_embedding.1).powi(2))
-    .sqrt();
-    costs += len * (current_vertex_flows[vertex.layer][vertex.index] as f64).powf(alpha);
+    costs += cost_model.get_edge_costs(
+        vertex.layer,
+        &vertex_embedding,
+        &parent_embedding,
+        current_vertex_flows[vertex.layer][vertex.index],
+    );
 
     return costs;
 }
 
+fn get_children_costs(
+    children: &Vec<VertexID>,
+    parent: &VertexID,
+    current_vertex_flows: &Vec<Vec<usize>>,
+    embeddings: &Vec<Vec<VertexEmbedding>>,
+    cost_model: &CostModel,
+) -> f64 {
+    return children.iter().fold(0.0, |acc, child| {
+        acc + get_edge_costs(
+            child,
+            parent,
+            current_vertex_flows[child.layer][child.index],
+            embeddings,
+            cost_model,
+        )
+    });
+}
+
 fn get_edge_costs(
     start_vertex: &VertexID,
     end_vertex: &VertexID,
     flow: usize,
     embeddings: &Vec<Vec<VertexEmbedding>>,
-    alpha: f64,
+    cost_model: &CostModel,
 ) -> f64 {
     let start_position = embeddings[start_vertex.layer][start_vertex.index];
     let end_position = embeddings[end_vertex.layer][end_vertex.index];
-    let len = ((end_position.0 - start_position.0).powi(2)
-        + (end_position.1 - start_position.1).powi(2))
-    .sqrt();
-    return len * (flow as f64).powf(alpha);
+    return cost_model.get_edge_costs(start_vertex.layer, &start_position, &end_position, flow);
 }
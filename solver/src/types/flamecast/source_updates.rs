@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    simulated_annealing::OptimizationOptions, EmbeddingOptions, FlamecastInstance, Vertex,
+    VertexEmbedding, VertexID,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SourceUpdateKind {
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceUpdate {
+    pub kind: SourceUpdateKind,
+    pub source_index: usize, // index of the added source or of the removed source before its removal
+    pub source_embedding: VertexEmbedding, // unprojected, (longitude, latitude) if geographic
+    pub attached_to: Option<VertexID>, // vertex the branch of an added source was attached to
+    pub number_changed_vertices: usize, // inner vertices opened for or pruned with the source
+    pub objective_value_before: f64,
+    pub objective_value_after_update: f64,
+    pub objective_value_after_reoptimization: f64,
+}
+
+impl FlamecastInstance {
+    // inserts a source at the given position into the cheapest branch that can take another
+    // source, the position is given like the sources of the instance were created, so as
+    // (longitude, latitude) for geographic instances, and is projected like them, the new branch
+    // gets a pass-through vertex in every layer below the vertex it is attached to, afterwards
+    // the solution is re-optimized with the given options, which should only allow a few iterations
+    pub fn add_source(
+        &mut self,
+        source_embedding: VertexEmbedding,
+        options: Option<OptimizationOptions>,
+    ) -> Result<usize, String> {
+        let objective_value_before = self.get_objective_function_value();
+        let projected_source_embedding = self.coordinate_system.project(&source_embedding);
+        let target = self
+            .find_cheapest_source_insertion(&projected_source_embedding)
+            .ok_or("No branch can take another source.")?;
+
+        let graph = &mut self.solution_state.current_solution.base_graph;
+        let source = graph.add_vertex_to_layer(0, Vertex::new_empty());
+        let mut current_parent = target.clone();
+        for layer in (1..target.layer).rev() {
+            let mut pass_through_vertex = Vertex::new_empty();
+            pass_through_vertex.set_parent(Some(current_parent.index));
+            current_parent = graph.add_vertex_to_layer(layer, pass_through_vertex);
+        }
+        graph.add_edge(&source, &current_parent);
+
+        self.sources_drains_embeddings.embeddings[0].push(projected_source_embedding);
+        self.max_path_length.add_source();
+
+        self.finish_source_update(
+            SourceUpdate {
+                kind: SourceUpdateKind::Added,
+                source_index: source.index,
+                source_embedding,
+                attached_to: Some(target.clone()),
+                number_changed_vertices: target.layer - 1,
+                objective_value_before,
+                objective_value_after_update: 0.0,
+                objective_value_after_reoptimization: 0.0,
+            },
+            options,
+        );
+        return Ok(source.index);
+    }
+
+    // removes a source and every inner vertex that has no children left, the last source takes
+    // over the index of the removed one, afterwards the solution is re-optimized like after adding
+    pub fn remove_source(
+        &mut self,
+        source_index: usize,
+        options: Option<OptimizationOptions>,
+    ) -> Result<(), String> {
+        let number_of_sources = self.get_number_of_sources();
+        if source_index >= number_of_sources {
+            return Err(format!(
+                "There is no source {}, the instance has {} sources.",
+                source_index, number_of_sources
+            ));
+        }
+        if number_of_sources == 1 {
+            return Err(String::from("The last source cannot be removed."));
+        }
+
+        let objective_value_before = self.get_objective_function_value();
+        let drain_layer = self.num_layers - 1;
+        let graph = &mut self.solution_state.current_solution.base_graph;
+
+        let source = VertexID::new(0, source_index);
+        let mut current = graph.get_parent(&source).unwrap();
+        graph.remove_edge(&source);
+        graph.remove_vertex(&source);
+
+        // prune the branch up to the first vertex that still has children
+        let mut number_pruned_vertices = 0;
+        while current.layer < drain_layer
+            && graph.get_vertex(&current).get_number_of_children() == 0
+        {
+            let parent = graph.get_parent(&current).unwrap();
+            graph.remove_edge(&current);
+            graph.remove_vertex(&current);
+            number_pruned_vertices += 1;
+            current = parent;
+        }
+
+        let source_embedding = self
+            .coordinate_system
+            .unproject(&self.sources_drains_embeddings.embeddings[0].swap_remove(source_index));
+        self.max_path_length.remove_source(source_index);
+
+        self.finish_source_update(
+            SourceUpdate {
+                kind: SourceUpdateKind::Removed,
+                source_index,
+                source_embedding,
+                attached_to: None,
+                number_changed_vertices: number_pruned_vertices,
+                objective_value_before,
+                objective_value_after_update: 0.0,
+                objective_value_after_reoptimization: 0.0,
+            },
+            options,
+        );
+        return Ok(());
+    }
+
+    fn find_cheapest_source_insertion(
+        &self,
+        source_embedding: &VertexEmbedding,
+    ) -> Option<VertexID> {
+        // estimates the costs of attaching a new branch to every vertex that can take one more
+        // source, the pass-through vertices are assumed to lie on the straight line to the vertex
+        let solution = &self.solution_state.current_solution;
+        let graph = &solution.base_graph;
+        let embeddings = &solution.vertices_embeddings.embeddings;
+        let cost_model = self.get_cost_model();
+        let vertex_flows = graph.calculate_vertex_flows();
+
+        let mut best_insertion: Option<(VertexID, f64)> = None;
+        for layer_index in 1..self.num_layers {
+            // the pass-through vertices below the vertex carry the flow of the new source
+            if (1..layer_index).any(|layer| self.capacities[layer] < 1) {
+                continue;
+            }
+            let opening_costs = (1..layer_index)
+                .map(|layer| cost_model.get_opening_costs(layer))
+                .sum::<f64>();
+
+            for vertex_index in 0..graph.layers[layer_index].vertices.len() {
+                let vertex = VertexID::new(layer_index, vertex_index);
+                if graph.get_vertex(&vertex).get_number_of_children()
+                    >= self.max_children.get_max_children(layer_index)
+                {
+                    continue;
+                }
+
+                let mut costs = opening_costs
+                    + cost_model.get_edge_costs(
+                        0,
+                        source_embedding,
+                        &embeddings[layer_index][vertex_index],
+                        1,
+                    );
+
+                // the flow increases by one on the whole path to the drain
+                let mut feasible = true;
+                let mut current = vertex.clone();
+                loop {
+                    let flow = vertex_flows[current.layer][current.index];
+                    if flow + 1 > self.capacities[current.layer] {
+                        feasible = false;
+                        break;
+                    }
+                    let parent = match graph.get_parent(&current) {
+                        Some(parent) => parent,
+                        None => break,
+                    };
+                    let from = &embeddings[current.layer][current.index];
+                    let to = &embeddings[parent.layer][parent.index];
+                    costs += cost_model.get_edge_costs(current.layer, from, to, flow + 1)
+                        - cost_model.get_edge_costs(current.layer, from, to, flow);
+                    current = parent;
+                }
+
+                if feasible
+                    && best_insertion
+                        .as_ref()
+                        .is_none_or(|(_, best_costs)| costs < *best_costs)
+                {
+                    best_insertion = Some((vertex, costs));
+                }
+            }
+        }
+
+        return best_insertion.map(|(vertex, _)| vertex);
+    }
+
+    fn finish_source_update(
+        &mut self,
+        mut source_update: SourceUpdate,
+        options: Option<OptimizationOptions>,
+    ) {
+        // the changed topology is embedded again and becomes the new initial solution
+        self.restart_from_current_solution(&EmbeddingOptions::default());
+        source_update.objective_value_after_update = self.get_objective_function_value();
+
+        let verbose = options.as_ref().is_some_and(|options| options.verbose);
+        if let Some(options) = options {
+            self.solve(options);
+        }
+        source_update.objective_value_after_reoptimization = self.get_objective_function_value();
+
+        if verbose {
+            println!(
+                "Source {:?}: {} -> {} -> {} ({} changed vertices)",
+                source_update.kind,
+                source_update.objective_value_before,
+                source_update.objective_value_after_update,
+                source_update.objective_value_after_reoptimization,
+                source_update.number_changed_vertices
+            );
+        }
+        self.source_updates.push(source_update);
+    }
+}
@@ -1,7 +1,11 @@
 mod best_iteration_value;
 mod neighbor_change;
+mod pareto_archive;
+mod solution_history;
 mod solution_state;
 
 pub use best_iteration_value::*;
 pub use neighbor_change::*;
+pub use pareto_archive::*;
+pub use solution_history::*;
 pub use solution_state::*;
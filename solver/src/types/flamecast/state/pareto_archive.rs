@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParetoPoint {
+    pub objective_value: f64,
+    pub number_inner_vertices: usize,
+    pub max_path_length: Option<f64>, // only tracked if the path length is an objective
+    pub iteration: usize,             // usize::MAX for the initial solution
+}
+
+impl ParetoPoint {
+    pub fn new(
+        objective_value: f64,
+        number_inner_vertices: usize,
+        max_path_length: Option<f64>,
+        iteration: usize,
+    ) -> Self {
+        Self {
+            objective_value,
+            number_inner_vertices,
+            max_path_length,
+            iteration,
+        }
+    }
+
+    pub fn dominates(&self, other: &ParetoPoint) -> bool {
+        // at least as good in every objective and better in at least one,
+        // a missing path length is never better or worse than another one
+        let path_length_ordering = match (self.max_path_length, other.max_path_length) {
+            (Some(own), Some(other)) => own.partial_cmp(&other),
+            _ => Some(std::cmp::Ordering::Equal),
+        };
+        let orderings = [
+            self.objective_value.partial_cmp(&other.objective_value),
+            Some(self.number_inner_vertices.cmp(&other.number_inner_vertices)),
+            path_length_ordering,
+        ];
+
+        return orderings
+            .iter()
+            .all(|ordering| *ordering != Some(std::cmp::Ordering::Greater))
+            && orderings.contains(&Some(std::cmp::Ordering::Less));
+    }
+
+    fn has_same_objectives(&self, other: &ParetoPoint) -> bool {
+        return self.objective_value == other.objective_value
+            && self.number_inner_vertices == other.number_inner_vertices
+            && self.max_path_length == other.max_path_length;
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParetoArchive {
+    pub points: Vec<ParetoPoint>, // no point dominates another one
+}
+
+impl ParetoArchive {
+    pub fn new() -> Self {
+        Self { points: Vec::new() }
+    }
+
+    pub fn insert(&mut self, point: ParetoPoint) -> bool {
+        // returns whether the point was added, points it dominates are removed from the archive
+        if self
+            .points
+            .iter()
+            .any(|archived| archived.dominates(&point) || archived.has_same_objectives(&point))
+        {
+            return false;
+        }
+
+        self.points.retain(|archived| !point.dominates(archived));
+        self.points.push(point);
+        return true;
+    }
+
+    pub fn get_front(&self) -> Vec<ParetoPoint> {
+        // the archived points sorted by the number of inner vertices and then by the costs
+        let mut front = self.points.clone();
+        front.sort_by(|a, b| {
+            a.number_inner_vertices
+                .cmp(&b.number_inner_vertices)
+                .then(a.objective_value.partial_cmp(&b.objective_value).unwrap())
+        });
+        return front;
+    }
+
+    pub fn get_cost_vertex_front(&self) -> Vec<ParetoPoint> {
+        // the points that are not dominated if the path length is ignored
+        let front = self.get_front();
+        let mut cost_vertex_front: Vec<ParetoPoint> = Vec::new();
+        for point in front {
+            // more inner vertices only pay off if they reduce the costs
+            match cost_vertex_front.last() {
+                Some(last) if last.objective_value <= point.objective_value => continue,
+                _ => cost_vertex_front.push(point),
+            }
+        }
+        return cost_vertex_front;
+    }
+
+    pub fn get_marginal_savings(&self) -> Vec<(usize, f64)> {
+        // costs saved per additional inner vertex between neighboring points of the front,
+        // given for the number of inner vertices of the larger solution
+        return self
+            .get_cost_vertex_front()
+            .windows(2)
+            .map(|pair| {
+                let additional_vertices =
+                    pair[1].number_inner_vertices - pair[0].number_inner_vertices;
+                let savings = pair[0].objective_value - pair[1].objective_value;
+                (
+                    pair[1].number_inner_vertices,
+                    savings / additional_vertices as f64,
+                )
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    graph_embedding::embed_directed_graph, CostModel, EmbeddingOptions, GraphEmbedding,
+    LayeredGraph, VertexEmbeddings,
+};
+
+use super::NeighborChange;
+
+#[derive(Debug, Clone)]
+pub struct SolutionHistory<'a> {
+    pub initial_solution: &'a GraphEmbedding,
+    pub accepted_neighbors: &'a Vec<NeighborChange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IntermediateSolution {
+    pub accepted_neighbor: Option<NeighborChange>, // None for the initial solution
+    pub solution: LayeredGraph,
+}
+
+pub struct IntermediateSolutionIterator<'a> {
+    accepted_neighbors: &'a Vec<NeighborChange>,
+    current_solution: LayeredGraph,
+    next_index: usize,
+    started: bool,
+}
+
+impl<'a> SolutionHistory<'a> {
+    pub fn new(
+        initial_solution: &'a GraphEmbedding,
+        accepted_neighbors: &'a Vec<NeighborChange>,
+    ) -> Self {
+        Self {
+            initial_solution,
+            accepted_neighbors,
+        }
+    }
+
+    pub fn get_solution_at_iteration(&self, iteration: usize) -> LayeredGraph {
+        // apply all neighbors accepted up to and including the given iteration to the initial topology
+        let mut solution = self.initial_solution.base_graph.clone();
+        for neighbor_change in self.accepted_neighbors.iter() {
+            if neighbor_change.iteration > iteration {
+                break;
+            }
+            solution.apply_neighbor_change(&neighbor_change.neighbor);
+        }
+        return solution;
+    }
+
+    pub fn get_embedded_solution_at_iteration(
+        &self,
+        iteration: usize,
+        cost_model: &CostModel,
+        options: &EmbeddingOptions,
+    ) -> GraphEmbedding {
+        let solution = self.get_solution_at_iteration(iteration);
+        return self.embed_solution(solution, cost_model, options);
+    }
+
+    pub fn embed_solution(
+        &self,
+        solution: LayeredGraph,
+        cost_model: &CostModel,
+        options: &EmbeddingOptions,
+    ) -> GraphEmbedding {
+        // sources and drains never move, so their positions are taken from the initial solution
+        let vertices_embeddings = embed_directed_graph(
+            &solution,
+            &self.get_sources_drains_embeddings(),
+            &solution.calculate_edge_flows(),
+            cost_model,
+            options,
+        );
+        return GraphEmbedding::new(solution, vertices_embeddings);
+    }
+
+    pub fn get_sources_drains_embeddings(&self) -> VertexEmbeddings {
+        let embeddings = &self.initial_solution.vertices_embeddings.embeddings;
+        let num_layers = embeddings.len();
+        let mut sources_drains_embeddings = VertexEmbeddings::new_with_size(num_layers);
+        if num_layers == 0 {
+            return sources_drains_embeddings;
+        }
+        sources_drains_embeddings.embeddings[0] = embeddings[0].clone();
+        sources_drains_embeddings.embeddings[num_layers - 1] = embeddings[num_layers - 1].clone();
+        return sources_drains_embeddings;
+    }
+
+    pub fn iter_intermediate_solutions(&self) -> IntermediateSolutionIterator<'a> {
+        return IntermediateSolutionIterator {
+            accepted_neighbors: self.accepted_neighbors,
+            current_solution: self.initial_solution.base_graph.clone(),
+            next_index: 0,
+            started: false,
+        };
+    }
+}
+
+impl<'a> Iterator for IntermediateSolutionIterator<'a> {
+    type Item = IntermediateSolution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the first item is the initial solution, every following item applies one accepted neighbor
+        if !self.started {
+            self.started = true;
+            return Some(IntermediateSolution {
+                accepted_neighbor: None,
+                solution: self.current_solution.clone(),
+            });
+        }
+
+        let neighbor_change = self.accepted_neighbors.get(self.next_index)?;
+        self.current_solution
+            .apply_neighbor_change(&neighbor_change.neighbor);
+        self.next_index += 1;
+
+        return Some(IntermediateSolution {
+            accepted_neighbor: Some(neighbor_change.clone()),
+            solution: self.current_solution.clone(),
+        });
+    }
+}
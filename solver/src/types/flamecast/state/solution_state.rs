@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::GraphEmbedding;
+use crate::{GraphEmbedding, LayeredGraph};
 
-use super::{BestIterationValue, NeighborChange};
+use super::{BestIterationValue, NeighborChange, ParetoArchive, ParetoPoint, SolutionHistory};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SolutionState {
@@ -10,6 +10,8 @@ pub struct SolutionState {
     pub current_solution: GraphEmbedding,
     pub accepted_neighbors: Vec<NeighborChange>,
     pub best_iteration: BestIterationValue,
+    #[serde(default)]
+    pub pareto_archive: ParetoArchive,
 }
 
 impl SolutionState {
@@ -19,6 +21,29 @@ impl SolutionState {
             current_solution,
             accepted_neighbors: Vec::new(),
             best_iteration: BestIterationValue::new(),
+            pareto_archive: ParetoArchive::new(),
         }
     }
+
+    pub fn get_solution_history(&self) -> SolutionHistory<'_> {
+        return SolutionHistory::new(&self.initial_solution, &self.accepted_neighbors);
+    }
+
+    pub fn get_best_solution(&self) -> LayeredGraph {
+        // the iteration of the best value is usize::MAX if the initial solution was the best one
+        return self.get_solution_at_iteration(self.best_iteration.iteration);
+    }
+
+    pub fn get_pareto_solution(&self, point: &ParetoPoint) -> LayeredGraph {
+        return self.get_solution_at_iteration(point.iteration);
+    }
+
+    fn get_solution_at_iteration(&self, iteration: usize) -> LayeredGraph {
+        if iteration == usize::MAX {
+            return self.initial_solution.base_graph.clone();
+        }
+        return self
+            .get_solution_history()
+            .get_solution_at_iteration(iteration);
+    }
 }
@@ -17,7 +17,7 @@ impl VertexID {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Vertex {
     pub parent_index: Option<usize>,
     pub children_indices: Option<Vec<usize>>,
@@ -82,6 +82,13 @@ impl Vertex {
         }
     }
 
+    pub fn get_number_of_children(&self) -> usize {
+        return self
+            .children_indices
+            .as_ref()
+            .map_or(0, |children_indices| children_indices.len());
+    }
+
     pub fn change_child(&mut self, old_index: usize, new_index: usize) {
         if let Some(children_indices) = &mut self.children_indices {
             let index = children_indices
@@ -93,7 +100,7 @@ impl Vertex {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Layer {
     pub vertices: Vec<Vertex>,
 }
@@ -129,7 +136,7 @@ impl Layer {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LayeredGraph {
     pub layers: Vec<Layer>,
 }
@@ -318,6 +325,17 @@ impl LayeredGraph {
             .fold(0, |acc, layer| acc + layer.vertices.len());
     }
 
+    pub fn get_number_of_inner_vertices(&self) -> usize {
+        // get the number of vertices that are neither sources nor drains
+        let number_of_layers = self.layers.len();
+        if number_of_layers < 2 {
+            return 0;
+        }
+        return self.get_number_of_vertices()
+            - self.layers[0].vertices.len()
+            - self.layers[number_of_layers - 1].vertices.len();
+    }
+
     pub fn get_number_of_edges(&self) -> usize {
         // get the number of edges of the graph
         let number_of_layers = self.layers.len();
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use super::{LayeredGraph, VertexID};
+
+// bound on the number of direct children of the vertices of every layer, e.g. the ports of a hub,
+// without entries the number of children is only limited by the capacities
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MaxChildren {
+    pub layer_max_children: Vec<usize>, // one entry per layer, the entry of the sources is ignored
+}
+
+impl MaxChildren {
+    pub fn new(layer_max_children: Vec<usize>) -> Self {
+        Self { layer_max_children }
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        return self.layer_max_children.is_empty();
+    }
+
+    pub fn get_max_children(&self, layer_index: usize) -> usize {
+        return self
+            .layer_max_children
+            .get(layer_index)
+            .cloned()
+            .unwrap_or(usize::MAX);
+    }
+
+    pub fn get_max_sources_per_vertex(&self, capacities: &Vec<usize>) -> Vec<usize> {
+        // the number of sources a vertex of every layer can serve, it is limited by its capacity
+        // and by the number of children of the vertices in its subtree
+        let mut max_sources: Vec<usize> = vec![1];
+        for layer_index in 1..capacities.len() {
+            let reachable_sources =
+                max_sources[layer_index - 1].saturating_mul(self.get_max_children(layer_index));
+            max_sources.push(reachable_sources.min(capacities[layer_index]));
+        }
+        return max_sources;
+    }
+
+    pub fn validate(
+        &self,
+        capacities: &Vec<usize>,
+        number_of_sources: usize,
+        number_of_drains: usize,
+    ) -> Result<(), String> {
+        if self.is_unlimited() {
+            return Ok(());
+        }
+        if self.layer_max_children.len() != capacities.len() {
+            return Err(format!(
+                "Expected {} max children, one per layer, but got {}.",
+                capacities.len(),
+                self.layer_max_children.len()
+            ));
+        }
+        if self.layer_max_children.iter().skip(1).any(|max| *max == 0) {
+            return Err(String::from(
+                "Every layer except the sources has to allow at least one child.",
+            ));
+        }
+
+        let max_sources_per_drain =
+            self.get_max_sources_per_vertex(capacities)[capacities.len() - 1];
+        if number_of_sources > number_of_drains.saturating_mul(max_sources_per_drain) {
+            return Err(format!(
+                "The drains can serve at most {} sources with the max children but there are {}.",
+                number_of_drains.saturating_mul(max_sources_per_drain),
+                number_of_sources
+            ));
+        }
+        return Ok(());
+    }
+
+    pub fn get_violating_vertices(&self, graph: &LayeredGraph) -> Vec<VertexID> {
+        // the vertices with more children than allowed in their layer
+        let mut violating_vertices = Vec::new();
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            let max_children = self.get_max_children(layer_index);
+            for (vertex_index, vertex) in layer.vertices.iter().enumerate() {
+                if vertex.get_number_of_children() > max_children {
+                    violating_vertices.push(VertexID::new(layer_index, vertex_index));
+                }
+            }
+        }
+        return violating_vertices;
+    }
+}
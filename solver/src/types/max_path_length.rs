@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+// bound on the length of the path from a source to its drain, e.g. to limit the latency,
+// geographic instances bound the length on the projection in kilometers
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum MaxPathLength {
+    #[default]
+    Unbounded,
+    Global(f64),                 // the same bound for every source
+    PerSource(Vec<Option<f64>>), // one bound per source, None leaves a source unbounded
+}
+
+// relative tolerance of the bounds, the embedding is only solved up to the accuracy of the solver
+pub const MAX_PATH_LENGTH_TOLERANCE: f64 = 1e-6;
+
+impl MaxPathLength {
+    pub fn is_unbounded(&self) -> bool {
+        return match self {
+            MaxPathLength::Unbounded => true,
+            MaxPathLength::Global(max_path_length) => max_path_length.is_infinite(),
+            MaxPathLength::PerSource(max_path_lengths) => max_path_lengths
+                .iter()
+                .all(|max_path_length| max_path_length.is_none()),
+        };
+    }
+
+    pub fn get_max_path_length(&self, source_index: usize) -> f64 {
+        return match self {
+            MaxPathLength::Unbounded => f64::INFINITY,
+            MaxPathLength::Global(max_path_length) => *max_path_length,
+            MaxPathLength::PerSource(max_path_lengths) => max_path_lengths
+                .get(source_index)
+                .cloned()
+                .flatten()
+                .unwrap_or(f64::INFINITY),
+        };
+    }
+
+    pub fn add_source(&mut self) {
+        // a new source is only bounded by a global bound
+        if let MaxPathLength::PerSource(max_path_lengths) = self {
+            max_path_lengths.push(None);
+        }
+    }
+
+    pub fn remove_source(&mut self, source_index: usize) {
+        // the bound of the last source moves to the removed one, like the source itself
+        if let MaxPathLength::PerSource(max_path_lengths) = self {
+            max_path_lengths.swap_remove(source_index);
+        }
+    }
+
+    pub fn validate(&self, number_of_sources: usize) -> Result<(), String> {
+        let max_path_lengths = match self {
+            MaxPathLength::Unbounded => return Ok(()),
+            MaxPathLength::Global(max_path_length) => vec![*max_path_length],
+            MaxPathLength::PerSource(max_path_lengths) => {
+                if max_path_lengths.len() != number_of_sources {
+                    return Err(format!(
+                        "Expected {} max path lengths, one per source, but got {}.",
+                        number_of_sources,
+                        max_path_lengths.len()
+                    ));
+                }
+                max_path_lengths.iter().flatten().cloned().collect()
+            }
+        };
+
+        if max_path_lengths
+            .iter()
+            .any(|max_path_length| max_path_length.is_nan() || *max_path_length <= 0.0)
+        {
+            return Err(String::from("Every max path length has to be positive."));
+        }
+        return Ok(());
+    }
+
+    pub fn get_violated_sources(&self, source_path_lengths: &Vec<f64>) -> Vec<usize> {
+        // indices of the sources whose path is longer than allowed
+        return source_path_lengths
+            .iter()
+            .enumerate()
+            .filter(|(source_index, path_length)| {
+                let max_path_length = self.get_max_path_length(*source_index);
+                **path_length > max_path_length * (1.0 + MAX_PATH_LENGTH_TOLERANCE)
+            })
+            .map(|(source_index, _)| source_index)
+            .collect();
+    }
+}
@@ -1,9 +1,29 @@
+mod coordinate_system;
+mod cost_model;
+mod distance_metric;
+mod edge_cost;
 mod embedding;
 mod flamecast;
 mod graph;
+mod max_children;
+mod max_path_length;
 mod neighbor_loader;
+mod robustness;
+mod solution_statistics;
+mod topology;
+mod topology_distance;
 
+pub use coordinate_system::*;
+pub use cost_model::*;
+pub use distance_metric::*;
+pub use edge_cost::*;
 pub use embedding::*;
 pub use flamecast::*;
 pub use graph::*;
+pub use max_children::*;
+pub use max_path_length::*;
 pub use neighbor_loader::*;
+pub use robustness::*;
+pub use solution_statistics::*;
+pub use topology::*;
+pub use topology_distance::*;
@@ -41,6 +41,14 @@ impl NeighborLoader {
                 self.unload_informations.push(old_parent);
                 self.unload_informations.push(new_parent);
             }
+            Neighbor::SubtreeExchange(_, _) => {
+                base_graph.apply_neighbor_change(neighbor);
+            }
+            Neighbor::Reroute(node, _) => {
+                let old_parent_node = base_graph.get_parent(node).unwrap();
+                base_graph.apply_neighbor_change(neighbor);
+                self.unload_informations.push(old_parent_node);
+            }
         }
 
         self.neighbor_loaded = true;
@@ -68,6 +76,13 @@ impl NeighborLoader {
                 let old_parent = self.unload_informations.pop().unwrap();
                 base_graph.undo_split(&old_parent, &new_parent);
             }
+            Neighbor::SubtreeExchange(node1, node2) => {
+                base_graph.undo_exchange_subtrees(node1, node2);
+            }
+            Neighbor::Reroute(node, target_node) => {
+                let old_parent_node = self.unload_informations.pop().unwrap();
+                base_graph.undo_reroute(node, target_node, &old_parent_node);
+            }
         }
 
         self.neighbor_loaded = false;
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CostModel, GraphEmbedding, LayeredGraph, MaxChildren, VertexID};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VertexFailure {
+    pub vertex: VertexID,
+    pub disconnected_sources: usize,
+    pub repair_costs: Option<f64>, // None if the subtree cannot be rerouted to another vertex
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RobustnessReport {
+    pub number_of_sources: usize,
+    pub objective_value: f64,
+    pub vertex_failures: Vec<VertexFailure>, // one entry per inner vertex
+}
+
+impl RobustnessReport {
+    pub fn get_expected_disconnected_sources(&self) -> f64 {
+        // sources that lose their connection if one inner vertex fails, averaged over the vertices
+        if self.vertex_failures.is_empty() {
+            return 0.0;
+        }
+        return self
+            .vertex_failures
+            .iter()
+            .map(|failure| failure.disconnected_sources as f64)
+            .sum::<f64>()
+            / self.vertex_failures.len() as f64;
+    }
+
+    pub fn get_robustness_score(&self) -> f64 {
+        // 1 if no failure disconnects a source, 0 if every failure disconnects all sources
+        if self.number_of_sources == 0 {
+            return 1.0;
+        }
+        return 1.0 - self.get_expected_disconnected_sources() / self.number_of_sources as f64;
+    }
+
+    pub fn get_unrepairable_failures(&self) -> Vec<VertexID> {
+        return self
+            .vertex_failures
+            .iter()
+            .filter(|failure| failure.repair_costs.is_none())
+            .map(|failure| failure.vertex.clone())
+            .collect();
+    }
+
+    pub fn get_max_repair_costs(&self) -> Option<f64> {
+        // the most expensive repairable failure
+        return self
+            .vertex_failures
+            .iter()
+            .filter_map(|failure| failure.repair_costs)
+            .fold(None, |max, costs| {
+                Some(max.map_or(costs, |max: f64| max.max(costs)))
+            });
+    }
+}
+
+impl LayeredGraph {
+    pub fn calculate_expected_disconnected_sources(&self) -> f64 {
+        // the flow of an inner vertex is the number of sources it disconnects if it fails
+        let num_layers = self.layers.len();
+        if num_layers <= 2 {
+            return 0.0;
+        }
+
+        let vertex_flows = self.calculate_vertex_flows();
+        let inner_flows = vertex_flows
+            .iter()
+            .take(num_layers - 1)
+            .skip(1)
+            .flat_map(|layer| layer.iter());
+        let number_inner_vertices = inner_flows.clone().count();
+        if number_inner_vertices == 0 {
+            return 0.0;
+        }
+        return inner_flows.sum::<usize>() as f64 / number_inner_vertices as f64;
+    }
+}
+
+impl GraphEmbedding {
+    pub fn calculate_robustness_penalty_with_cost_model(&self, cost_model: &CostModel) -> f64 {
+        if cost_model.robustness_penalty == 0.0 {
+            return 0.0;
+        }
+        return cost_model.robustness_penalty
+            * self.base_graph.calculate_expected_disconnected_sources();
+    }
+
+    pub fn analyze_robustness(
+        &self,
+        capacities: &Vec<usize>,
+        max_children: &MaxChildren,
+        cost_model: &CostModel,
+    ) -> RobustnessReport {
+        // every inner vertex fails on its own, its children are rerouted to the other vertices of
+        // its layer, the positions of all remaining vertices are kept
+        let objective_value = self.calculate_costs_with_cost_model(cost_model);
+        let vertex_flows = self.base_graph.calculate_vertex_flows();
+        let num_layers = self.base_graph.layers.len();
+
+        let mut vertex_failures = Vec::new();
+        for (layer_index, layer_flows) in vertex_flows
+            .iter()
+            .enumerate()
+            .take(num_layers.saturating_sub(1))
+            .skip(1)
+        {
+            for (vertex_index, flow) in layer_flows.iter().enumerate() {
+                let vertex = VertexID::new(layer_index, vertex_index);
+                let repair_costs = self
+                    .repair_vertex_failure(&vertex, capacities, max_children, cost_model)
+                    .map(|repaired| {
+                        repaired.calculate_costs_with_cost_model(cost_model) - objective_value
+                    });
+                vertex_failures.push(VertexFailure {
+                    vertex,
+                    disconnected_sources: *flow,
+                    repair_costs,
+                });
+            }
+        }
+
+        return RobustnessReport {
+            number_of_sources: self.base_graph.layers[0].vertices.len(),
+            objective_value,
+            vertex_failures,
+        };
+    }
+
+    pub fn repair_vertex_failure(
+        &self,
+        vertex: &VertexID,
+        capacities: &Vec<usize>,
+        max_children: &MaxChildren,
+        cost_model: &CostModel,
+    ) -> Option<GraphEmbedding> {
+        // removes the vertex and attaches each of its children greedily to the vertex of the
+        // same layer with the cheapest estimated costs, None if a child fits nowhere
+        let mut repaired = self.clone();
+        let graph = &mut repaired.base_graph;
+        let embeddings = &self.vertices_embeddings.embeddings;
+
+        let mut children = graph.get_children(vertex).unwrap_or_default();
+        children.iter().for_each(|child| graph.remove_edge(child));
+
+        // the children with the most flow are the hardest to place
+        let child_flows = self.base_graph.calculate_vertex_flows();
+        children.sort_by_key(|child| std::cmp::Reverse(child_flows[child.layer][child.index]));
+
+        for child in children.iter() {
+            let child_flow = child_flows[child.layer][child.index];
+            let vertex_flows = graph.calculate_vertex_flows();
+
+            let mut best_target: Option<(VertexID, f64)> = None;
+            for target_index in 0..graph.layers[vertex.layer].vertices.len() {
+                let target = VertexID::new(vertex.layer, target_index);
+                if target == *vertex
+                    || graph.get_vertex(&target).get_number_of_children()
+                        >= max_children.get_max_children(target.layer)
+                {
+                    continue;
+                }
+
+                let mut costs = cost_model.get_edge_costs(
+                    child.layer,
+                    &embeddings[child.layer][child.index],
+                    &embeddings[target.layer][target.index],
+                    child_flow,
+                );
+                // the flow of the child is added on the whole path of the target
+                let mut feasible = true;
+                let mut current = target.clone();
+                loop {
+                    let flow = vertex_flows[current.layer][current.index];
+                    if flow + child_flow > capacities[current.layer] {
+                        feasible = false;
+                        break;
+                    }
+                    let parent = match graph.get_parent(&current) {
+                        Some(parent) => parent,
+                        None => break,
+                    };
+                    let from = &embeddings[current.layer][current.index];
+                    let to = &embeddings[parent.layer][parent.index];
+                    costs += cost_model.get_edge_costs(current.layer, from, to, flow + child_flow)
+                        - cost_model.get_edge_costs(current.layer, from, to, flow);
+                    current = parent;
+                }
+
+                if feasible
+                    && best_target
+                        .as_ref()
+                        .is_none_or(|(_, best_costs)| costs < *best_costs)
+                {
+                    best_target = Some((target, costs));
+                }
+            }
+
+            let (target, _) = best_target?;
+            graph.add_edge(child, &target);
+        }
+
+        // the failed vertex and every vertex above it that has no children left are removed
+        let drain_layer = graph.layers.len() - 1;
+        let mut current = vertex.clone();
+        while current.layer < drain_layer
+            && graph.get_vertex(&current).get_number_of_children() == 0
+        {
+            let parent = graph.get_parent(&current).unwrap();
+            graph.remove_edge(&current);
+            graph.remove_vertex(&current);
+            // the embeddings are swapped like the vertices
+            repaired.vertices_embeddings.embeddings[current.layer].swap_remove(current.index);
+            current = parent;
+        }
+
+        return Some(repaired);
+    }
+}
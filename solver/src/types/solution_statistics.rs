@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CostModel, GraphEmbedding};
+
+pub const UTILIZATION_HISTOGRAM_BINS: usize = 10; // bins of equal width between 0 and 1
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayerStatistics {
+    pub layer: usize,
+    pub number_of_vertices: usize,
+    pub capacity: usize,
+    pub branching_factors: Vec<usize>, // entry i is the number of vertices with i children
+    pub mean_utilization: f64,         // mean flow of the vertices relative to the capacity
+    pub edge_length: f64,              // length of the outgoing edges, 0 for the drains
+    pub edge_costs: f64,
+    pub opening_costs: f64,
+}
+
+impl LayerStatistics {
+    pub fn get_costs(&self) -> f64 {
+        return self.edge_costs + self.opening_costs;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolutionStatistics {
+    pub layers: Vec<LayerStatistics>,
+    // distributions over all vertices that are not sources, the flow of a source is always 1
+    pub branching_factors: Vec<usize>,
+    pub flow_histogram: Vec<usize>, // entry i is the number of vertices with flow i
+    pub utilization_histogram: Vec<usize>,
+    pub total_edge_length: f64,
+    pub longest_source_path: usize, // index of the source with the longest path to its drain
+    pub longest_source_path_length: f64,
+    pub robustness_penalty: f64,
+    pub objective_value: f64,
+}
+
+impl GraphEmbedding {
+    pub fn calculate_statistics(
+        &self,
+        capacities: &Vec<usize>,
+        cost_model: &CostModel,
+    ) -> SolutionStatistics {
+        // assumes a valid flamecast graph, the lengths and costs are measured like the objective
+        let graph = &self.base_graph;
+        let vertex_flows = graph.calculate_vertex_flows();
+        let edge_lengths = self.calculate_edge_lengths_with_cost_model(cost_model);
+        let edge_costs = self.calculate_edge_costs_with_cost_model(cost_model);
+
+        let mut branching_factors = Vec::new();
+        let mut flow_histogram = Vec::new();
+        let mut utilization_histogram = vec![0; UTILIZATION_HISTOGRAM_BINS];
+        let mut layers = Vec::new();
+        for (layer_index, layer) in graph.layers.iter().enumerate() {
+            let number_of_vertices = layer.vertices.len();
+            let capacity = capacities[layer_index];
+
+            let mut layer_branching_factors = Vec::new();
+            if layer_index > 0 {
+                for vertex in layer.vertices.iter() {
+                    let number_of_children = vertex.get_number_of_children();
+                    add_to_distribution(&mut layer_branching_factors, number_of_children);
+                    add_to_distribution(&mut branching_factors, number_of_children);
+                }
+            }
+
+            let mut utilization_sum = 0.0;
+            for flow in vertex_flows[layer_index].iter() {
+                let utilization = *flow as f64 / capacity as f64;
+                utilization_sum += utilization;
+                if layer_index > 0 {
+                    add_to_distribution(&mut flow_histogram, *flow);
+                    let bin = (utilization * UTILIZATION_HISTOGRAM_BINS as f64) as usize;
+                    utilization_histogram[bin.min(UTILIZATION_HISTOGRAM_BINS - 1)] += 1;
+                }
+            }
+
+            layers.push(LayerStatistics {
+                layer: layer_index,
+                number_of_vertices,
+                capacity,
+                branching_factors: layer_branching_factors,
+                mean_utilization: if number_of_vertices == 0 {
+                    0.0
+                } else {
+                    utilization_sum / number_of_vertices as f64
+                },
+                edge_length: edge_lengths
+                    .get(layer_index)
+                    .map_or(0.0, |lengths| lengths.iter().sum()),
+                edge_costs: edge_costs
+                    .get(layer_index)
+                    .map_or(0.0, |costs| costs.iter().sum()),
+                opening_costs: number_of_vertices as f64
+                    * cost_model.get_opening_costs(layer_index),
+            });
+        }
+
+        let (longest_source_path, longest_source_path_length) = self
+            .calculate_source_path_lengths_with_cost_model(cost_model)
+            .into_iter()
+            .enumerate()
+            .fold((0, 0.0), |longest, (source, length)| {
+                if length > longest.1 {
+                    (source, length)
+                } else {
+                    longest
+                }
+            });
+        let robustness_penalty = self.calculate_robustness_penalty_with_cost_model(cost_model);
+
+        return SolutionStatistics {
+            total_edge_length: layers.iter().map(|layer| layer.edge_length).sum(),
+            objective_value: layers.iter().map(|layer| layer.get_costs()).sum::<f64>()
+                + robustness_penalty,
+            layers,
+            branching_factors,
+            flow_histogram,
+            utilization_histogram,
+            longest_source_path,
+            longest_source_path_length,
+            robustness_penalty,
+        };
+    }
+}
+
+fn add_to_distribution(distribution: &mut Vec<usize>, value: usize) {
+    // the distribution grows with the largest value seen so far
+    if distribution.len() <= value {
+        distribution.resize(value + 1, 0);
+    }
+    distribution[value] += 1;
+}
@@ -0,0 +1,154 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+};
+
+use crate::EmbeddingOptions;
+
+use super::LayeredGraph;
+
+pub type TopologyCacheKey = (u64, u32, u64); // (topology hash, search depth, time limit bits)
+
+impl LayeredGraph {
+    pub fn calculate_topology_hash(&self) -> u64 {
+        // hash of the topology which is invariant to the order of the vertices inside the inner layers,
+        // every vertex is identified by the sorted hashes of its children, sources and drains keep their indices
+        let num_layers = self.layers.len();
+        let mut vertex_hashes: Vec<Vec<u64>> = Vec::with_capacity(num_layers);
+
+        vertex_hashes.push(
+            (0..self.layers[0].vertices.len())
+                .map(|source_index| hash_value(&(0, source_index)))
+                .collect(),
+        );
+
+        for layer_index in 1..num_layers {
+            let layer_hashes = self.layers[layer_index]
+                .vertices
+                .iter()
+                .map(|vertex| {
+                    let mut children_hashes = match &vertex.children_indices {
+                        Some(children_indices) => children_indices
+                            .iter()
+                            .map(|child_index| vertex_hashes[layer_index - 1][*child_index])
+                            .collect::<Vec<u64>>(),
+                        None => Vec::new(),
+                    };
+                    children_hashes.sort();
+                    return hash_value(&(layer_index, children_hashes));
+                })
+                .collect();
+            vertex_hashes.push(layer_hashes);
+        }
+
+        // the drains are not sorted because they are fixed like the sources
+        return hash_value(&vertex_hashes[num_layers - 1]);
+    }
+}
+
+fn hash_value<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    return hasher.finish();
+}
+
+// the key only contains the topology hash and the embedding options that change the costs, the
+// search depth and the time limit, so the cached costs are only valid for the cost model and the
+// sources and drains they were calculated with. every solve starts with a new cache, and every
+// setter of the cost model as well as adding or removing sources goes through
+// restart_from_current_solution, which clears it. code that changes the instance in another way
+// while a cache is in use has to clear the cache itself
+#[derive(Debug, Clone, Default)]
+pub struct TopologyCache {
+    pub capacity: usize,
+    pub hits: usize,
+    pub misses: usize,
+    costs: HashMap<TopologyCacheKey, f64>,
+    insertion_order: VecDeque<TopologyCacheKey>,
+}
+
+impl TopologyCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            hits: 0,
+            misses: 0,
+            costs: HashMap::new(),
+            insertion_order: VecDeque::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    pub fn get(&mut self, topology_hash: u64, options: &EmbeddingOptions) -> Option<f64> {
+        // returns the cached costs of the topology and counts the hit or miss
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let result = self
+            .costs
+            .get(&get_cache_key(topology_hash, options))
+            .copied();
+
+        if result.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+
+        return result;
+    }
+
+    pub fn insert(&mut self, topology_hash: u64, options: &EmbeddingOptions, costs: f64) {
+        // inserts the costs of a topology, the oldest entry is removed if the cache is full
+        if !self.is_enabled() {
+            return;
+        }
+
+        let key = get_cache_key(topology_hash, options);
+        if self.costs.insert(key, costs).is_some() {
+            return;
+        }
+
+        self.insertion_order.push_back(key);
+        if self.insertion_order.len() > self.capacity {
+            let oldest_key = self.insertion_order.pop_front().unwrap();
+            self.costs.remove(&oldest_key);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+        self.costs.clear();
+        self.insertion_order.clear();
+    }
+
+    pub fn get_hit_rate(&self) -> f64 {
+        if self.hits + self.misses == 0 {
+            return 0.0;
+        }
+        return self.hits as f64 / (self.hits + self.misses) as f64;
+    }
+}
+
+fn get_cache_key(topology_hash: u64, options: &EmbeddingOptions) -> TopologyCacheKey {
+    // the costs depend on the depth and the time limit of the embedding, the other options only
+    // change what is printed
+    (
+        topology_hash,
+        options.search_depth as u32,
+        options.time_limit.to_bits(),
+    )
+}
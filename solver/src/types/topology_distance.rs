@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::LayeredGraph;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceGroupingDiff {
+    pub layer: usize,
+    // groups of sources that share a vertex of the layer in one solution but not in the other
+    pub only_in_first: Vec<Vec<usize>>,
+    pub only_in_second: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopologicalDistance {
+    // one entry per layer above the sources, entry i belongs to layer i + 1
+    pub layer_rand_indices: Vec<f64>,
+    pub distance: f64, // 0 for the same topology, at most 1
+}
+
+impl LayeredGraph {
+    pub fn get_source_ancestors(&self) -> Vec<Vec<usize>> {
+        // index of the vertex above every source in every layer, the first layer are the sources
+        let num_layers = self.layers.len();
+        let mut ancestors = vec![(0..self.layers[0].vertices.len()).collect::<Vec<usize>>()];
+        for layer_index in 0..num_layers - 1 {
+            let layer_ancestors = ancestors[layer_index]
+                .iter()
+                .map(|vertex_index| {
+                    self.layers[layer_index].vertices[*vertex_index]
+                        .parent_index
+                        .unwrap()
+                })
+                .collect();
+            ancestors.push(layer_ancestors);
+        }
+        return ancestors;
+    }
+
+    pub fn get_source_groups(&self, layer_index: usize) -> Vec<Vec<usize>> {
+        // the sources below every vertex of the layer, sorted so that the groups of two
+        // solutions can be compared regardless of the order of the vertices
+        let ancestors = &self.get_source_ancestors()[layer_index];
+        let mut groups = vec![Vec::new(); self.layers[layer_index].vertices.len()];
+        for (source_index, ancestor) in ancestors.iter().enumerate() {
+            groups[*ancestor].push(source_index);
+        }
+        let mut groups = groups
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .collect::<Vec<Vec<usize>>>();
+        groups.sort();
+        return groups;
+    }
+
+    pub fn calculate_topological_distance(
+        &self,
+        other: &LayeredGraph,
+    ) -> Result<TopologicalDistance, String> {
+        // in every layer two sources are grouped if they share a vertex, the rand index is the
+        // share of source pairs on whose grouping both solutions agree, the distance is one minus
+        // the mean rand index of the layers
+        self.check_comparable(other)?;
+
+        let own_ancestors = self.get_source_ancestors();
+        let other_ancestors = other.get_source_ancestors();
+        let number_of_sources = self.layers[0].vertices.len();
+        let number_of_pairs = get_number_of_pairs(number_of_sources);
+
+        let layer_rand_indices = (1..self.layers.len())
+            .map(|layer_index| {
+                if number_of_pairs == 0 {
+                    return 1.0;
+                }
+                let own_pairs = get_grouped_pairs(own_ancestors[layer_index].iter().cloned());
+                let other_pairs = get_grouped_pairs(other_ancestors[layer_index].iter().cloned());
+                let common_pairs = get_grouped_pairs(
+                    own_ancestors[layer_index]
+                        .iter()
+                        .cloned()
+                        .zip(other_ancestors[layer_index].iter().cloned()),
+                );
+                // pairs that are grouped in exactly one solution are disagreements
+                let disagreements = own_pairs + other_pairs - 2 * common_pairs;
+                1.0 - disagreements as f64 / number_of_pairs as f64
+            })
+            .collect::<Vec<f64>>();
+
+        let distance =
+            1.0 - layer_rand_indices.iter().sum::<f64>() / layer_rand_indices.len() as f64;
+        return Ok(TopologicalDistance {
+            layer_rand_indices,
+            distance,
+        });
+    }
+
+    pub fn calculate_source_grouping_diff(
+        &self,
+        other: &LayeredGraph,
+    ) -> Result<Vec<SourceGroupingDiff>, String> {
+        // the layers in which the solutions group the sources differently
+        self.check_comparable(other)?;
+
+        let mut diffs = Vec::new();
+        for layer_index in 1..self.layers.len() {
+            let own_groups = self.get_source_groups(layer_index);
+            let other_groups = other.get_source_groups(layer_index);
+            let only_in_first = own_groups
+                .iter()
+                .filter(|group| other_groups.binary_search(group).is_err())
+                .cloned()
+                .collect::<Vec<Vec<usize>>>();
+            let only_in_second = other_groups
+                .iter()
+                .filter(|group| own_groups.binary_search(group).is_err())
+                .cloned()
+                .collect::<Vec<Vec<usize>>>();
+
+            if !only_in_first.is_empty() || !only_in_second.is_empty() {
+                diffs.push(SourceGroupingDiff {
+                    layer: layer_index,
+                    only_in_first,
+                    only_in_second,
+                });
+            }
+        }
+        return Ok(diffs);
+    }
+
+    fn check_comparable(&self, other: &LayeredGraph) -> Result<(), String> {
+        // both graphs have to be solutions of the same instance
+        let num_layers = self.layers.len();
+        if num_layers != other.layers.len() {
+            return Err(format!(
+                "The solutions have {} and {} layers.",
+                num_layers,
+                other.layers.len()
+            ));
+        }
+        for (layer_index, name) in [(0, "sources"), (num_layers - 1, "drains")] {
+            let own_size = self.layers[layer_index].vertices.len();
+            let other_size = other.layers[layer_index].vertices.len();
+            if own_size != other_size {
+                return Err(format!(
+                    "The solutions have {} and {} {}.",
+                    own_size, other_size, name
+                ));
+            }
+        }
+        return Ok(());
+    }
+}
+
+fn get_number_of_pairs(size: usize) -> usize {
+    return size * size.saturating_sub(1) / 2;
+}
+
+fn get_grouped_pairs<T: std::hash::Hash + Eq>(groups: impl Iterator<Item = T>) -> usize {
+    // number of pairs of sources with the same group
+    let mut group_sizes: HashMap<T, usize> = HashMap::new();
+    for group in groups {
+        *group_sizes.entry(group).or_insert(0) += 1;
+    }
+    return group_sizes
+        .values()
+        .map(|size| get_number_of_pairs(*size))
+        .sum();
+}